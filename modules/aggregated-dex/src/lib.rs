@@ -240,7 +240,11 @@ impl<T: Config> Pallet<T> {
 		))
 	}
 
-	fn get_aggregated_swap_amount(paths: &[SwapPath], swap_limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+	/// Calculate the swap amount of aggregated swap by DEX and Taiga.
+	pub fn get_aggregated_swap_amount(
+		paths: &[SwapPath],
+		swap_limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
 		Self::check_swap_paths(paths).ok()?;
 
 		match swap_limit {
@@ -317,7 +321,7 @@ impl<T: Config> Pallet<T> {
 
 	/// Aggregated swap by DEX and Taiga.
 	#[transactional]
-	fn do_aggregated_swap(
+	pub fn do_aggregated_swap(
 		who: &T::AccountId,
 		paths: &[SwapPath],
 		swap_limit: SwapLimit<Balance>,
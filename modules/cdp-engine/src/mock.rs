@@ -284,6 +284,8 @@ impl module_evm::Config for Runtime {
 	type NewContractExtraBytes = ConstU32<1>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<10>;
+	type MinBaseFeePerGas = ConstU128<10>;
+	type BaseFeeGasTarget = ConstU64<15_000_000>;
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType = ();
 	type PrecompilesValue = ();
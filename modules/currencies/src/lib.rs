@@ -29,13 +29,13 @@ use frame_support::{
 			fungible, fungibles, DepositConsequence, Fortitude, Precision, Preservation, Provenance, Restriction,
 			WithdrawConsequence,
 		},
-		BalanceStatus as Status, Currency as PalletCurrency, ExistenceRequirement, Get, Imbalance,
+		BalanceStatus as Status, Currency as PalletCurrency, ExistenceRequirement, Get, Imbalance, IsSubType,
 		LockableCurrency as PalletLockableCurrency, ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
 	},
 	transactional,
 };
 use frame_system::pallet_prelude::*;
-use module_support::{evm::limits::erc20, AddressMapping, EVMBridge, InvokeContext};
+use module_support::{evm::limits::erc20, AddressMapping, EVMBridge, Erc20TransferLog, InvokeContext};
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	currency::{OnDust, TransferAll},
@@ -46,8 +46,12 @@ use parity_scale_codec::Codec;
 use primitives::{evm::EvmAddress, CurrencyId};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedSub, Convert, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
-	DispatchError, DispatchResult,
+	traits::{
+		Bounded, CheckedAdd, CheckedSub, Convert, DispatchInfoOf, MaybeSerializeDeserialize, PostDispatchInfoOf,
+		Saturating, SignedExtension, StaticLookup, Zero,
+	},
+	transaction_validity::TransactionValidityError,
+	ArithmeticError, DispatchError, DispatchResult,
 };
 use sp_std::{fmt::Debug, marker, result, vec::Vec};
 
@@ -93,7 +97,8 @@ pub mod module {
 		#[pallet::constant]
 		type GetNativeCurrencyId: Get<CurrencyId>;
 
-		/// Used as temporary account for ERC20 token `withdraw` and `deposit`.
+		/// Used as temporary account for ERC20 token `withdraw` and `deposit`, and as the escrow of
+		/// the locked ERC20 tokens.
 		#[pallet::constant]
 		type Erc20HoldingAccount: Get<EvmAddress>;
 
@@ -112,6 +117,9 @@ pub mod module {
 
 		/// Handler to burn or transfer account's dust
 		type OnDust: OnDust<Self::AccountId, CurrencyId, BalanceOf<Self>>;
+
+		/// Emits the ERC20 `Transfer` logs of the balance changes made through this module.
+		type Erc20TransferLog: Erc20TransferLog<Self::AccountId, BalanceOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -128,6 +136,8 @@ pub mod module {
 		RealOriginNotFound,
 		/// Deposit result is not expected
 		DepositFailed,
+		/// The allowance is too low
+		AllowanceTooLow,
 	}
 
 	#[pallet::event]
@@ -158,8 +168,62 @@ pub mod module {
 			who: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// The ERC20 allowance of a spender is set.
+		Approved {
+			currency_id: CurrencyId,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
+	/// The ERC20 allowances of the currencies, used by the MultiCurrency precompile.
+	///
+	/// Allowances: double_map (CurrencyId, owner: AccountId), spender: AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(CurrencyId, T::AccountId),
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The EIP-2612 permit nonces of the currencies.
+	///
+	/// PermitNonces: double_map CurrencyId, owner: AccountId => u64
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonces)]
+	pub type PermitNonces<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// The ERC20 locks, the max of the locks of an account is escrowed in `Erc20HoldingAccount`.
+	///
+	/// Erc20Locks: double_map (CurrencyId, AccountId), LockIdentifier => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_locks)]
+	pub type Erc20Locks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(CurrencyId, T::AccountId),
+		Twox64Concat,
+		LockIdentifier,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The ERC20 balances on hold, keyed by the hash of the encoded hold reason. The balances on
+	/// hold are reserved.
+	///
+	/// Erc20Holds: double_map (CurrencyId, AccountId), Hash(Reason) => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_holds)]
+	pub type Erc20Holds<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, (CurrencyId, T::AccountId), Identity, [u8; 32], BalanceOf<T>, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -206,7 +270,9 @@ pub mod module {
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(dest)?;
-			<T::NativeCurrency as BasicCurrency<_>>::transfer(&from, &to, amount, ExistenceRequirement::AllowDeath)
+			<T::NativeCurrency as BasicCurrency<_>>::transfer(&from, &to, amount, ExistenceRequirement::AllowDeath)?;
+			Self::log_transfer(T::GetNativeCurrencyId::get(), Some(&from), Some(&to), amount);
+			Ok(())
 		}
 
 		/// Update amount of account `who` under `currency_id`.
@@ -293,10 +359,139 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Emit the ERC20 `Transfer` log of a balance change. `from` is `None` for a mint and `to` is
+	/// `None` for a burn. Nothing is logged when no balance changes hands.
+	fn log_transfer(
+		currency_id: CurrencyId,
+		from: Option<&T::AccountId>,
+		to: Option<&T::AccountId>,
+		amount: BalanceOf<T>,
+	) {
+		if !amount.is_zero() && from != to {
+			T::Erc20TransferLog::log_transfer(currency_id, from, to, amount);
+		}
+	}
+
+	/// Emit the ERC20 `Transfer` log of a balance update by `by_amount`.
+	fn log_balance_update(currency_id: CurrencyId, who: &T::AccountId, by_amount: AmountOf<T>) {
+		if let Ok(amount) = TryInto::<BalanceOf<T>>::try_into(by_amount.abs()) {
+			if by_amount.is_positive() {
+				Self::log_transfer(currency_id, None, Some(who), amount);
+			} else {
+				Self::log_transfer(currency_id, Some(who), None, amount);
+			}
+		}
+	}
+
 	fn get_evm_origin() -> Result<EvmAddress, DispatchError> {
 		let origin = T::EVMBridge::get_real_or_xcm_origin().ok_or(Error::<T>::RealOriginNotFound)?;
 		Ok(T::AddressMapping::get_or_create_evm_address(&origin))
 	}
+
+	/// Set the ERC20 allowance of `spender` over the `currency_id` of `owner`.
+	pub fn approve(currency_id: CurrencyId, owner: &T::AccountId, spender: &T::AccountId, amount: BalanceOf<T>) {
+		Allowances::<T>::insert((currency_id, owner.clone()), spender, amount);
+		Self::deposit_event(Event::Approved {
+			currency_id,
+			owner: owner.clone(),
+			spender: spender.clone(),
+			amount,
+		});
+	}
+
+	/// Transfer `amount` of `currency_id` from `from` to `to` on behalf of `spender`, spending the
+	/// allowance of `spender`. The max allowance is never spent.
+	#[transactional]
+	pub fn transfer_from(
+		currency_id: CurrencyId,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		Allowances::<T>::try_mutate((currency_id, from.clone()), spender, |allowance| -> DispatchResult {
+			if *allowance != BalanceOf::<T>::max_value() {
+				*allowance = allowance.checked_sub(&amount).ok_or(Error::<T>::AllowanceTooLow)?;
+			}
+			Ok(())
+		})?;
+
+		<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, from, to, amount, ExistenceRequirement::AllowDeath)
+	}
+
+	/// The ERC20 balance of `who` on hold for `reason`.
+	pub fn erc20_balance_on_hold(currency_id: CurrencyId, reason: &impl Encode, who: &T::AccountId) -> BalanceOf<T> {
+		Erc20Holds::<T>::get((currency_id, who.clone()), reason.using_encoded(blake2_256))
+	}
+
+	fn set_erc20_balance_on_hold(
+		currency_id: CurrencyId,
+		reason: &impl Encode,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) {
+		let key = reason.using_encoded(blake2_256);
+		if amount.is_zero() {
+			Erc20Holds::<T>::remove((currency_id, who.clone()), key);
+		} else {
+			Erc20Holds::<T>::insert((currency_id, who.clone()), key, amount);
+		}
+	}
+
+	/// The ERC20 balance of `who` escrowed by locks, which is the max of the locks.
+	pub fn erc20_locked_balance(currency_id: CurrencyId, who: &T::AccountId) -> BalanceOf<T> {
+		Erc20Locks::<T>::iter_prefix_values((currency_id, who.clone()))
+			.max()
+			.unwrap_or_default()
+	}
+
+	/// Update the ERC20 lock `lock_id` of `who`, `None` removes the lock. The change of the locked
+	/// balance is escrowed into or refunded from `Erc20HoldingAccount`.
+	#[transactional]
+	fn update_erc20_lock(
+		lock_id: LockIdentifier,
+		contract: EvmAddress,
+		who: &T::AccountId,
+		amount: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let currency_id = CurrencyId::Erc20(contract);
+		let locked_before = Self::erc20_locked_balance(currency_id, who);
+		match amount {
+			Some(amount) if !amount.is_zero() => Erc20Locks::<T>::insert((currency_id, who.clone()), lock_id, amount),
+			_ => Erc20Locks::<T>::remove((currency_id, who.clone()), lock_id),
+		}
+		let locked_after = Self::erc20_locked_balance(currency_id, who);
+
+		if locked_after == locked_before {
+			return Ok(());
+		}
+
+		let holding = T::Erc20HoldingAccount::get();
+		let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
+		let (sender, receiver, value) = if locked_after > locked_before {
+			(address, holding, locked_after - locked_before)
+		} else {
+			(holding, address, locked_before - locked_after)
+		};
+		T::EVMBridge::transfer(
+			InvokeContext {
+				contract,
+				sender,
+				origin: Self::get_evm_origin().unwrap_or(address),
+			},
+			receiver,
+			value,
+		)
+	}
+
+	/// Use the EIP-2612 permit nonce of `owner`, returns the used nonce.
+	pub fn use_permit_nonce(currency_id: CurrencyId, owner: &T::AccountId) -> u64 {
+		PermitNonces::<T>::mutate(currency_id, owner, |nonce| {
+			let used = *nonce;
+			*nonce = nonce.saturating_add(1);
+			used
+		})
+	}
 }
 
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
@@ -329,7 +524,10 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 			CurrencyId::Erc20(_) => {
 				let free_balance = Self::free_balance(currency_id, who);
 				let reserved_balance = <Self as MultiReservableCurrency<_>>::reserved_balance(currency_id, who);
-				free_balance.saturating_add(reserved_balance)
+				let locked_balance = Self::erc20_locked_balance(currency_id, who);
+				free_balance
+					.saturating_add(reserved_balance)
+					.saturating_add(locked_balance)
 			}
 			id if id == T::GetNativeCurrencyId::get() => <T::NativeCurrency as BasicCurrency<_>>::total_balance(who),
 			_ => <T::MultiCurrency as MultiCurrency<_>>::total_balance(currency_id, who),
@@ -420,6 +618,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 			to: to.clone(),
 			amount,
 		});
+		Self::log_transfer(currency_id, Some(from), Some(to), amount);
 		Ok(())
 	}
 
@@ -461,8 +660,16 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 				});
 				Ok(())
 			}
-			id if id == T::GetNativeCurrencyId::get() => <T::NativeCurrency as BasicCurrency<_>>::deposit(who, amount),
-			_ => <T::MultiCurrency as MultiCurrency<_>>::deposit(currency_id, who, amount),
+			id if id == T::GetNativeCurrencyId::get() => {
+				<T::NativeCurrency as BasicCurrency<_>>::deposit(who, amount)?;
+				Self::log_transfer(currency_id, None, Some(who), amount);
+				Ok(())
+			}
+			_ => {
+				<T::MultiCurrency as MultiCurrency<_>>::deposit(currency_id, who, amount)?;
+				Self::log_transfer(currency_id, None, Some(who), amount);
+				Ok(())
+			}
 		}
 	}
 
@@ -506,9 +713,15 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 				Ok(())
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
-				<T::NativeCurrency as BasicCurrency<_>>::withdraw(who, amount, existence_requirement)
+				<T::NativeCurrency as BasicCurrency<_>>::withdraw(who, amount, existence_requirement)?;
+				Self::log_transfer(currency_id, Some(who), None, amount);
+				Ok(())
+			}
+			_ => {
+				<T::MultiCurrency as MultiCurrency<_>>::withdraw(currency_id, who, amount, existence_requirement)?;
+				Self::log_transfer(currency_id, Some(who), None, amount);
+				Ok(())
 			}
-			_ => <T::MultiCurrency as MultiCurrency<_>>::withdraw(currency_id, who, amount, existence_requirement),
 		}
 	}
 
@@ -523,11 +736,13 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	}
 
 	fn slash(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> Self::Balance {
-		match currency_id {
-			CurrencyId::Erc20(_) => Default::default(),
+		let remaining = match currency_id {
+			CurrencyId::Erc20(_) => return Default::default(),
 			id if id == T::GetNativeCurrencyId::get() => <T::NativeCurrency as BasicCurrency<_>>::slash(who, amount),
 			_ => <T::MultiCurrency as MultiCurrency<_>>::slash(currency_id, who, amount),
-		}
+		};
+		Self::log_transfer(currency_id, Some(who), None, amount.saturating_sub(remaining));
+		remaining
 	}
 }
 
@@ -544,13 +759,23 @@ impl<T: Config> MultiCurrencyExtended<T::AccountId> for Pallet<T> {
 				}
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
-				<T::NativeCurrency as BasicCurrencyExtended<_>>::update_balance(who, by_amount)
+				<T::NativeCurrency as BasicCurrencyExtended<_>>::update_balance(who, by_amount)?;
+				Self::log_balance_update(currency_id, who, by_amount);
+				Ok(())
+			}
+			_ => {
+				<T::MultiCurrency as MultiCurrencyExtended<_>>::update_balance(currency_id, who, by_amount)?;
+				Self::log_balance_update(currency_id, who, by_amount);
+				Ok(())
 			}
-			_ => <T::MultiCurrency as MultiCurrencyExtended<_>>::update_balance(currency_id, who, by_amount),
 		}
 	}
 }
 
+/// The ERC20 contracts can't freeze balances, so the ERC20 locks differ from the orml locks: the
+/// max of the locks of an account is escrowed in `Erc20HoldingAccount`. The locked balance is taken
+/// out of the free balance, so it can't be transferred nor reserved, and the locks and the reserves
+/// of an account add up instead of overlapping.
 impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 	type Moment = BlockNumberFor<T>;
 
@@ -561,7 +786,7 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => Self::update_erc20_lock(lock_id, contract, who, Some(amount)),
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::set_lock(lock_id, who, amount)
 			}
@@ -576,7 +801,10 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				let locked = Erc20Locks::<T>::get((currency_id, who.clone()), lock_id);
+				Self::update_erc20_lock(lock_id, contract, who, Some(locked.max(amount)))
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::extend_lock(lock_id, who, amount)
 			}
@@ -586,7 +814,7 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 
 	fn remove_lock(lock_id: LockIdentifier, currency_id: Self::CurrencyId, who: &T::AccountId) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => Self::update_erc20_lock(lock_id, contract, who, None),
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::remove_lock(lock_id, who)
 			}
@@ -607,13 +835,15 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 	}
 
 	fn slash_reserved(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
-		match currency_id {
-			CurrencyId::Erc20(_) => value,
+		let remaining = match currency_id {
+			CurrencyId::Erc20(_) => return value,
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicReservableCurrency<_>>::slash_reserved(who, value)
 			}
 			_ => <T::MultiCurrency as MultiReservableCurrency<_>>::slash_reserved(currency_id, who, value),
-		}
+		};
+		Self::log_transfer(currency_id, Some(who), None, value.saturating_sub(remaining));
+		remaining
 	}
 
 	fn reserved_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
@@ -711,7 +941,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 		value: Self::Balance,
 		status: BalanceStatus,
 	) -> result::Result<Self::Balance, DispatchError> {
-		match currency_id {
+		let remaining = match currency_id {
 			CurrencyId::Erc20(contract) => {
 				if value.is_zero() {
 					return Ok(value);
@@ -762,7 +992,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 						actual,
 					),
 				}?;
-				Ok(value - actual)
+				return Ok(value - actual);
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicReservableCurrency<_>>::repatriate_reserved(
@@ -770,7 +1000,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 					beneficiary,
 					value,
 					status,
-				)
+				)?
 			}
 			_ => <T::MultiCurrency as MultiReservableCurrency<_>>::repatriate_reserved(
 				currency_id,
@@ -778,8 +1008,15 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				beneficiary,
 				value,
 				status,
-			),
-		}
+			)?,
+		};
+		Self::log_transfer(
+			currency_id,
+			Some(slashed),
+			Some(beneficiary),
+			value.saturating_sub(remaining),
+		);
+		Ok(remaining)
 	}
 }
 
@@ -917,13 +1154,20 @@ impl<T: Config> fungibles::Unbalanced<T::AccountId> for Pallet<T> {
 		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> Result<Option<Self::Balance>, DispatchError> {
-		match asset_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+		ensure!(!asset_id.is_erc20_currency_id(), Error::<T>::Erc20InvalidOperation);
+		let previous = <Self as fungibles::Inspect<_>>::balance(asset_id, who);
+		let dust = match asset_id {
 			id if id == T::GetNativeCurrencyId::get() => {
-				<T::NativeCurrency as fungible::Unbalanced<_>>::write_balance(who, amount)
+				<T::NativeCurrency as fungible::Unbalanced<_>>::write_balance(who, amount)?
 			}
-			_ => <T::MultiCurrency as fungibles::Unbalanced<_>>::write_balance(asset_id, who, amount),
+			_ => <T::MultiCurrency as fungibles::Unbalanced<_>>::write_balance(asset_id, who, amount)?,
+		};
+		if amount > previous {
+			Self::log_transfer(asset_id, None, Some(who), amount - previous);
+		} else {
+			Self::log_transfer(asset_id, Some(who), None, previous - amount);
 		}
+		Ok(dust)
 	}
 
 	fn set_total_issuance(asset_id: Self::AssetId, amount: Self::Balance) {
@@ -946,9 +1190,15 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 		match asset_id {
 			CurrencyId::Erc20(_) => <Self as MultiCurrency<_>>::deposit(asset_id, who, amount).map(|_| amount),
 			id if id == T::GetNativeCurrencyId::get() => {
-				<T::NativeCurrency as fungible::Mutate<_>>::mint_into(who, amount)
+				<T::NativeCurrency as fungible::Mutate<_>>::mint_into(who, amount).map(|actual| {
+					Self::log_transfer(asset_id, None, Some(who), actual);
+					actual
+				})
 			}
-			_ => <T::MultiCurrency as fungibles::Mutate<_>>::mint_into(asset_id, who, amount),
+			_ => <T::MultiCurrency as fungibles::Mutate<_>>::mint_into(asset_id, who, amount).map(|actual| {
+				Self::log_transfer(asset_id, None, Some(who), actual);
+				actual
+			}),
 		}
 	}
 
@@ -970,6 +1220,10 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as fungible::Mutate<_>>::burn_from(who, amount, preservation, precision, fortitude)
+					.map(|actual| {
+						Self::log_transfer(asset_id, Some(who), None, actual);
+						actual
+					})
 			}
 			_ => <T::MultiCurrency as fungibles::Mutate<_>>::burn_from(
 				asset_id,
@@ -978,7 +1232,11 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 				preservation,
 				precision,
 				fortitude,
-			),
+			)
+			.map(|actual| {
+				Self::log_transfer(asset_id, Some(who), None, actual);
+				actual
+			}),
 		}
 	}
 
@@ -1007,6 +1265,7 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 						to: dest.clone(),
 						amount: actual,
 					});
+					Self::log_transfer(asset_id, Some(source), Some(dest), actual);
 					actual
 				})
 			}
@@ -1018,6 +1277,7 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 						to: dest.clone(),
 						amount: actual,
 					});
+					Self::log_transfer(asset_id, Some(source), Some(dest), actual);
 					actual
 				}),
 		}
@@ -1030,7 +1290,7 @@ impl<T: Config> fungibles::InspectHold<T::AccountId> for Pallet<T> {
 
 	fn balance_on_hold(asset_id: Self::AssetId, reason: &Self::Reason, who: &T::AccountId) -> Self::Balance {
 		match asset_id {
-			CurrencyId::Erc20(_) => <Self as MultiReservableCurrency<_>>::reserved_balance(asset_id, who),
+			CurrencyId::Erc20(_) => Self::erc20_balance_on_hold(asset_id, reason, who),
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as fungible::InspectHold<_>>::balance_on_hold(reason, who)
 			}
@@ -1087,7 +1347,17 @@ impl<T: Config> fungibles::UnbalancedHold<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match asset_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(_) => {
+				let on_hold = Self::erc20_balance_on_hold(asset_id, reason, who);
+				if amount > on_hold {
+					<Self as MultiReservableCurrency<_>>::reserve(asset_id, who, amount - on_hold)?;
+				} else {
+					let gap = <Self as MultiReservableCurrency<_>>::unreserve(asset_id, who, on_hold - amount);
+					ensure!(gap.is_zero(), Error::<T>::BalanceTooLow);
+				}
+				Self::set_erc20_balance_on_hold(asset_id, reason, who, amount);
+				Ok(())
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as fungible::UnbalancedHold<_>>::set_balance_on_hold(reason, who, amount)
 			}
@@ -1104,7 +1374,13 @@ impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match asset_id {
-			CurrencyId::Erc20(_) => <Self as MultiReservableCurrency<_>>::reserve(asset_id, who, amount),
+			CurrencyId::Erc20(_) => {
+				let on_hold = Self::erc20_balance_on_hold(asset_id, reason, who);
+				let new_on_hold = on_hold.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+				<Self as MultiReservableCurrency<_>>::reserve(asset_id, who, amount)?;
+				Self::set_erc20_balance_on_hold(asset_id, reason, who, new_on_hold);
+				Ok(())
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as fungible::MutateHold<_>>::hold(reason, who, amount)
 			}
@@ -1124,13 +1400,16 @@ impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {
 				if amount.is_zero() {
 					return Ok(amount);
 				}
+				let on_hold = Self::erc20_balance_on_hold(asset_id, reason, who);
 				ensure!(
-					precision == Precision::BestEffort
-						|| amount <= <Self as MultiReservableCurrency<_>>::reserved_balance(asset_id, who),
+					precision == Precision::BestEffort || amount <= on_hold,
 					Error::<T>::BalanceTooLow
 				);
+				let amount = amount.min(on_hold);
 				let gap = <Self as MultiReservableCurrency<_>>::unreserve(asset_id, who, amount);
-				Ok(amount.saturating_sub(gap))
+				let actual = amount.saturating_sub(gap);
+				Self::set_erc20_balance_on_hold(asset_id, reason, who, on_hold.saturating_sub(actual));
+				Ok(actual)
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as fungible::MutateHold<_>>::release(reason, who, amount, precision)
@@ -1154,11 +1433,12 @@ impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {
 				if amount.is_zero() {
 					return Ok(amount);
 				}
+				let on_hold = Self::erc20_balance_on_hold(asset_id, reason, source);
 				ensure!(
-					precision == Precision::BestEffort
-						|| amount <= <Self as fungibles::InspectHold<_>>::balance_on_hold(asset_id, reason, source),
+					precision == Precision::BestEffort || amount <= on_hold,
 					Error::<T>::BalanceTooLow
 				);
+				let amount = amount.min(on_hold);
 
 				let status = match restriction {
 					Restriction::Free => Status::Free,
@@ -1166,7 +1446,15 @@ impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {
 				};
 				let gap =
 					<Self as MultiReservableCurrency<_>>::repatriate_reserved(asset_id, source, dest, amount, status)?;
-				Ok(amount.saturating_sub(gap))
+				let actual = amount.saturating_sub(gap);
+				if source != dest || status == Status::Free {
+					Self::set_erc20_balance_on_hold(asset_id, reason, source, on_hold.saturating_sub(actual));
+				}
+				if source != dest && status == Status::Reserved {
+					let dest_on_hold = Self::erc20_balance_on_hold(asset_id, reason, dest);
+					Self::set_erc20_balance_on_hold(asset_id, reason, dest, dest_on_hold.saturating_add(actual));
+				}
+				Ok(actual)
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as fungible::MutateHold<_>>::transfer_on_hold(
@@ -1178,6 +1466,10 @@ impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {
 					restriction,
 					fortitude,
 				)
+				.map(|actual| {
+					Self::log_transfer(asset_id, Some(source), Some(dest), actual);
+					actual
+				})
 			}
 			_ => <T::MultiCurrency as fungibles::MutateHold<_>>::transfer_on_hold(
 				asset_id,
@@ -1188,7 +1480,11 @@ impl<T: Config> fungibles::MutateHold<T::AccountId> for Pallet<T> {
 				precision,
 				restriction,
 				fortitude,
-			),
+			)
+			.map(|actual| {
+				Self::log_transfer(asset_id, Some(source), Some(dest), actual);
+				actual
+			}),
 		}
 	}
 }
@@ -1884,3 +2180,99 @@ where
 		};
 	}
 }
+
+/// Emits the ERC20 `Transfer` logs of the native transfers dispatched straight to
+/// `pallet_balances`, which bypass the currency traits of this pallet. Only the top-level calls
+/// of the extrinsics are logged.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct LogNativeTransfer<T: Config + Send + Sync>(marker::PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for LogNativeTransfer<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "LogNativeTransfer")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> LogNativeTransfer<T> {
+	pub fn new() -> Self {
+		Self(marker::PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for LogNativeTransfer<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> SignedExtension for LogNativeTransfer<T>
+where
+	T: Config + pallet_balances::Config<Balance = BalanceOf<T>> + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<pallet_balances::Call<T>>,
+{
+	const IDENTIFIER: &'static str = "LogNativeTransfer";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	/// The `from`, `to` and amount of the native transfer.
+	type Pre = Option<(T::AccountId, T::AccountId, BalanceOf<T>)>;
+
+	fn additional_signed(&self) -> result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let transfer = match call.is_sub_type() {
+			Some(pallet_balances::Call::transfer_allow_death { dest, value })
+			| Some(pallet_balances::Call::transfer_keep_alive { dest, value }) => {
+				T::Lookup::lookup(dest.clone()).ok().map(|to| (who.clone(), to, *value))
+			}
+			Some(pallet_balances::Call::force_transfer { source, dest, value }) => T::Lookup::lookup(source.clone())
+				.ok()
+				.zip(T::Lookup::lookup(dest.clone()).ok())
+				.map(|(from, to)| (from, to, *value)),
+			Some(pallet_balances::Call::transfer_all { dest, keep_alive }) => {
+				// same as `transfer_all`, the fee is already paid
+				let preservation = if *keep_alive {
+					Preservation::Preserve
+				} else {
+					Preservation::Expendable
+				};
+				let amount = <pallet_balances::Pallet<T> as fungible::Inspect<T::AccountId>>::reducible_balance(
+					who,
+					preservation,
+					Fortitude::Polite,
+				);
+				T::Lookup::lookup(dest.clone()).ok().map(|to| (who.clone(), to, amount))
+			}
+			_ => None,
+		};
+		Ok(transfer)
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let (Some(Some((from, to, amount))), Ok(())) = (pre, result) {
+			Pallet::<T>::log_transfer(T::GetNativeCurrencyId::get(), Some(&from), Some(&to), amount);
+		}
+		Ok(())
+	}
+}
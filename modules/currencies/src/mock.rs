@@ -30,7 +30,7 @@ use frame_support::{
 };
 use frame_system::EnsureSignedBy;
 use module_support::{
-	mocks::{MockAddressMapping, TestRandomness},
+	mocks::{MockAddressMapping, MockErc20InfoMapping, TestRandomness},
 	AddressMapping,
 };
 use orml_traits::{currency::MutationHooks, parameter_type_with_key};
@@ -112,10 +112,11 @@ parameter_types! {
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, MaxEncodedLen, TypeInfo, RuntimeDebug)]
 pub enum TestId {
 	Foo,
+	Bar,
 }
 
 impl VariantCount for TestId {
-	const VARIANT_COUNT: u32 = 1;
+	const VARIANT_COUNT: u32 = 2;
 }
 
 impl pallet_balances::Config for Runtime {
@@ -171,6 +172,8 @@ impl module_evm::Config for Runtime {
 	type NewContractExtraBytes = ConstU32<1>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = TxFeePerGas;
+	type MinBaseFeePerGas = ConstU128<10>;
+	type BaseFeeGasTarget = ConstU64<15_000_000>;
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType = ();
 	type PrecompilesValue = ();
@@ -212,6 +215,7 @@ impl Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = crate::TransferDust<Runtime, DustAccount>;
+	type Erc20TransferLog = module_evm::Erc20TransferLogger<Runtime, MockErc20InfoMapping>;
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
@@ -258,6 +262,7 @@ pub fn eva_evm_addr() -> EvmAddress {
 }
 
 pub const ID_1: LockIdentifier = *b"1       ";
+pub const ID_2: LockIdentifier = *b"2       ";
 
 pub fn erc20_address() -> EvmAddress {
 	EvmAddress::from_str("0x5dddfce53ee040d9eb21afbc0ae1bb4dbb0ba643").unwrap()
@@ -26,14 +26,15 @@ use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo, traits::W
 use mock::{
 	alice, bob, deploy_contracts, erc20_address, erc20_address_not_exist, eva, AccountId, AdaptedBasicCurrency,
 	Balances, CouncilAccount, Currencies, DustAccount, ExtBuilder, NativeCurrency, PalletBalances, Runtime,
-	RuntimeEvent, RuntimeOrigin, System, TestId, Tokens, ALICE_BALANCE, CHARLIE, DAVE, DOT, EVE, EVM, FERDIE, ID_1,
-	NATIVE_CURRENCY_ID, X_TOKEN_ID,
+	RuntimeCall, RuntimeEvent, RuntimeOrigin, System, TestId, Tokens, ALICE_BALANCE, CHARLIE, DAVE, DOT, EVE, EVM,
+	FERDIE, ID_1, ID_2, NATIVE_CURRENCY_ID, X_TOKEN_ID,
 };
 use module_support::mocks::MockAddressMapping;
 use module_support::EVM as EVMTrait;
-use sp_core::H160;
+use primitives::evm::ERC20_TRANSFER_TOPIC;
+use sp_core::{H160, H256};
 use sp_runtime::{
-	traits::{BadOrigin, Bounded},
+	traits::{BadOrigin, Bounded, Dispatchable},
 	ModuleError, TokenError,
 };
 
@@ -688,7 +689,7 @@ fn call_event_should_work() {
 				100
 			));
 			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 140);
-			System::assert_last_event(RuntimeEvent::Tokens(orml_tokens::Event::Deposited {
+			System::assert_has_event(RuntimeEvent::Tokens(orml_tokens::Event::Deposited {
 				currency_id: X_TOKEN_ID,
 				who: alice(),
 				amount: 100,
@@ -701,7 +702,7 @@ fn call_event_should_work() {
 				ExistenceRequirement::AllowDeath
 			));
 			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 120);
-			System::assert_last_event(RuntimeEvent::Tokens(orml_tokens::Event::Withdrawn {
+			System::assert_has_event(RuntimeEvent::Tokens(orml_tokens::Event::Withdrawn {
 				currency_id: X_TOKEN_ID,
 				who: alice(),
 				amount: 20,
@@ -1042,24 +1043,184 @@ fn erc20_should_not_slash() {
 }
 
 #[test]
-fn erc20_should_not_be_lockable() {
+fn erc20_lock_should_work() {
 	ExtBuilder::default()
 		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 200000)])
 		.build()
 		.execute_with(|| {
 			deploy_contracts();
+			let currency_id = CurrencyId::Erc20(erc20_address());
+			let erc20_holding_account = MockAddressMapping::get_account_id(&Erc20HoldingAccount::get());
+
+			assert_ok!(Currencies::set_lock(ID_1, currency_id, &alice(), 100));
+			assert_eq!(Currencies::erc20_locks((currency_id, alice()), ID_1), 100);
+			assert_eq!(Currencies::erc20_locked_balance(currency_id, &alice()), 100);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 100);
+			assert_eq!(Currencies::total_balance(currency_id, &alice()), ALICE_BALANCE);
+			assert_eq!(Currencies::free_balance(currency_id, &erc20_holding_account), 100);
+
+			// only the max of the locks is escrowed
+			assert_ok!(Currencies::set_lock(ID_2, currency_id, &alice(), 60));
+			assert_eq!(Currencies::erc20_locked_balance(currency_id, &alice()), 100);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 100);
+
+			assert_ok!(Currencies::extend_lock(ID_2, currency_id, &alice(), 50));
+			assert_eq!(Currencies::erc20_locks((currency_id, alice()), ID_2), 60);
+			assert_ok!(Currencies::extend_lock(ID_2, currency_id, &alice(), 150));
+			assert_eq!(Currencies::erc20_locks((currency_id, alice()), ID_2), 150);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 150);
+			assert_eq!(Currencies::free_balance(currency_id, &erc20_holding_account), 150);
+
+			assert_ok!(Currencies::remove_lock(ID_2, currency_id, &alice()));
+			assert_eq!(Currencies::erc20_locked_balance(currency_id, &alice()), 100);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 100);
+
+			// set lock to zero removes the lock
+			assert_ok!(Currencies::set_lock(ID_1, currency_id, &alice(), 0));
+			assert_eq!(Erc20Locks::<Runtime>::contains_key((currency_id, alice()), ID_1), false);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE);
+			assert_eq!(Currencies::free_balance(currency_id, &erc20_holding_account), 0);
+
+			// lock more than the free balance
+			assert!(Currencies::set_lock(ID_1, currency_id, &alice(), ALICE_BALANCE + 1).is_err());
+			assert_eq!(Currencies::erc20_locked_balance(currency_id, &alice()), 0);
+
 			assert_noop!(
-				Currencies::set_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::set_lock(ID_1, currency_id, &DAVE, 1),
+				Error::<Runtime>::EvmAccountNotFound
 			);
-			assert_noop!(
-				Currencies::extend_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
+		});
+}
+
+#[test]
+fn erc20_locks_escrow_the_locked_balance() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 200000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let currency_id = CurrencyId::Erc20(erc20_address());
+			let erc20_holding_account = MockAddressMapping::get_account_id(&Erc20HoldingAccount::get());
+
+			// unlike orml locks, the locked balance can't be reserved or transferred
+			assert_ok!(Currencies::set_lock(ID_1, currency_id, &alice(), 100));
+			assert!(!Currencies::can_reserve(currency_id, &alice(), ALICE_BALANCE));
+			assert!(Currencies::reserve(currency_id, &alice(), ALICE_BALANCE).is_err());
+			assert!(<Currencies as MultiCurrency<_>>::transfer(
+				currency_id,
+				&alice(),
+				&bob(),
+				ALICE_BALANCE,
+				ExistenceRequirement::AllowDeath
+			)
+			.is_err());
+
+			// the overlapping locks and reserves add up
+			assert_ok!(Currencies::reserve(currency_id, &alice(), 50));
+			assert_ok!(Currencies::set_lock(ID_2, currency_id, &alice(), 80));
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 150);
+			assert_eq!(Currencies::total_balance(currency_id, &alice()), ALICE_BALANCE);
+			assert_eq!(Currencies::free_balance(currency_id, &erc20_holding_account), 150);
+
+			// extending a lock escrows the difference only, and fails without enough free balance
+			assert_ok!(Currencies::extend_lock(ID_2, currency_id, &alice(), 120));
+			assert_eq!(Currencies::erc20_locked_balance(currency_id, &alice()), 120);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 170);
+			assert!(Currencies::extend_lock(ID_2, currency_id, &alice(), ALICE_BALANCE).is_err());
+			assert_eq!(Currencies::erc20_locks((currency_id, alice()), ID_2), 120);
+			assert_eq!(Currencies::free_balance(currency_id, &erc20_holding_account), 170);
+
+			// removing the locks refunds the escrow, the reserve stays
+			assert_ok!(Currencies::remove_lock(ID_1, currency_id, &alice()));
+			assert_ok!(Currencies::remove_lock(ID_2, currency_id, &alice()));
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 50);
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 50);
+			assert_eq!(Currencies::free_balance(currency_id, &erc20_holding_account), 50);
+		});
+}
+
+#[test]
+fn erc20_set_balance_on_hold_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 200000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let currency_id = CurrencyId::Erc20(erc20_address());
+
+			assert_ok!(<Currencies as fungibles::UnbalancedHold<_>>::set_balance_on_hold(
+				currency_id,
+				&TestId::Foo,
+				&alice(),
+				100
+			));
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 100);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 100);
+
+			assert_ok!(<Currencies as fungibles::UnbalancedHold<_>>::set_balance_on_hold(
+				currency_id,
+				&TestId::Foo,
+				&alice(),
+				40
+			));
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 40);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 40);
+
+			// the holds of different reasons are independent
+			assert_ok!(<Currencies as fungibles::MutateHold<_>>::hold(
+				currency_id,
+				&TestId::Bar,
+				&alice(),
+				30
+			));
+			assert_eq!(
+				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(currency_id, &TestId::Foo, &alice()),
+				40
+			);
+			assert_eq!(
+				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(currency_id, &TestId::Bar, &alice()),
+				30
+			);
+			assert_eq!(
+				<Currencies as fungibles::InspectHold<_>>::total_balance_on_hold(currency_id, &alice()),
+				70
 			);
+
+			assert_ok!(<Currencies as fungibles::UnbalancedHold<_>>::set_balance_on_hold(
+				currency_id,
+				&TestId::Foo,
+				&alice(),
+				0
+			));
+			assert_eq!(
+				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(currency_id, &TestId::Bar, &alice()),
+				30
+			);
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 30);
+
+			// can't release more than the hold of the reason
 			assert_noop!(
-				Currencies::remove_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice()),
-				Error::<Runtime>::Erc20InvalidOperation
+				<Currencies as fungibles::MutateHold<_>>::release(
+					currency_id,
+					&TestId::Foo,
+					&alice(),
+					1,
+					Precision::Exact
+				),
+				Error::<Runtime>::BalanceTooLow
 			);
+			assert_eq!(
+				<Currencies as fungibles::MutateHold<_>>::release(
+					currency_id,
+					&TestId::Bar,
+					&alice(),
+					50,
+					Precision::BestEffort
+				),
+				Ok(30)
+			);
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 0);
+			assert_eq!(Erc20Holds::<Runtime>::iter_prefix((currency_id, alice())).count(), 0);
 		});
 }
 
@@ -1765,7 +1926,7 @@ fn fungible_mutate_trait_should_work() {
 				&alice(),
 				1000
 			));
-			System::assert_last_event(RuntimeEvent::Balances(pallet_balances::Event::Minted {
+			System::assert_has_event(RuntimeEvent::Balances(pallet_balances::Event::Minted {
 				who: alice(),
 				amount: 1000,
 			}));
@@ -1791,7 +1952,7 @@ fn fungible_mutate_trait_should_work() {
 				&alice(),
 				1000
 			));
-			System::assert_last_event(RuntimeEvent::Tokens(orml_tokens::Event::Deposited {
+			System::assert_has_event(RuntimeEvent::Tokens(orml_tokens::Event::Deposited {
 				currency_id: X_TOKEN_ID,
 				who: alice(),
 				amount: 1000,
@@ -1845,7 +2006,7 @@ fn fungible_mutate_trait_should_work() {
 				Precision::Exact,
 				Fortitude::Force,
 			));
-			System::assert_last_event(RuntimeEvent::Balances(pallet_balances::Event::Burned {
+			System::assert_has_event(RuntimeEvent::Balances(pallet_balances::Event::Burned {
 				who: alice(),
 				amount: 1000,
 			}));
@@ -1874,7 +2035,7 @@ fn fungible_mutate_trait_should_work() {
 				Precision::Exact,
 				Fortitude::Force,
 			));
-			System::assert_last_event(RuntimeEvent::Tokens(orml_tokens::Event::Withdrawn {
+			System::assert_has_event(RuntimeEvent::Tokens(orml_tokens::Event::Withdrawn {
 				currency_id: X_TOKEN_ID,
 				who: alice(),
 				amount: 1000,
@@ -2133,7 +2294,7 @@ fn fungible_unbalanced_trait_should_work() {
 				&alice(),
 				80000
 			));
-			System::assert_last_event(RuntimeEvent::Tokens(orml_tokens::Event::BalanceSet {
+			System::assert_has_event(RuntimeEvent::Tokens(orml_tokens::Event::BalanceSet {
 				currency_id: X_TOKEN_ID,
 				who: alice(),
 				free: 80000,
@@ -2557,7 +2718,7 @@ fn fungible_inspect_hold_and_hold_trait_should_work() {
 				),
 				Ok(2000)
 			);
-			System::assert_last_event(RuntimeEvent::Tokens(orml_tokens::Event::ReserveRepatriated {
+			System::assert_has_event(RuntimeEvent::Tokens(orml_tokens::Event::ReserveRepatriated {
 				currency_id: X_TOKEN_ID,
 				from: alice(),
 				to: bob(),
@@ -3061,3 +3222,204 @@ fn transfer_erc20_will_charge_gas() {
 		);
 	});
 }
+
+#[test]
+fn erc20_transfer_log_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			let token_address = |currency_id: CurrencyId| EvmAddress::try_from(currency_id).unwrap();
+			let topic = |who: &AccountId| H256::from(MockAddressMapping::get_or_create_evm_address(who));
+			let data = |amount: u128| H256::from_low_u64_be(amount as u64).as_bytes().to_vec();
+
+			assert_ok!(Currencies::transfer(Some(alice()).into(), bob(), X_TOKEN_ID, 50));
+			System::assert_last_event(RuntimeEvent::EVM(module_evm::Event::Logged {
+				log: module_evm::Log {
+					address: token_address(X_TOKEN_ID),
+					topics: vec![ERC20_TRANSFER_TOPIC, topic(&alice()), topic(&bob())],
+					data: data(50),
+				},
+			}));
+
+			assert_ok!(Currencies::transfer_native_currency(Some(alice()).into(), bob(), 10));
+			System::assert_last_event(RuntimeEvent::EVM(module_evm::Event::Logged {
+				log: module_evm::Log {
+					address: token_address(NATIVE_CURRENCY_ID),
+					topics: vec![ERC20_TRANSFER_TOPIC, topic(&alice()), topic(&bob())],
+					data: data(10),
+				},
+			}));
+
+			// mint
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				X_TOKEN_ID,
+				20
+			));
+			System::assert_last_event(RuntimeEvent::EVM(module_evm::Event::Logged {
+				log: module_evm::Log {
+					address: token_address(X_TOKEN_ID),
+					topics: vec![ERC20_TRANSFER_TOPIC, H256::zero(), topic(&alice())],
+					data: data(20),
+				},
+			}));
+
+			// balance changes made by other modules through the currency traits are logged as well
+			assert_ok!(Currencies::deposit(X_TOKEN_ID, &bob(), 5));
+			System::assert_last_event(RuntimeEvent::EVM(module_evm::Event::Logged {
+				log: module_evm::Log {
+					address: token_address(X_TOKEN_ID),
+					topics: vec![ERC20_TRANSFER_TOPIC, H256::zero(), topic(&bob())],
+					data: data(5),
+				},
+			}));
+
+			assert_ok!(Currencies::withdraw(
+				NATIVE_CURRENCY_ID,
+				&bob(),
+				3,
+				ExistenceRequirement::AllowDeath
+			));
+			System::assert_last_event(RuntimeEvent::EVM(module_evm::Event::Logged {
+				log: module_evm::Log {
+					address: token_address(NATIVE_CURRENCY_ID),
+					topics: vec![ERC20_TRANSFER_TOPIC, topic(&bob()), H256::zero()],
+					data: data(3),
+				},
+			}));
+
+			assert_ok!(<Currencies as fungibles::Mutate<_>>::transfer(
+				X_TOKEN_ID,
+				&bob(),
+				&alice(),
+				7,
+				Preservation::Expendable
+			));
+			System::assert_last_event(RuntimeEvent::EVM(module_evm::Event::Logged {
+				log: module_evm::Log {
+					address: token_address(X_TOKEN_ID),
+					topics: vec![ERC20_TRANSFER_TOPIC, topic(&bob()), topic(&alice())],
+					data: data(7),
+				},
+			}));
+		});
+}
+
+#[test]
+fn native_balances_transfer_log_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			let transfer_log = |from: &AccountId, to: &AccountId, amount: u128| {
+				RuntimeEvent::EVM(module_evm::Event::Logged {
+					log: module_evm::Log {
+						address: EvmAddress::try_from(NATIVE_CURRENCY_ID).unwrap(),
+						topics: vec![
+							ERC20_TRANSFER_TOPIC,
+							H256::from(MockAddressMapping::get_or_create_evm_address(from)),
+							H256::from(MockAddressMapping::get_or_create_evm_address(to)),
+						],
+						data: H256::from_low_u64_be(amount as u64).as_bytes().to_vec(),
+					},
+				})
+			};
+			let dispatch_signed = |who: AccountId, call: RuntimeCall| {
+				let info = call.get_dispatch_info();
+				let pre = LogNativeTransfer::<Runtime>::new()
+					.pre_dispatch(&who, &call, &info, 0)
+					.unwrap();
+				let (post_info, result) = match call.dispatch(RuntimeOrigin::signed(who)) {
+					Ok(post_info) => (post_info, Ok(())),
+					Err(e) => (e.post_info, Err(e.error)),
+				};
+				assert_ok!(LogNativeTransfer::<Runtime>::post_dispatch(
+					Some(pre),
+					&info,
+					&post_info,
+					0,
+					&result
+				));
+			};
+
+			dispatch_signed(
+				alice(),
+				RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { dest: bob(), value: 10 }),
+			);
+			assert_eq!(PalletBalances::free_balance(bob()), 110);
+			System::assert_last_event(transfer_log(&alice(), &bob(), 10));
+
+			dispatch_signed(
+				alice(),
+				RuntimeCall::Balances(pallet_balances::Call::transfer_all {
+					dest: eva(),
+					keep_alive: true,
+				}),
+			);
+			assert_eq!(PalletBalances::free_balance(eva()), 88);
+			System::assert_last_event(transfer_log(&alice(), &eva(), 88));
+
+			// failed transfers are not logged
+			System::reset_events();
+			dispatch_signed(
+				bob(),
+				RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+					dest: alice(),
+					value: 1_000,
+				}),
+			);
+			assert!(System::events().is_empty());
+		});
+}
+
+#[test]
+fn approve_and_transfer_from_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			Currencies::approve(X_TOKEN_ID, &alice(), &bob(), 30);
+			assert_eq!(Currencies::allowances((X_TOKEN_ID, alice()), bob()), 30);
+			System::assert_last_event(RuntimeEvent::Currencies(crate::Event::Approved {
+				currency_id: X_TOKEN_ID,
+				owner: alice(),
+				spender: bob(),
+				amount: 30,
+			}));
+
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &eva(), 20));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 80);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 20);
+			assert_eq!(Currencies::allowances((X_TOKEN_ID, alice()), bob()), 10);
+
+			assert_noop!(
+				Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &eva(), 11),
+				Error::<Runtime>::AllowanceTooLow
+			);
+			assert_noop!(
+				Currencies::transfer_from(X_TOKEN_ID, &eva(), &alice(), &eva(), 1),
+				Error::<Runtime>::AllowanceTooLow
+			);
+
+			// unlimited allowance is never spent
+			Currencies::approve(X_TOKEN_ID, &alice(), &bob(), Bounded::max_value());
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &eva(), 50));
+			assert_eq!(
+				Currencies::allowances((X_TOKEN_ID, alice()), bob()),
+				Bounded::max_value()
+			);
+		});
+}
+
+#[test]
+fn use_permit_nonce_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Currencies::permit_nonces(X_TOKEN_ID, alice()), 0);
+		assert_eq!(Currencies::use_permit_nonce(X_TOKEN_ID, &alice()), 0);
+		assert_eq!(Currencies::use_permit_nonce(X_TOKEN_ID, &alice()), 1);
+		assert_eq!(Currencies::permit_nonces(X_TOKEN_ID, alice()), 2);
+		assert_eq!(Currencies::permit_nonces(DOT, alice()), 0);
+	});
+}
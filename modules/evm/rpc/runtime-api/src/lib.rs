@@ -19,8 +19,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
 
-use primitives::evm::{AccessListItem, BlockLimits, CallInfo, CreateInfo, EstimateResourcesRequest};
-use sp_core::H160;
+use primitives::evm::{
+	AccessListInfo, AccessListItem, AccountEntries, BlockLimits, CallInfo, CreateInfo, EstimateResourcesRequest,
+	FeeHistory, StateOverride,
+};
+use sp_core::{H160, H256};
 use sp_runtime::{
 	codec::Codec,
 	traits::{MaybeDisplay, MaybeFromStr},
@@ -28,11 +31,12 @@ use sp_runtime::{
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(6)]
 	pub trait EVMRuntimeRPCApi<Balance, AccountId> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 		AccountId: Codec + MaybeDisplay + MaybeFromStr,
 	{
+		#[changed_in(4)]
 		fn call(
 			from: H160,
 			to: H160,
@@ -44,6 +48,29 @@ sp_api::decl_runtime_apis! {
 			estimate: bool,
 		) -> Result<CallInfo, sp_runtime::DispatchError>;
 
+		fn call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			estimate: bool,
+			state_overrides: Option<Vec<(H160, StateOverride<Balance>)>>,
+		) -> Result<CallInfo, sp_runtime::DispatchError>;
+
+		#[changed_in(4)]
+		fn create(
+			from: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			estimate: bool,
+		) -> Result<CreateInfo, sp_runtime::DispatchError>;
+
 		fn create(
 			from: H160,
 			data: Vec<u8>,
@@ -52,6 +79,7 @@ sp_api::decl_runtime_apis! {
 			storage_limit: u32,
 			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
+			state_overrides: Option<Vec<(H160, StateOverride<Balance>)>>,
 		) -> Result<CreateInfo, sp_runtime::DispatchError>;
 
 		fn get_estimate_resources_request(data: Vec<u8>) -> Result<EstimateResourcesRequest, sp_runtime::DispatchError>;
@@ -78,15 +106,60 @@ sp_api::decl_runtime_apis! {
 			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
 		) -> Result<CreateInfo, sp_runtime::DispatchError>;
+
+		/// Generate the access list of a call, or a create if `to` is `None`, like `eth_createAccessList`.
+		#[api_version(3)]
+		fn create_access_list(
+			from: H160,
+			to: Option<H160>,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+		) -> Result<AccessListInfo, sp_runtime::DispatchError>;
+
+		/// The base fee and EVM gas used of the latest `block_count` blocks, like `eth_feeHistory`.
+		#[api_version(5)]
+		fn fee_history(block_count: u32) -> FeeHistory;
+
+		/// The `Accounts`, `CodeInfos`, `Codes` and `AccountStorages` entries of `address`. The Merkle
+		/// proof of the entries against the state root is fetched with `state_getReadProof`.
+		#[api_version(6)]
+		fn account_entries(address: H160, storage_keys: Vec<H256>) -> AccountEntries;
 	}
 }
 
 #[cfg(feature = "tracing")]
 sp_api::decl_runtime_apis! {
-	pub trait EVMTraceApi {
+	#[api_version(2)]
+	pub trait EVMTraceApi<Balance> where
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+	{
 		fn trace_extrinsic(
 			extrinsic: Block::Extrinsic,
 			tracer_config: primitives::evm::tracing::TracerConfig,
 		) -> Result<primitives::evm::tracing::TraceOutcome, sp_runtime::transaction_validity::TransactionValidityError>;
+
+		/// Trace a call, or a create if `to` is `None`, without signing a transaction.
+		#[api_version(2)]
+		fn trace_call(
+			from: H160,
+			to: Option<H160>,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<primitives::evm::tracing::TraceOutcome, sp_runtime::DispatchError>;
+
+		/// Replay `block` on top of its parent and trace the extrinsics executing the EVM.
+		/// Returns the index of each traced extrinsic in the block along with its trace.
+		#[api_version(2)]
+		fn trace_block(
+			block: Block,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<Vec<(u32, primitives::evm::tracing::TraceOutcome)>, sp_runtime::transaction_validity::TransactionValidityError>;
 	}
 }
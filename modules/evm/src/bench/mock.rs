@@ -180,6 +180,8 @@ impl Config for Runtime {
 	type NewContractExtraBytes = ConstU32<100>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<20_000_000>;
+	type MinBaseFeePerGas = ConstU128<20_000_000>;
+	type BaseFeeGasTarget = ConstU64<15_000_000>;
 
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType = ();
@@ -34,9 +34,10 @@ use frame_support::{
 	error::BadOrigin,
 	pallet_prelude::*,
 	parameter_types,
+	storage::StoragePrefixedMap,
 	traits::{
 		BalanceStatus, Currency, EitherOfDiverse, EnsureOrigin, ExistenceRequirement, FindAuthor, Get,
-		NamedReservableCurrency, OnKilledAccount, Randomness,
+		NamedReservableCurrency, OnKilledAccount, Randomness, WithdrawReasons,
 	},
 	transactional,
 	weights::Weight,
@@ -54,15 +55,16 @@ pub use module_evm_utility::{
 	Account,
 };
 pub use module_support::{
-	AddressMapping, DispatchableTask, EVMManager, ExecutionMode, IdleScheduler, InvokeContext, TransactionPayment,
-	EVM as EVMTrait,
+	AddressMapping, DispatchableTask, EVMManager, Erc20InfoMapping, Erc20TransferLog, ExecutionMode, IdleScheduler,
+	InvokeContext, TransactionPayment, EVM as EVMTrait,
 };
 pub use orml_traits::{currency::TransferAll, MultiCurrency};
 use parity_scale_codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 pub use primitives::{
 	evm::{
-		convert_decimals_from_evm, convert_decimals_to_evm, decode_gas_limit, is_system_contract, CallInfo, CreateInfo,
-		EvmAddress, ExecutionInfo, Vicinity, MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START,
+		convert_decimals_from_evm, convert_decimals_to_evm, decode_gas_limit, is_system_contract, AccountEntries,
+		CallInfo, CreateInfo, EvmAddress, ExecutionInfo, FeeHistory, StateOverride, StorageEntry, StorageKeyHasher,
+		Vicinity, ERC20_TRANSFER_TOPIC, MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START,
 	},
 	task::TaskResult,
 	Balance, CurrencyId, Nonce, ReserveIdentifier,
@@ -96,6 +98,12 @@ pub const STORAGE_SIZE: u32 = 64;
 pub const REMOVE_LIMIT: u32 = 100;
 /// Immediate remove contract item limit 50 DB writes
 pub const IMMEDIATE_REMOVE_LIMIT: u32 = 50;
+/// Number of recent blocks kept in `BlockFees`
+pub const FEE_HISTORY_LENGTH: u32 = 1024;
+/// Bound of the base fee change between two blocks, 1/8 as EIP-1559
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+/// Max number of executions to generate an access list
+pub const MAX_ACCESS_LIST_ITERATIONS: u32 = 10;
 
 /// Type alias for currency balance.
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -104,13 +112,13 @@ pub type NegativeImbalanceOf<T> =
 pub const RESERVE_ID_STORAGE_DEPOSIT: ReserveIdentifier = ReserveIdentifier::EvmStorageDeposit;
 pub const RESERVE_ID_DEVELOPER_DEPOSIT: ReserveIdentifier = ReserveIdentifier::EvmDeveloperDeposit;
 
-// Initially based on shanghai hard fork configuration.
+// Initially based on cancun hard fork configuration.
 static ACALA_CONFIG: EvmConfig = EvmConfig {
 	refund_sstore_clears: 0,            // no gas refund
 	sstore_gas_metering: false,         // no gas refund
 	sstore_revert_under_stipend: false, // ignored
 	create_contract_limit: Some(MaxCodeSize::get() as usize),
-	..module_evm_utility::evm::Config::shanghai()
+	..module_evm_utility::evm::Config::cancun()
 };
 
 /// Create an empty contract `contract Empty { }`.
@@ -197,6 +205,16 @@ pub mod module {
 		#[pallet::constant]
 		type TxFeePerGas: Get<BalanceOf<Self>>;
 
+		/// The minimum base fee per gas, with the EVM decimals. It is covered by the transaction
+		/// fee, the base fee above it is burned for the gas used.
+		#[pallet::constant]
+		type MinBaseFeePerGas: Get<BalanceOf<Self>>;
+
+		/// The EVM gas used per block targeted by the base fee. The base fee increases when a block
+		/// uses more gas, and decreases when it uses less.
+		#[pallet::constant]
+		type BaseFeeGasTarget: Get<u64>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -361,6 +379,26 @@ pub mod module {
 	#[pallet::getter(fn xcm_origin)]
 	pub type XcmOrigin<T: Config> = StorageValue<_, Vec<T::AccountId>, OptionQuery>;
 
+	/// The base fee per gas of the current block, with the EVM decimals.
+	///
+	/// BaseFeePerGas: U256
+	#[pallet::storage]
+	pub type BaseFeePerGas<T: Config> = StorageValue<_, U256, ValueQuery>;
+
+	/// The EVM gas used by the current block.
+	///
+	/// BlockGasUsed: u64
+	#[pallet::storage]
+	#[pallet::getter(fn block_gas_used)]
+	pub type BlockGasUsed<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The base fee per gas and the EVM gas used of the recent blocks.
+	///
+	/// BlockFees: map BlockNumber => Option<(U256, u64)>
+	#[pallet::storage]
+	#[pallet::getter(fn block_fees)]
+	pub type BlockFees<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, (U256, u64), OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -500,6 +538,8 @@ pub mod module {
 		ContractSetCode { contract: EvmAddress },
 		/// Selfdestructed contract code.
 		ContractSelfdestructed { contract: EvmAddress },
+		/// An EVM log emitted outside of the EVM execution.
+		Logged { log: Log },
 	}
 
 	#[pallet::error]
@@ -538,6 +578,8 @@ pub mod module {
 		StrictCallFailed,
 		/// Caller is not externally owned account
 		NotEOA,
+		/// Invalid state override
+		InvalidStateOverride,
 	}
 
 	#[pallet::pallet]
@@ -546,8 +588,28 @@ pub mod module {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// on_finalize
+			T::DbWeight::get().reads_writes(2, 4)
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			let base_fee = Self::base_fee_per_gas();
+			let gas_used = BlockGasUsed::<T>::take();
+
+			BlockFees::<T>::insert(n, (base_fee, gas_used));
+			BlockFees::<T>::remove(n.saturating_sub(FEE_HISTORY_LENGTH.into()));
+			BaseFeePerGas::<T>::put(next_base_fee_per_gas(
+				base_fee,
+				gas_used,
+				T::BaseFeeGasTarget::get(),
+				Self::min_base_fee_per_gas(),
+			));
+		}
+
 		fn integrity_test() {
 			assert!(convert_decimals_from_evm(T::StorageDepositPerByte::get()).is_some());
+			assert!(!T::BaseFeeGasTarget::get().is_zero());
 		}
 	}
 
@@ -666,6 +728,9 @@ pub mod module {
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
 
+					// the base fee is paid by the signed EVM transactions only
+					Self::charge_base_fee(&source, used_gas).map_err(|_| Error::<T>::ChargeFeeFailed)?;
+
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Executed {
 							from: source,
@@ -718,86 +783,7 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
-			let _from_account = T::AddressMapping::get_account_id(&from);
-			let _paid: NegativeImbalanceOf<T>;
-			#[cfg(not(feature = "with-ethereum-compatibility"))]
-			{
-				// unreserve the transaction fee for gas_limit
-				let weight = T::GasToWeight::convert(gas_limit);
-				let (_, imbalance) = T::ChargeTransactionPayment::unreserve_and_charge_fee(&_from_account, weight)
-					.map_err(|_| Error::<T>::ChargeFeeFailed)?;
-				_paid = imbalance;
-			}
-
-			match T::Runner::call(
-				from,
-				from,
-				target,
-				input,
-				value,
-				gas_limit,
-				storage_limit,
-				access_list.into_iter().map(|v| (v.address, v.storage_keys)).collect(),
-				T::config(),
-			) {
-				Err(e) => {
-					Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed {
-						from,
-						contract: target,
-						exit_reason: ExitReason::Error(ExitError::Other(Into::<&str>::into(e).into())),
-						output: vec![],
-						logs: vec![],
-						used_gas: gas_limit,
-						used_storage: Default::default(),
-					});
-
-					Ok(().into())
-				}
-				Ok(info) => {
-					let used_gas: u64 = info.used_gas.unique_saturated_into();
-
-					if info.exit_reason.is_succeed() {
-						Pallet::<T>::deposit_event(Event::<T>::Executed {
-							from,
-							contract: target,
-							logs: info.logs,
-							used_gas,
-							used_storage: info.used_storage,
-						});
-					} else {
-						Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed {
-							from,
-							contract: target,
-							exit_reason: info.exit_reason.clone(),
-							output: info.value.clone(),
-							logs: info.logs,
-							used_gas,
-							used_storage: Default::default(),
-						});
-					}
-
-					#[cfg(not(feature = "with-ethereum-compatibility"))]
-					{
-						use sp_runtime::traits::Zero;
-						let refund_gas = gas_limit.saturating_sub(used_gas);
-						if !refund_gas.is_zero() {
-							// ignore the result to continue. if it fails, just the user will not
-							// be refunded, there will not increase user balance.
-							let res = T::ChargeTransactionPayment::refund_fee(
-								&_from_account,
-								T::GasToWeight::convert(refund_gas),
-								_paid,
-							);
-							debug_assert!(res.is_ok());
-						}
-					}
-
-					Ok(PostDispatchInfo {
-						actual_weight: Some(T::GasToWeight::convert(used_gas)),
-						pays_fee: Pays::Yes,
-					})
-				}
-			}
+			Self::do_scheduled_call(from, target, input, value, gas_limit, storage_limit, access_list)
 		}
 
 		/// Issue an EVM create operation. This is similar to a contract
@@ -851,6 +837,9 @@ pub mod module {
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
 
+					// the base fee is paid by the signed EVM transactions only
+					Self::charge_base_fee(&source, used_gas).map_err(|_| Error::<T>::ChargeFeeFailed)?;
+
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Created {
 							from: source,
@@ -932,6 +921,9 @@ pub mod module {
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
 
+					// the base fee is paid by the signed EVM transactions only
+					Self::charge_base_fee(&source, used_gas).map_err(|_| Error::<T>::ChargeFeeFailed)?;
+
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Created {
 							from: source,
@@ -1331,6 +1323,217 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Execute a scheduled EVM call, charging the fee reserved for `gas_limit` when the call was
+	/// scheduled and refunding the unused part.
+	fn do_scheduled_call(
+		from: EvmAddress,
+		target: EvmAddress,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<AccessListItem>,
+	) -> DispatchResultWithPostInfo {
+		let _from_account = T::AddressMapping::get_account_id(&from);
+		let _paid: NegativeImbalanceOf<T>;
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		{
+			// unreserve the transaction fee for gas_limit
+			let weight = T::GasToWeight::convert(gas_limit);
+			let (_, imbalance) = T::ChargeTransactionPayment::unreserve_and_charge_fee(&_from_account, weight)
+				.map_err(|_| Error::<T>::ChargeFeeFailed)?;
+			_paid = imbalance;
+		}
+
+		match T::Runner::call(
+			from,
+			from,
+			target,
+			input,
+			value,
+			gas_limit,
+			storage_limit,
+			access_list.into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+			T::config(),
+		) {
+			Err(e) => {
+				Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed {
+					from,
+					contract: target,
+					exit_reason: ExitReason::Error(ExitError::Other(Into::<&str>::into(e).into())),
+					output: vec![],
+					logs: vec![],
+					used_gas: gas_limit,
+					used_storage: Default::default(),
+				});
+
+				Ok(().into())
+			}
+			Ok(info) => {
+				let used_gas: u64 = info.used_gas.unique_saturated_into();
+
+				if info.exit_reason.is_succeed() {
+					Pallet::<T>::deposit_event(Event::<T>::Executed {
+						from,
+						contract: target,
+						logs: info.logs,
+						used_gas,
+						used_storage: info.used_storage,
+					});
+				} else {
+					Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed {
+						from,
+						contract: target,
+						exit_reason: info.exit_reason.clone(),
+						output: info.value.clone(),
+						logs: info.logs,
+						used_gas,
+						used_storage: Default::default(),
+					});
+				}
+
+				#[cfg(not(feature = "with-ethereum-compatibility"))]
+				{
+					use sp_runtime::traits::Zero;
+					let refund_gas = gas_limit.saturating_sub(used_gas);
+					if !refund_gas.is_zero() {
+						// ignore the result to continue. if it fails, just the user will not
+						// be refunded, there will not increase user balance.
+						let res = T::ChargeTransactionPayment::refund_fee(
+							&_from_account,
+							T::GasToWeight::convert(refund_gas),
+							_paid,
+						);
+						debug_assert!(res.is_ok());
+					}
+				}
+
+				Ok(PostDispatchInfo {
+					actual_weight: Some(T::GasToWeight::convert(used_gas)),
+					pays_fee: Pays::Yes,
+				})
+			}
+		}
+	}
+
+	/// The base fee per gas of the current block, with the EVM decimals.
+	pub fn base_fee_per_gas() -> U256 {
+		BaseFeePerGas::<T>::get().max(Self::min_base_fee_per_gas())
+	}
+
+	fn min_base_fee_per_gas() -> U256 {
+		U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(
+			T::MinBaseFeePerGas::get(),
+		))
+	}
+
+	/// Add the gas used by an EVM execution to the gas used by the current block.
+	pub fn record_block_gas_used(used_gas: u64) {
+		BlockGasUsed::<T>::mutate(|gas| *gas = gas.saturating_add(used_gas));
+	}
+
+	/// The base fee per gas and gas used of the latest `block_count` finalized blocks, like
+	/// `eth_feeHistory`.
+	pub fn fee_history(block_count: u32) -> FeeHistory {
+		let mut newest_block = frame_system::Pallet::<T>::block_number();
+		if !BlockFees::<T>::contains_key(newest_block) {
+			// the current block is not finalized yet
+			newest_block = newest_block.saturating_sub(One::one());
+		}
+		let newest_block: u32 = newest_block.unique_saturated_into();
+		let oldest_block = newest_block
+			.saturating_add(1)
+			.saturating_sub(block_count.min(FEE_HISTORY_LENGTH));
+
+		let mut fees = (oldest_block..=newest_block)
+			.rev()
+			.map_while(|n| Self::block_fees(BlockNumberFor::<T>::from(n)))
+			.collect::<Vec<_>>();
+		fees.reverse();
+
+		let (mut base_fee_per_gas, gas_used): (Vec<_>, Vec<_>) = fees.into_iter().unzip();
+		// the base fee of the block after the newest block
+		base_fee_per_gas.push(Self::base_fee_per_gas());
+
+		FeeHistory {
+			oldest_block: newest_block.saturating_add(1).saturating_sub(gas_used.len() as u32),
+			base_fee_per_gas,
+			gas_used,
+			gas_target: T::BaseFeeGasTarget::get(),
+		}
+	}
+
+	/// The storage key of the `Accounts` entry of `address`.
+	pub fn account_key(address: &EvmAddress) -> Vec<u8> {
+		Accounts::<T>::hashed_key_for(address)
+	}
+
+	/// The storage key of the `Codes` entry of `code_hash`.
+	pub fn code_key(code_hash: &H256) -> Vec<u8> {
+		Codes::<T>::hashed_key_for(code_hash)
+	}
+
+	/// The storage key of the `CodeInfos` entry of `code_hash`.
+	pub fn code_info_key(code_hash: &H256) -> Vec<u8> {
+		CodeInfos::<T>::hashed_key_for(code_hash)
+	}
+
+	/// The storage key of the `AccountStorages` entry of `address` at `index`.
+	pub fn account_storage_key(address: &EvmAddress, index: &H256) -> Vec<u8> {
+		AccountStorages::<T>::hashed_key_for(address, index)
+	}
+
+	/// The storage entries of `address` and its `storage_keys` slots, with the keys needed to get
+	/// their Merkle proof with `state_getReadProof`.
+	pub fn account_entries(address: EvmAddress, storage_keys: Vec<H256>) -> AccountEntries {
+		let entry = |key: Vec<u8>, prefix: [u8; 32], hashers: Vec<StorageKeyHasher>| StorageEntry {
+			value: sp_io::storage::get(&key).map(|v| v.to_vec()),
+			key,
+			prefix: prefix.to_vec(),
+			hashers,
+		};
+
+		let code_hash = Self::accounts(address)
+			.and_then(|account| account.contract_info)
+			.map(|contract_info| contract_info.code_hash);
+
+		AccountEntries {
+			address,
+			account: entry(
+				Self::account_key(&address),
+				Accounts::<T>::final_prefix(),
+				vec![StorageKeyHasher::Twox64Concat],
+			),
+			code_info: code_hash.map(|code_hash| {
+				entry(
+					Self::code_info_key(&code_hash),
+					CodeInfos::<T>::final_prefix(),
+					vec![StorageKeyHasher::Identity],
+				)
+			}),
+			code: code_hash.map(|code_hash| {
+				entry(
+					Self::code_key(&code_hash),
+					Codes::<T>::final_prefix(),
+					vec![StorageKeyHasher::Identity],
+				)
+			}),
+			storage: storage_keys
+				.into_iter()
+				.map(|index| {
+					(
+						index,
+						entry(
+							Self::account_storage_key(&address, &index),
+							AccountStorages::<T>::final_prefix(),
+							vec![StorageKeyHasher::Twox64Concat, StorageKeyHasher::Blake2_128Concat],
+						),
+					)
+				})
+				.collect(),
+		}
+	}
+
 	/// Get StorageDepositPerByte of actual decimals
 	pub fn get_storage_deposit_per_byte() -> BalanceOf<T> {
 		// StorageDepositPerByte decimals is 18, KAR/ACA decimals is 12, convert to 12 here.
@@ -1586,6 +1789,112 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Execute `f` with `overrides` applied to the state, like the state overrides of Geth's
+	/// `eth_call`. All the state changes, including the overrides, are discarded.
+	pub fn with_state_overrides<R>(
+		overrides: Vec<(EvmAddress, StateOverride<BalanceOf<T>>)>,
+		f: impl FnOnce() -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		frame_support::storage::with_transaction(|| {
+			TransactionOutcome::Rollback(
+				overrides
+					.into_iter()
+					.try_for_each(|(address, state_override)| Self::apply_state_override(address, state_override))
+					.and_then(|_| f()),
+			)
+		})
+	}
+
+	fn apply_state_override(address: EvmAddress, state_override: StateOverride<BalanceOf<T>>) -> DispatchResult {
+		let StateOverride {
+			balance,
+			nonce,
+			code,
+			state,
+			state_diff,
+		} = state_override;
+		ensure!(
+			state.is_none() || state_diff.is_none(),
+			Error::<T>::InvalidStateOverride
+		);
+
+		if let Some(balance) = balance {
+			let balance = convert_decimals_from_evm(balance).ok_or(Error::<T>::InvalidDecimals)?;
+			T::Currency::make_free_balance_be(&T::AddressMapping::get_account_id(&address), balance);
+		}
+
+		if let Some(nonce) = nonce {
+			Accounts::<T>::mutate(address, |maybe_account_info| {
+				maybe_account_info
+					.get_or_insert_with(|| AccountInfo::new(Default::default(), None))
+					.nonce = nonce.into();
+			});
+		}
+
+		if let Some(code) = code {
+			let contract_info = if code.is_empty() {
+				None
+			} else {
+				let bounded_code: BoundedVec<u8, MaxCodeSize> =
+					code.try_into().map_err(|_| Error::<T>::ContractExceedsMaxCodeSize)?;
+				let code_hash = code_hash(bounded_code.as_slice());
+				let code_size = bounded_code.len() as u32;
+
+				CodeInfos::<T>::mutate(code_hash, |maybe_code_info| {
+					let code_info = maybe_code_info.get_or_insert(CodeInfo {
+						code_size,
+						ref_count: 0,
+					});
+					code_info.ref_count = code_info.ref_count.saturating_add(1);
+				});
+				Codes::<T>::insert(code_hash, bounded_code);
+
+				Some(ContractInfo {
+					code_hash,
+					maintainer: address,
+					published: true,
+				})
+			};
+
+			Accounts::<T>::mutate(address, |maybe_account_info| {
+				let account_info = maybe_account_info.get_or_insert_with(|| AccountInfo::new(Default::default(), None));
+				// keep the maintainer and publication status of an existing contract
+				let old = account_info.contract_info.take();
+				account_info.contract_info = contract_info.map(|new| match old {
+					Some(old) => ContractInfo {
+						code_hash: new.code_hash,
+						..old
+					},
+					None => new,
+				});
+			});
+		}
+
+		// keep the storage size in sync with the slots, as the execution updates it per slot
+		if state.is_some() {
+			let removed = AccountStorages::<T>::drain_prefix(address).count() as u32;
+			ContractStorageSizes::<T>::mutate(address, |size| {
+				*size = size.saturating_sub(removed.saturating_mul(STORAGE_SIZE))
+			});
+		}
+		for (index, value) in state.into_iter().chain(state_diff).flatten() {
+			let exists = AccountStorages::<T>::contains_key(address, index);
+			if value.is_zero() {
+				AccountStorages::<T>::remove(address, index);
+				if exists {
+					Self::update_contract_storage_size(&address, -(STORAGE_SIZE as i32));
+				}
+			} else {
+				AccountStorages::<T>::insert(address, index, value);
+				if !exists {
+					Self::update_contract_storage_size(&address, STORAGE_SIZE as i32);
+				}
+			}
+		}
+
+		Ok(())
+	}
+
 	/// Sets a given contract's contract info to a new maintainer.
 	fn do_transfer_maintainer(who: T::AccountId, contract: EvmAddress, new_maintainer: EvmAddress) -> DispatchResult {
 		Accounts::<T>::mutate(contract, |maybe_account_info| -> DispatchResult {
@@ -1756,7 +2065,8 @@ impl<T: Config> Pallet<T> {
 			.map_or(Err(BadOrigin), Ok)
 	}
 
-	fn can_call_contract(address: &H160, caller: &H160) -> bool {
+	/// Whether the `caller` is allowed to call the contract at `address`.
+	pub fn can_call_contract(address: &H160, caller: &H160) -> bool {
 		if let Some(AccountInfo {
 			contract_info: Some(ContractInfo {
 				published, maintainer, ..
@@ -1819,6 +2129,30 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Burn the base fee of the gas used above `MinBaseFeePerGas`, which is already paid by the
+	/// transaction fee. Only the signed EVM transactions pay it, the runtime calls through the
+	/// runner don't.
+	fn charge_base_fee(caller: &H160, used_gas: u64) -> DispatchResult {
+		let fee_per_gas = Self::base_fee_per_gas().saturating_sub(Self::min_base_fee_per_gas());
+		if fee_per_gas.is_zero() || used_gas.is_zero() {
+			return Ok(());
+		}
+
+		let user = T::AddressMapping::get_account_id(caller);
+		// round down to the native decimals
+		let amount = fee_per_gas.saturating_mul(used_gas.into()) / U256::from(convert_decimals_to_evm(1u32));
+		let amount = TryInto::<BalanceOf<T>>::try_into(amount).map_err(|_| Error::<T>::ChargeFeeFailed)?;
+
+		log::debug!(
+			target: "evm",
+			"charge_base_fee: [from: {:?}, account: {:?}, used_gas: {:?}, amount: {:?}]",
+			caller, user, used_gas, amount
+		);
+
+		T::Currency::withdraw(&user, amount, WithdrawReasons::FEE, ExistenceRequirement::AllowDeath)?;
+		Ok(())
+	}
+
 	fn charge_storage(caller: &H160, contract: &H160, storage: i32) -> DispatchResult {
 		if storage.is_zero() {
 			return Ok(());
@@ -2086,6 +2420,63 @@ impl<T: Config> OnKilledAccount<T::AccountId> for CallKillAccount<T> {
 	}
 }
 
+environmental::environmental!(erc20_transfer_logged: ());
+
+/// Runs `f` without emitting the ERC20 `Transfer` logs of its transfers, for the callers that
+/// log them on their own, such as the MultiCurrency precompile.
+pub fn without_erc20_transfer_logs<R>(f: impl FnOnce() -> R) -> R {
+	erc20_transfer_logged::using(&mut (), f)
+}
+
+/// Emits the ERC20 `Transfer` logs of the substrate side transfers as `Logged` events.
+pub struct Erc20TransferLogger<T, Erc20Info>(PhantomData<(T, Erc20Info)>);
+impl<T: Config, Erc20Info: Erc20InfoMapping> Erc20TransferLog<T::AccountId, Balance>
+	for Erc20TransferLogger<T, Erc20Info>
+{
+	fn log_transfer(currency_id: CurrencyId, from: Option<&T::AccountId>, to: Option<&T::AccountId>, amount: Balance) {
+		// ERC20 contracts emit their own logs.
+		if currency_id.is_erc20_currency_id() || erc20_transfer_logged::with(|_| ()).is_some() {
+			return;
+		}
+		let Some(address) = Erc20Info::encode_evm_address(currency_id) else {
+			return;
+		};
+
+		let topic = |who: Option<&T::AccountId>| -> H256 {
+			who.map(|who| {
+				T::AddressMapping::get_evm_address(who)
+					.unwrap_or_else(|| T::AddressMapping::get_default_evm_address(who))
+			})
+			.unwrap_or_default()
+			.into()
+		};
+
+		let mut data = [0u8; 32];
+		U256::from(amount).to_big_endian(&mut data);
+
+		Pallet::<T>::deposit_event(Event::Logged {
+			log: Log {
+				address,
+				topics: vec![ERC20_TRANSFER_TOPIC, topic(from), topic(to)],
+				data: data.to_vec(),
+			},
+		});
+	}
+}
+
+/// Calculate the base fee per gas of the next block as EIP-1559.
+pub fn next_base_fee_per_gas(base_fee: U256, gas_used: u64, gas_target: u64, min_base_fee: U256) -> U256 {
+	let change = |gas_delta: u64| {
+		base_fee.saturating_mul(gas_delta.into()) / gas_target.max(1) / BASE_FEE_MAX_CHANGE_DENOMINATOR
+	};
+	let next_base_fee = match gas_used.cmp(&gas_target) {
+		cmp::Ordering::Equal => base_fee,
+		cmp::Ordering::Greater => base_fee.saturating_add(change(gas_used - gas_target).max(U256::one())),
+		cmp::Ordering::Less => base_fee.saturating_sub(change(gas_target - gas_used)),
+	};
+	next_base_fee.max(min_base_fee)
+}
+
 pub fn code_hash(code: &[u8]) -> H256 {
 	H256::from_slice(Keccak256::digest(code).as_slice())
 }
@@ -2177,7 +2568,6 @@ impl<T: Config + Send + Sync> SignedExtension for SetEvmOrigin<T> {
 
 #[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
 pub enum EvmTask<T: Config> {
-	// TODO: update
 	Schedule {
 		from: EvmAddress,
 		target: EvmAddress,
@@ -2196,13 +2586,44 @@ pub enum EvmTask<T: Config> {
 impl<T: Config> DispatchableTask for EvmTask<T> {
 	fn dispatch(self, weight: Weight) -> TaskResult {
 		match self {
-			// TODO: update
-			EvmTask::Schedule { .. } => {
-				// check weight and call `scheduled_call`
-				TaskResult {
-					result: Ok(()),
-					used_weight: Weight::zero(),
-					finished: false,
+			EvmTask::Schedule {
+				from,
+				target,
+				input,
+				value,
+				gas_limit,
+				storage_limit,
+			} => {
+				// keep the task and try again later if there is not enough weight for `gas_limit`
+				let required_weight = T::GasToWeight::convert(gas_limit);
+				if !weight.all_gte(required_weight) {
+					return TaskResult {
+						result: Ok(()),
+						used_weight: Weight::zero(),
+						finished: false,
+					};
+				}
+
+				// The task is dropped on error, e.g. the caller can no longer pay the reserved fee.
+				let result =
+					Pallet::<T>::do_scheduled_call(from, target, input, value, gas_limit, storage_limit, vec![]);
+				log::debug!(
+					target: "evm",
+					"EvmTask schedule: [from: {:?}, target: {:?}, gas_limit: {:?}, storage_limit: {:?}, result: {:?}]",
+					from, target, gas_limit, storage_limit, result
+				);
+
+				match result {
+					Ok(post_info) => TaskResult {
+						result: Ok(()),
+						used_weight: post_info.actual_weight.unwrap_or(required_weight),
+						finished: true,
+					},
+					Err(e) => TaskResult {
+						result: Err(e.error),
+						used_weight: e.post_info.actual_weight.unwrap_or(Weight::zero()),
+						finished: true,
+					},
 				}
 			}
 			EvmTask::Remove {
@@ -0,0 +1,331 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	decode_fp, decode_g1, decode_scalar, encode_g1, error, msm_required_gas, G1_LENGTH, PADDED_FP_LENGTH, SCALAR_LENGTH,
+};
+use crate::{precompiles::Precompile, PrecompileHandle, PrecompileOutput, PrecompileResult};
+use ark_bls12_381::{g1, G1Projective};
+use ark_ec::{
+	hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurve},
+	AffineRepr, CurveGroup, VariableBaseMSM,
+};
+use module_evm_utility::evm::ExitSucceed;
+use sp_std::vec::Vec;
+
+/// Discount table for G1 MSM, indexed by the number of pairs minus one.
+const G1_MSM_DISCOUNT_TABLE: [u64; 128] = [
+	1000, 949, 848, 797, 764, 750, 738, 728, 719, 712, 705, 698, 692, 687, 682, 677, 673, 669, 665, 661, 658, 654, 651,
+	648, 645, 642, 640, 637, 635, 632, 630, 627, 625, 623, 621, 619, 617, 615, 613, 611, 609, 608, 606, 604, 603, 601,
+	599, 598, 596, 595, 593, 592, 591, 589, 588, 586, 585, 584, 582, 581, 580, 579, 577, 576, 575, 574, 573, 572, 570,
+	569, 568, 567, 566, 565, 564, 563, 562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 551, 550, 549, 548, 547,
+	547, 546, 545, 544, 543, 542, 541, 540, 540, 539, 538, 537, 536, 536, 535, 534, 533, 532, 532, 531, 530, 529, 528,
+	528, 527, 526, 525, 525, 524, 523, 522, 522, 521, 520, 520, 519,
+];
+
+/// The BLS12-381 G1 addition builtin
+pub struct Bls12381G1Add;
+
+impl Bls12381G1Add {
+	const GAS_COST: u64 = 375; // https://eips.ethereum.org/EIPS/eip-2537
+}
+
+impl Precompile for Bls12381G1Add {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(Bls12381G1Add::GAS_COST)?;
+
+		let input = handle.input();
+		if input.len() != 2 * G1_LENGTH {
+			return Err(error("Invalid input length, must be 256 bytes"));
+		}
+
+		// no subgroup check for addition
+		let p1 = decode_g1(&input[..G1_LENGTH], false)?;
+		let p2 = decode_g1(&input[G1_LENGTH..], false)?;
+
+		let sum = (p1 + p2).into_affine();
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: encode_g1(&sum),
+		})
+	}
+
+	#[cfg(feature = "evm-tests")]
+	fn execute_ext(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &crate::Context,
+		is_static: bool,
+	) -> Result<(PrecompileOutput, u64), crate::PrecompileFailure> {
+		let mut handle = crate::precompiles::tests::MockPrecompileHandle::new(&input, target_gas, context, is_static);
+		let output = Self::execute(&mut handle)?;
+
+		Ok((output, handle.gas_used))
+	}
+}
+
+/// The BLS12-381 G1 multi-scalar-multiplication builtin
+pub struct Bls12381G1Msm;
+
+impl Bls12381G1Msm {
+	// https://eips.ethereum.org/EIPS/eip-2537
+	const MULTIPLICATION_GAS_COST: u64 = 12_000;
+	const MAX_DISCOUNT: u64 = 519;
+}
+
+impl Precompile for Bls12381G1Msm {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		const ITEM_LENGTH: usize = G1_LENGTH + SCALAR_LENGTH;
+
+		let input = handle.input();
+		if input.is_empty() || input.len() % ITEM_LENGTH != 0 {
+			return Err(error("Invalid input length, must be a non-zero multiple of 160 bytes"));
+		}
+
+		let k = input.len() / ITEM_LENGTH;
+		handle.record_cost(msm_required_gas(
+			k,
+			&G1_MSM_DISCOUNT_TABLE,
+			Bls12381G1Msm::MAX_DISCOUNT,
+			Bls12381G1Msm::MULTIPLICATION_GAS_COST,
+		))?;
+
+		let input = handle.input();
+		let mut points = Vec::with_capacity(k);
+		let mut scalars = Vec::with_capacity(k);
+		for item in input.chunks(ITEM_LENGTH) {
+			points.push(decode_g1(&item[..G1_LENGTH], true)?);
+			scalars.push(decode_scalar(&item[G1_LENGTH..]));
+		}
+
+		let result = G1Projective::msm(&points, &scalars)
+			.map_err(|_| error("Mismatched number of points and scalars"))?
+			.into_affine();
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: encode_g1(&result),
+		})
+	}
+
+	#[cfg(feature = "evm-tests")]
+	fn execute_ext(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &crate::Context,
+		is_static: bool,
+	) -> Result<(PrecompileOutput, u64), crate::PrecompileFailure> {
+		let mut handle = crate::precompiles::tests::MockPrecompileHandle::new(&input, target_gas, context, is_static);
+		let output = Self::execute(&mut handle)?;
+
+		Ok((output, handle.gas_used))
+	}
+}
+
+/// The BLS12-381 map field element to G1 builtin
+pub struct Bls12381MapG1;
+
+impl Bls12381MapG1 {
+	const GAS_COST: u64 = 5_500; // https://eips.ethereum.org/EIPS/eip-2537
+}
+
+impl Precompile for Bls12381MapG1 {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(Bls12381MapG1::GAS_COST)?;
+
+		let input = handle.input();
+		if input.len() != PADDED_FP_LENGTH {
+			return Err(error("Invalid input length, must be 64 bytes"));
+		}
+
+		let fp = decode_fp(input)?;
+
+		let point = WBMap::<g1::Config>::new()
+			.and_then(|map| map.map_to_curve(fp))
+			.map_err(|_| error("Failed to map field element to G1"))?
+			.clear_cofactor();
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: encode_g1(&point),
+		})
+	}
+
+	#[cfg(feature = "evm-tests")]
+	fn execute_ext(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &crate::Context,
+		is_static: bool,
+	) -> Result<(PrecompileOutput, u64), crate::PrecompileFailure> {
+		let mut handle = crate::precompiles::tests::MockPrecompileHandle::new(&input, target_gas, context, is_static);
+		let output = Self::execute(&mut handle)?;
+
+		Ok((output, handle.gas_used))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{precompiles::tests::MockPrecompileHandle, PrecompileFailure};
+	use hex_literal::hex;
+	use module_evm_utility::evm::{Context, ExitError};
+	use sp_core::U256;
+
+	fn get_context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: Default::default(),
+			apparent_value: U256::zero(),
+		}
+	}
+
+	const G1: [u8; 128] = hex! {"
+		0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0f
+		c3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb
+		0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4
+		fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1
+	"};
+
+	const G1_DOUBLE: [u8; 128] = hex! {"
+		000000000000000000000000000000000572cbea904d67468808c8eb50a9450c
+		9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e
+		00000000000000000000000000000000166a9d8cabc673a322fda673779d8e38
+		22ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28
+	"};
+
+	const G1_NEG: [u8; 128] = hex! {"
+		0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0f
+		c3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb
+		00000000000000000000000000000000114d1d6855d545a8aa7d76c8cf2e21f2
+		67816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca
+	"};
+
+	#[test]
+	fn g1_add() {
+		// g + g
+		let input = [G1, G1].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G1Add::execute(&mut handle).unwrap().output, G1_DOUBLE.to_vec());
+		assert_eq!(handle.gas_used, 375);
+
+		// g + infinity
+		let input = [G1, [0u8; 128]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G1Add::execute(&mut handle).unwrap().output, G1.to_vec());
+
+		// g + (-g)
+		let input = [G1, G1_NEG].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G1Add::execute(&mut handle).unwrap().output, [0u8; 128].to_vec());
+
+		// should fail - point not on curve
+		let mut input = [G1, G1].concat();
+		input[127] ^= 1;
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(
+			Bls12381G1Add::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("G1 point is not on curve".into())
+			})
+		);
+
+		// should fail - invalid input length
+		let mut handle = MockPrecompileHandle::new(&G1, None, &get_context(), false);
+		assert_eq!(
+			Bls12381G1Add::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("Invalid input length, must be 256 bytes".into())
+			})
+		);
+	}
+
+	#[test]
+	fn g1_msm() {
+		let mut two = [0u8; 32];
+		two[31] = 2;
+		let mut one = [0u8; 32];
+		one[31] = 1;
+
+		// 2 * g
+		let input = [&G1[..], &two[..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G1Msm::execute(&mut handle).unwrap().output, G1_DOUBLE.to_vec());
+		assert_eq!(handle.gas_used, 12_000);
+
+		// 1 * g + 1 * g
+		let input = [&G1[..], &one[..], &G1[..], &one[..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G1Msm::execute(&mut handle).unwrap().output, G1_DOUBLE.to_vec());
+		assert_eq!(handle.gas_used, 2 * 12_000 * 949 / 1000);
+
+		// 0 * g
+		let input = [&G1[..], &[0u8; 32][..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G1Msm::execute(&mut handle).unwrap().output, [0u8; 128].to_vec());
+
+		// should fail - empty input
+		let mut handle = MockPrecompileHandle::new(&[], None, &get_context(), false);
+		assert_eq!(
+			Bls12381G1Msm::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("Invalid input length, must be a non-zero multiple of 160 bytes".into())
+			})
+		);
+
+		// should fail - out of gas
+		let input = [&G1[..], &two[..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, Some(11_999), &get_context(), false);
+		assert_eq!(
+			Bls12381G1Msm::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::OutOfGas
+			})
+		);
+	}
+
+	#[test]
+	fn map_fp_to_g1() {
+		// test vector `bls_g1map_` of EIP-2537
+		let input = hex! {"
+			00000000000000000000000000000000156c8a6a2c184569d69a76be144b5cdc
+			5141d2d2ca4fe341f011e25e3969c55ad9e9b9ce2eb833c81a908e5fa4ac5f03
+		"};
+		let expected = hex! {"
+			00000000000000000000000000000000184bb665c37ff561a89ec2122dd343f2
+			0e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba
+			0000000000000000000000000000000004407b8d35af4dacc809927071fc0405
+			218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3
+		"};
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381MapG1::execute(&mut handle).unwrap().output, expected.to_vec());
+		assert_eq!(handle.gas_used, 5_500);
+
+		// should fail - field element not less than modulus
+		let input = hex!("000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(
+			Bls12381MapG1::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("Invalid field element".into())
+			})
+		);
+	}
+}
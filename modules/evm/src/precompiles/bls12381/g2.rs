@@ -0,0 +1,309 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	decode_fp2, decode_g2, decode_scalar, encode_g2, error, msm_required_gas, G2_LENGTH, PADDED_FP2_LENGTH,
+	SCALAR_LENGTH,
+};
+use crate::{precompiles::Precompile, PrecompileHandle, PrecompileOutput, PrecompileResult};
+use ark_bls12_381::{g2, G2Projective};
+use ark_ec::{
+	hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurve},
+	AffineRepr, CurveGroup, VariableBaseMSM,
+};
+use module_evm_utility::evm::ExitSucceed;
+use sp_std::vec::Vec;
+
+/// Discount table for G2 MSM, indexed by the number of pairs minus one.
+const G2_MSM_DISCOUNT_TABLE: [u64; 128] = [
+	1000, 1000, 923, 884, 855, 832, 812, 796, 782, 770, 759, 749, 740, 732, 724, 717, 711, 704, 699, 693, 688, 683,
+	679, 674, 670, 666, 663, 659, 655, 652, 649, 646, 643, 640, 637, 634, 632, 629, 627, 624, 622, 620, 618, 615, 613,
+	611, 609, 607, 606, 604, 602, 600, 598, 597, 595, 593, 592, 590, 589, 587, 586, 584, 583, 582, 580, 579, 578, 576,
+	575, 574, 573, 571, 570, 569, 568, 567, 566, 565, 563, 562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 552,
+	551, 550, 549, 548, 547, 546, 545, 545, 544, 543, 542, 541, 541, 540, 539, 538, 537, 537, 536, 535, 535, 534, 533,
+	532, 532, 531, 530, 530, 529, 528, 528, 527, 526, 526, 525, 524, 524,
+];
+
+/// The BLS12-381 G2 addition builtin
+pub struct Bls12381G2Add;
+
+impl Bls12381G2Add {
+	const GAS_COST: u64 = 600; // https://eips.ethereum.org/EIPS/eip-2537
+}
+
+impl Precompile for Bls12381G2Add {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(Bls12381G2Add::GAS_COST)?;
+
+		let input = handle.input();
+		if input.len() != 2 * G2_LENGTH {
+			return Err(error("Invalid input length, must be 512 bytes"));
+		}
+
+		// no subgroup check for addition
+		let p1 = decode_g2(&input[..G2_LENGTH], false)?;
+		let p2 = decode_g2(&input[G2_LENGTH..], false)?;
+
+		let sum = (p1 + p2).into_affine();
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: encode_g2(&sum),
+		})
+	}
+
+	#[cfg(feature = "evm-tests")]
+	fn execute_ext(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &crate::Context,
+		is_static: bool,
+	) -> Result<(PrecompileOutput, u64), crate::PrecompileFailure> {
+		let mut handle = crate::precompiles::tests::MockPrecompileHandle::new(&input, target_gas, context, is_static);
+		let output = Self::execute(&mut handle)?;
+
+		Ok((output, handle.gas_used))
+	}
+}
+
+/// The BLS12-381 G2 multi-scalar-multiplication builtin
+pub struct Bls12381G2Msm;
+
+impl Bls12381G2Msm {
+	// https://eips.ethereum.org/EIPS/eip-2537
+	const MULTIPLICATION_GAS_COST: u64 = 22_500;
+	const MAX_DISCOUNT: u64 = 524;
+}
+
+impl Precompile for Bls12381G2Msm {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		const ITEM_LENGTH: usize = G2_LENGTH + SCALAR_LENGTH;
+
+		let input = handle.input();
+		if input.is_empty() || input.len() % ITEM_LENGTH != 0 {
+			return Err(error("Invalid input length, must be a non-zero multiple of 288 bytes"));
+		}
+
+		let k = input.len() / ITEM_LENGTH;
+		handle.record_cost(msm_required_gas(
+			k,
+			&G2_MSM_DISCOUNT_TABLE,
+			Bls12381G2Msm::MAX_DISCOUNT,
+			Bls12381G2Msm::MULTIPLICATION_GAS_COST,
+		))?;
+
+		let input = handle.input();
+		let mut points = Vec::with_capacity(k);
+		let mut scalars = Vec::with_capacity(k);
+		for item in input.chunks(ITEM_LENGTH) {
+			points.push(decode_g2(&item[..G2_LENGTH], true)?);
+			scalars.push(decode_scalar(&item[G2_LENGTH..]));
+		}
+
+		let result = G2Projective::msm(&points, &scalars)
+			.map_err(|_| error("Mismatched number of points and scalars"))?
+			.into_affine();
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: encode_g2(&result),
+		})
+	}
+
+	#[cfg(feature = "evm-tests")]
+	fn execute_ext(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &crate::Context,
+		is_static: bool,
+	) -> Result<(PrecompileOutput, u64), crate::PrecompileFailure> {
+		let mut handle = crate::precompiles::tests::MockPrecompileHandle::new(&input, target_gas, context, is_static);
+		let output = Self::execute(&mut handle)?;
+
+		Ok((output, handle.gas_used))
+	}
+}
+
+/// The BLS12-381 map Fp2 element to G2 builtin
+pub struct Bls12381MapG2;
+
+impl Bls12381MapG2 {
+	const GAS_COST: u64 = 23_800; // https://eips.ethereum.org/EIPS/eip-2537
+}
+
+impl Precompile for Bls12381MapG2 {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(Bls12381MapG2::GAS_COST)?;
+
+		let input = handle.input();
+		if input.len() != PADDED_FP2_LENGTH {
+			return Err(error("Invalid input length, must be 128 bytes"));
+		}
+
+		let fp2 = decode_fp2(input)?;
+
+		let point = WBMap::<g2::Config>::new()
+			.and_then(|map| map.map_to_curve(fp2))
+			.map_err(|_| error("Failed to map field element to G2"))?
+			.clear_cofactor();
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: encode_g2(&point),
+		})
+	}
+
+	#[cfg(feature = "evm-tests")]
+	fn execute_ext(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &crate::Context,
+		is_static: bool,
+	) -> Result<(PrecompileOutput, u64), crate::PrecompileFailure> {
+		let mut handle = crate::precompiles::tests::MockPrecompileHandle::new(&input, target_gas, context, is_static);
+		let output = Self::execute(&mut handle)?;
+
+		Ok((output, handle.gas_used))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{precompiles::tests::MockPrecompileHandle, PrecompileFailure};
+	use hex_literal::hex;
+	use module_evm_utility::evm::{Context, ExitError};
+	use sp_core::U256;
+
+	fn get_context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: Default::default(),
+			apparent_value: U256::zero(),
+		}
+	}
+
+	const G2: [u8; 256] = hex! {"
+		00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051
+		c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8
+		0000000000000000000000000000000013e02b6052719f607dacd3a088274f65
+		596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e
+		000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351a
+		adfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801
+		000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99
+		cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be
+	"};
+
+	const G2_DOUBLE: [u8; 256] = hex! {"
+		000000000000000000000000000000001638533957d540a9d2370f17cc7ed586
+		3bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053
+		000000000000000000000000000000000a4edef9c1ed7f729f520e47730a124f
+		d70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577
+		000000000000000000000000000000000468fb440d82b0630aeb8dca2b525678
+		9a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899
+		000000000000000000000000000000000f6d4552fa65dd2638b361543f887136
+		a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3
+	"};
+
+	#[test]
+	fn g2_add() {
+		// g + g
+		let input = [G2, G2].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G2Add::execute(&mut handle).unwrap().output, G2_DOUBLE.to_vec());
+		assert_eq!(handle.gas_used, 600);
+
+		// infinity + g
+		let input = [[0u8; 256], G2].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G2Add::execute(&mut handle).unwrap().output, G2.to_vec());
+
+		// should fail - point not on curve
+		let mut input = [G2, G2].concat();
+		input[255] ^= 1;
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(
+			Bls12381G2Add::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("G2 point is not on curve".into())
+			})
+		);
+	}
+
+	#[test]
+	fn g2_msm() {
+		let mut two = [0u8; 32];
+		two[31] = 2;
+		let mut one = [0u8; 32];
+		one[31] = 1;
+
+		// 2 * g
+		let input = [&G2[..], &two[..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G2Msm::execute(&mut handle).unwrap().output, G2_DOUBLE.to_vec());
+		assert_eq!(handle.gas_used, 22_500);
+
+		// 1 * g + 1 * g + 0 * g
+		let input = [&G2[..], &one[..], &G2[..], &one[..], &G2[..], &[0u8; 32][..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381G2Msm::execute(&mut handle).unwrap().output, G2_DOUBLE.to_vec());
+		assert_eq!(handle.gas_used, 3 * 22_500 * 923 / 1000);
+
+		// should fail - invalid input length
+		let mut handle = MockPrecompileHandle::new(&G2, None, &get_context(), false);
+		assert_eq!(
+			Bls12381G2Msm::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("Invalid input length, must be a non-zero multiple of 288 bytes".into())
+			})
+		);
+	}
+
+	#[test]
+	fn map_fp2_to_g2() {
+		// test vector `bls_g2map_` of EIP-2537
+		let input = hex! {"
+			0000000000000000000000000000000007355d25caf6e7f2f0cb2812ca0e513b
+			d026ed09dda65b177500fa31714e09ea0ded3a078b526bed3307f804d4b93b04
+			0000000000000000000000000000000002829ce3c021339ccb5caf3e187f6370
+			e1e2a311dec9b75363117063ab2015603ff52c3d3b98f19c2f65575e99e8b78c
+		"};
+		let expected = hex! {"
+			0000000000000000000000000000000000e7f4568a82b4b7dc1f14c6aaa055ed
+			f51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7
+			00000000000000000000000000000000126b855e9e69b1f691f816e48ac69776
+			64d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b
+			000000000000000000000000000000000caead0fd7b6176c01436833c79d305c
+			78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42
+			000000000000000000000000000000001498aadcf7ae2b345243e281ae076df6
+			de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d
+		"};
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381MapG2::execute(&mut handle).unwrap().output, expected.to_vec());
+		assert_eq!(handle.gas_used, 23_800);
+
+		// should fail - invalid input length
+		let mut handle = MockPrecompileHandle::new(&input[..64], None, &get_context(), false);
+		assert_eq!(
+			Bls12381MapG2::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("Invalid input length, must be 128 bytes".into())
+			})
+		);
+	}
+}
@@ -0,0 +1,205 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! BLS12-381 curve operations as specified in EIP-2537.
+//!
+//! https://eips.ethereum.org/EIPS/eip-2537
+
+use crate::PrecompileFailure;
+use ark_bls12_381::{Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField, Zero};
+use module_evm_utility::evm::ExitError;
+use sp_std::vec::Vec;
+
+mod g1;
+mod g2;
+mod pairing;
+
+pub use g1::{Bls12381G1Add, Bls12381G1Msm, Bls12381MapG1};
+pub use g2::{Bls12381G2Add, Bls12381G2Msm, Bls12381MapG2};
+pub use pairing::Bls12381Pairing;
+
+/// Length of a field element without padding.
+const FP_LENGTH: usize = 48;
+/// Length of a field element padded to 64 bytes.
+const PADDED_FP_LENGTH: usize = 64;
+/// Length of an encoded Fp2 element.
+const PADDED_FP2_LENGTH: usize = 2 * PADDED_FP_LENGTH;
+/// Length of an encoded G1 point.
+const G1_LENGTH: usize = 2 * PADDED_FP_LENGTH;
+/// Length of an encoded G2 point.
+const G2_LENGTH: usize = 2 * PADDED_FP2_LENGTH;
+/// Length of an encoded scalar.
+const SCALAR_LENGTH: usize = 32;
+
+/// Multiplier of the MSM discount tables.
+const MSM_MULTIPLIER: u64 = 1000;
+
+fn error(message: &'static str) -> PrecompileFailure {
+	PrecompileFailure::Error {
+		exit_status: ExitError::Other(message.into()),
+	}
+}
+
+/// Gas cost of a MSM with `k` pairs, using the discount table of the group.
+fn msm_required_gas(k: usize, discount_table: &[u64], max_discount: u64, multiplication_cost: u64) -> u64 {
+	if k == 0 {
+		return 0;
+	}
+
+	let discount = discount_table.get(k - 1).copied().unwrap_or(max_discount);
+
+	(k as u64).saturating_mul(multiplication_cost).saturating_mul(discount) / MSM_MULTIPLIER
+}
+
+/// Decodes a 64 bytes big-endian field element. The top 16 bytes must be zero and the value must
+/// be less than the modulus.
+fn decode_fp(input: &[u8]) -> Result<Fq, PrecompileFailure> {
+	let (padding, value) = input[..PADDED_FP_LENGTH].split_at(PADDED_FP_LENGTH - FP_LENGTH);
+	if padding.iter().any(|b| *b != 0) {
+		return Err(error("Invalid field element padding"));
+	}
+
+	let mut limbs = [0u64; 6];
+	for (i, chunk) in value.chunks(8).enumerate() {
+		let mut buf = [0u8; 8];
+		buf.copy_from_slice(chunk);
+		limbs[5 - i] = u64::from_be_bytes(buf);
+	}
+
+	Fq::from_bigint(ark_ff::BigInt(limbs)).ok_or_else(|| error("Invalid field element"))
+}
+
+fn encode_fp(fp: &Fq, output: &mut Vec<u8>) {
+	output.extend_from_slice(&[0u8; PADDED_FP_LENGTH - FP_LENGTH]);
+	output.extend_from_slice(&fp.into_bigint().to_bytes_be());
+}
+
+fn decode_fp2(input: &[u8]) -> Result<Fq2, PrecompileFailure> {
+	let c0 = decode_fp(&input[..PADDED_FP_LENGTH])?;
+	let c1 = decode_fp(&input[PADDED_FP_LENGTH..PADDED_FP2_LENGTH])?;
+	Ok(Fq2::new(c0, c1))
+}
+
+fn encode_fp2(fp2: &Fq2, output: &mut Vec<u8>) {
+	encode_fp(&fp2.c0, output);
+	encode_fp(&fp2.c1, output);
+}
+
+/// Decodes a G1 point. All zero bytes encode the point at infinity.
+fn decode_g1(input: &[u8], subgroup_check: bool) -> Result<G1Affine, PrecompileFailure> {
+	let x = decode_fp(&input[..PADDED_FP_LENGTH])?;
+	let y = decode_fp(&input[PADDED_FP_LENGTH..G1_LENGTH])?;
+
+	if x.is_zero() && y.is_zero() {
+		return Ok(G1Affine::identity());
+	}
+
+	let point = G1Affine::new_unchecked(x, y);
+	if !point.is_on_curve() {
+		return Err(error("G1 point is not on curve"));
+	}
+	if subgroup_check && !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err(error("G1 point is not in the correct subgroup"));
+	}
+
+	Ok(point)
+}
+
+fn encode_g1(point: &G1Affine) -> Vec<u8> {
+	let mut output = Vec::with_capacity(G1_LENGTH);
+	match point.xy() {
+		Some((x, y)) => {
+			encode_fp(x, &mut output);
+			encode_fp(y, &mut output);
+		}
+		None => output.resize(G1_LENGTH, 0),
+	}
+	output
+}
+
+/// Decodes a G2 point. All zero bytes encode the point at infinity.
+fn decode_g2(input: &[u8], subgroup_check: bool) -> Result<G2Affine, PrecompileFailure> {
+	let x = decode_fp2(&input[..PADDED_FP2_LENGTH])?;
+	let y = decode_fp2(&input[PADDED_FP2_LENGTH..G2_LENGTH])?;
+
+	if x.is_zero() && y.is_zero() {
+		return Ok(G2Affine::identity());
+	}
+
+	let point = G2Affine::new_unchecked(x, y);
+	if !point.is_on_curve() {
+		return Err(error("G2 point is not on curve"));
+	}
+	if subgroup_check && !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err(error("G2 point is not in the correct subgroup"));
+	}
+
+	Ok(point)
+}
+
+fn encode_g2(point: &G2Affine) -> Vec<u8> {
+	let mut output = Vec::with_capacity(G2_LENGTH);
+	match point.xy() {
+		Some((x, y)) => {
+			encode_fp2(x, &mut output);
+			encode_fp2(y, &mut output);
+		}
+		None => output.resize(G2_LENGTH, 0),
+	}
+	output
+}
+
+/// Decodes a 32 bytes big-endian scalar. Scalars are not required to be less than the group order,
+/// and points are always checked to be in the subgroup before multiplication, so reducing is fine.
+fn decode_scalar(input: &[u8]) -> Fr {
+	Fr::from_be_bytes_mod_order(&input[..SCALAR_LENGTH])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hex_literal::hex;
+
+	#[test]
+	fn msm_required_gas_works() {
+		assert_eq!(msm_required_gas(0, &[1000, 500], 100, 10), 0);
+		assert_eq!(msm_required_gas(1, &[1000, 500], 100, 10), 10);
+		assert_eq!(msm_required_gas(2, &[1000, 500], 100, 10), 10);
+		assert_eq!(msm_required_gas(3, &[1000, 500], 100, 10), 3);
+	}
+
+	#[test]
+	fn decode_fp_works() {
+		// modulus - 1
+		let input = hex!("000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaaa");
+		let fp = decode_fp(&input).unwrap();
+		let mut output = Vec::new();
+		encode_fp(&fp, &mut output);
+		assert_eq!(output, input.to_vec());
+
+		// modulus
+		let input = hex!("000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+		assert_eq!(decode_fp(&input), Err(error("Invalid field element")));
+
+		// non-zero padding
+		let input = hex!("000000000000000000000000000000011a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaaa");
+		assert_eq!(decode_fp(&input), Err(error("Invalid field element padding")));
+	}
+}
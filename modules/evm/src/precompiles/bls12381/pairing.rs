@@ -0,0 +1,192 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{decode_g1, decode_g2, error, G1_LENGTH, G2_LENGTH};
+use crate::{precompiles::Precompile, PrecompileHandle, PrecompileOutput, PrecompileResult};
+use ark_bls12_381::Bls12_381;
+use ark_ec::pairing::Pairing;
+use ark_ff::Zero;
+use module_evm_utility::evm::ExitSucceed;
+use sp_std::vec::Vec;
+
+/// The BLS12-381 pairing check builtin
+pub struct Bls12381Pairing;
+
+impl Bls12381Pairing {
+	// https://eips.ethereum.org/EIPS/eip-2537
+	const BASE_GAS_COST: u64 = 37_700;
+	const GAS_COST_PER_PAIRING: u64 = 32_600;
+}
+
+impl Precompile for Bls12381Pairing {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		const ITEM_LENGTH: usize = G1_LENGTH + G2_LENGTH;
+
+		let input = handle.input();
+		if input.is_empty() || input.len() % ITEM_LENGTH != 0 {
+			return Err(error("Invalid input length, must be a non-zero multiple of 384 bytes"));
+		}
+
+		let k = input.len() / ITEM_LENGTH;
+		handle.record_cost(
+			Bls12381Pairing::BASE_GAS_COST
+				.saturating_add(Bls12381Pairing::GAS_COST_PER_PAIRING.saturating_mul(k as u64)),
+		)?;
+
+		let input = handle.input();
+		let mut g1_points = Vec::with_capacity(k);
+		let mut g2_points = Vec::with_capacity(k);
+		for item in input.chunks(ITEM_LENGTH) {
+			g1_points.push(decode_g1(&item[..G1_LENGTH], true)?);
+			g2_points.push(decode_g2(&item[G1_LENGTH..], true)?);
+		}
+
+		// the product of the pairings is the identity of the target group
+		let mut output = [0u8; 32];
+		if Bls12_381::multi_pairing(g1_points, g2_points).is_zero() {
+			output[31] = 1;
+		}
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: output.to_vec(),
+		})
+	}
+
+	#[cfg(feature = "evm-tests")]
+	fn execute_ext(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &crate::Context,
+		is_static: bool,
+	) -> Result<(PrecompileOutput, u64), crate::PrecompileFailure> {
+		let mut handle = crate::precompiles::tests::MockPrecompileHandle::new(&input, target_gas, context, is_static);
+		let output = Self::execute(&mut handle)?;
+
+		Ok((output, handle.gas_used))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{precompiles::tests::MockPrecompileHandle, PrecompileFailure};
+	use hex_literal::hex;
+	use module_evm_utility::evm::{Context, ExitError};
+	use sp_core::U256;
+
+	fn get_context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: Default::default(),
+			apparent_value: U256::zero(),
+		}
+	}
+
+	const G1: [u8; 128] = hex! {"
+		0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0f
+		c3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb
+		0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4
+		fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1
+	"};
+
+	const G1_NEG: [u8; 128] = hex! {"
+		0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0f
+		c3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb
+		00000000000000000000000000000000114d1d6855d545a8aa7d76c8cf2e21f2
+		67816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca
+	"};
+
+	const G2: [u8; 256] = hex! {"
+		00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051
+		c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8
+		0000000000000000000000000000000013e02b6052719f607dacd3a088274f65
+		596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e
+		000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351a
+		adfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801
+		000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99
+		cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be
+	"};
+
+	fn success() -> Vec<u8> {
+		let mut output = [0u8; 32];
+		output[31] = 1;
+		output.to_vec()
+	}
+
+	#[test]
+	fn pairing_check() {
+		// e(g1, g2) * e(-g1, g2) == 1
+		let input = [&G1[..], &G2[..], &G1_NEG[..], &G2[..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381Pairing::execute(&mut handle).unwrap().output, success());
+		assert_eq!(handle.gas_used, 37_700 + 2 * 32_600);
+
+		// e(g1, g2) != 1
+		let input = [&G1[..], &G2[..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(
+			Bls12381Pairing::execute(&mut handle).unwrap().output,
+			[0u8; 32].to_vec()
+		);
+
+		// e(infinity, g2) == 1
+		let input = [&[0u8; 128][..], &G2[..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(Bls12381Pairing::execute(&mut handle).unwrap().output, success());
+	}
+
+	#[test]
+	fn pairing_check_invalid_input() {
+		// should fail - empty input
+		let mut handle = MockPrecompileHandle::new(&[], None, &get_context(), false);
+		assert_eq!(
+			Bls12381Pairing::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("Invalid input length, must be a non-zero multiple of 384 bytes".into())
+			})
+		);
+
+		// should fail - point not on curve
+		let mut input = [&G1[..], &G2[..]].concat();
+		input[127] ^= 1;
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(
+			Bls12381Pairing::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("G1 point is not on curve".into())
+			})
+		);
+
+		// should fail - point on curve but not in the subgroup
+		let not_in_subgroup = hex! {"
+			0000000000000000000000000000000000000000000000000000000000000000
+			0000000000000000000000000000000000000000000000000000000000000004
+			000000000000000000000000000000000a989badd40d6212b33cffc3f3763e9b
+			c760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c
+		"};
+		let input = [&not_in_subgroup[..], &G2[..]].concat();
+		let mut handle = MockPrecompileHandle::new(&input, None, &get_context(), false);
+		assert_eq!(
+			Bls12381Pairing::execute(&mut handle),
+			Err(PrecompileFailure::Error {
+				exit_status: ExitError::Other("G1 point is not in the correct subgroup".into())
+			})
+		);
+	}
+}
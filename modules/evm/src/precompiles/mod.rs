@@ -23,22 +23,28 @@ use module_evm_utility::evm::{ExitError, ExitSucceed};
 use sp_std::vec::Vec;
 
 mod blake2;
+mod bls12381;
 mod bn128;
 mod ecrecover;
 mod ecrecover_publickey;
 mod identity;
 mod modexp;
+mod p256verify;
 mod ripemd;
 mod sha256;
 mod sha3fips;
 
 pub use self::ripemd::Ripemd160;
 pub use blake2::Blake2F;
+pub use bls12381::{
+	Bls12381G1Add, Bls12381G1Msm, Bls12381G2Add, Bls12381G2Msm, Bls12381MapG1, Bls12381MapG2, Bls12381Pairing,
+};
 pub use bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 pub use ecrecover::ECRecover;
 pub use ecrecover_publickey::ECRecoverPublicKey;
 pub use identity::Identity;
 pub use modexp::{IstanbulModexp, Modexp};
+pub use p256verify::P256Verify;
 pub use sha256::Sha256;
 pub use sha3fips::{Sha3FIPS256, Sha3FIPS512};
 
@@ -114,7 +120,10 @@ fn ensure_linear_cost(target_gas: Option<u64>, len: u64, base: u64, word: u64) -
 
 pub mod tests {
 	use crate::{ExitError, ExitReason, PrecompileHandle};
-	use module_evm_utility::evm::{Context, Transfer};
+	use module_evm_utility::{
+		ethereum::Log,
+		evm::{Context, Transfer},
+	};
 	use sp_core::{H160, H256};
 	use sp_std::vec::Vec;
 
@@ -125,6 +134,7 @@ pub mod tests {
 		pub gas_used: u64,
 		pub context: &'inner Context,
 		pub is_static: bool,
+		pub logs: Vec<Log>,
 	}
 
 	impl<'inner> MockPrecompileHandle<'inner> {
@@ -136,6 +146,7 @@ pub mod tests {
 				gas_used: 0,
 				context,
 				is_static,
+				logs: Vec::new(),
 			}
 		}
 	}
@@ -184,8 +195,9 @@ pub mod tests {
 			unimplemented!()
 		}
 
-		fn log(&mut self, _address: H160, _topics: Vec<H256>, _data: Vec<u8>) -> Result<(), ExitError> {
-			unimplemented!()
+		fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) -> Result<(), ExitError> {
+			self.logs.push(Log { address, topics, data });
+			Ok(())
 		}
 
 		fn code_address(&self) -> H160 {
@@ -0,0 +1,205 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::LinearCostPrecompile;
+use crate::PrecompileFailure;
+use module_evm_utility::evm::ExitSucceed;
+use p256::{
+	ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey},
+	EncodedPoint, FieldBytes,
+};
+use sp_std::vec::Vec;
+
+/// The P256VERIFY precompile.
+///
+/// Verifies an ECDSA signature over the secp256r1 curve as specified in RIP-7212.
+/// Input is `hash || r || s || x || y`, 160 bytes in total. Returns `1` as a 32-byte word when
+/// the signature is valid and empty output otherwise.
+pub struct P256Verify;
+
+impl LinearCostPrecompile for P256Verify {
+	const BASE: u64 = 3450;
+	const WORD: u64 = 0;
+
+	fn execute(input: &[u8], _: u64) -> core::result::Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+		if input.len() != 160 {
+			return Ok((ExitSucceed::Returned, [0u8; 0].to_vec()));
+		}
+
+		let result = if verify(input) {
+			let mut output = [0u8; 32];
+			output[31] = 1;
+			output.to_vec()
+		} else {
+			[0u8; 0].to_vec()
+		};
+
+		Ok((ExitSucceed::Returned, result))
+	}
+}
+
+fn verify(input: &[u8]) -> bool {
+	let hash = &input[0..32];
+	let r = FieldBytes::clone_from_slice(&input[32..64]);
+	let s = FieldBytes::clone_from_slice(&input[64..96]);
+	let x = FieldBytes::clone_from_slice(&input[96..128]);
+	let y = FieldBytes::clone_from_slice(&input[128..160]);
+
+	// rejects r or s that are zero or not less than the curve order
+	let Ok(signature) = Signature::from_scalars(r, s) else {
+		return false;
+	};
+	// rejects points that are not on the curve, including the point at infinity
+	let Ok(verifying_key) = VerifyingKey::from_encoded_point(&EncodedPoint::from_affine_coordinates(&x, &y, false))
+	else {
+		return false;
+	};
+
+	verifying_key.verify_prehash(hash, &signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hex_literal::hex;
+
+	// Vectors from Wycheproof `ecdsa_secp256r1_sha256_test.json`, with the message hashed and the
+	// DER signature decoded into `r || s`.
+	const VALID: [[u8; 160]; 5] = [
+		hex! {"
+			bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023
+			2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18
+			4cd60b855d442f5b3c7b11eb6c4e0ae7525fe710fab9aa7c77a67f79e6fadd76
+			2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838
+			c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e
+		"},
+		hex! {"
+			bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023
+			2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18
+			b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db
+			2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838
+			c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e
+		"},
+		hex! {"
+			70239dd877f7c944c422f44dea4ed1a52f2627416faf2f072fa50c772ed6f807
+			64a1aab5000d0e804f3e2fc02bdee9be8ff312334e2ba16d11547c97711c898e
+			6af015971cc30be6d1a206d4e013e0997772a2f91d73286ffd683b9bb2cf4f1b
+			2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838
+			c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e
+		"},
+		hex! {"
+			d0462673154cce587dde8800000000e98d35f1f45cf9c3bf46ada2de4c568c34
+			3b2cbf046eac45842ecb7984d475831582717bebb6492fd0a485c101e29ff0a8
+			4c9b7b47a98b0f82de512bc9313aaf51701099cac5f76e68c8595fc1c1d99258
+			2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838
+			c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e
+		"},
+		hex! {"
+			a2b5ffffffffebb251b085377605a224bc80872602a6e467fd016807e97fa395
+			bead6734ebe44b810d3fb2ea00b1732945377338febfd439a8d74dfbd0f942fa
+			6bb18eae36616a7d3cad35919fd21a8af4bbe7a10f73b3e036a46b103ef56e2a
+			2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838
+			c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e
+		"},
+	];
+
+	const INVALID: [[u8; 160]; 5] = [
+		hex! {"
+			bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023
+			0000000000000000000000000000000000000000000000000000000000000000
+			b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db
+			2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838
+			c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e
+		"},
+		hex! {"
+			bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023
+			2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18
+			4cd60b865d442f5a3c7b11eb6c4e0ae79578ec6353a20bf783ecb4b6ea97b825
+			2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838
+			c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e
+		"},
+		hex! {"
+			bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023
+			ffffffff00000001000000000000000000000000fffffffffffffffffffffffc
+			ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254e
+			0ad99500288d466940031d72a9f5445a4d43784640855bf0a69874d2de5fe103
+			c5011e6ef2c42dcd50d5d3d29f99ae6eba2c80c9244f4c5422f0979ff0c3ba5e
+		"},
+		hex! {"
+			bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023
+			6f2347cab7dd76858fe0555ac3bc99048c4aacafdfb6bcbe05ea6c42c4934569
+			bb726660235793aa9957a61e76e00c2c435109cf9a15dd624d53f4301047856b
+			5b812fd521aafa69835a849cce6fbdeb6983b442d2444fe70e134c027fc46963
+			7c75bf0c5c9f6d17ffb16d2726bf30a9c7aaf31a8d317472b1ea145ab66db616
+		"},
+		hex! {"
+			bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023
+			44a5ad0ad0636d9f12bc9e0a6bdd5e1cbcb012ea7bf091fcec15b0c43202d52e
+			249249246db6db6ddb6db6db6db6db6dad4591868595a8ee6bf5f864ff7be0c2
+			6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296
+			b01cbd1c01e58065711814b583f061e9d431cca994cea1313449bf97c840ae0a
+		"},
+	];
+
+	#[test]
+	fn valid_signatures() {
+		let mut expected = [0u8; 32];
+		expected[31] = 1;
+
+		for input in VALID.iter() {
+			let (exit, output) = P256Verify::execute(input, 0).unwrap();
+			assert_eq!(exit, ExitSucceed::Returned);
+			assert_eq!(output, expected.to_vec());
+		}
+	}
+
+	#[test]
+	fn invalid_signatures() {
+		for input in INVALID.iter() {
+			let (exit, output) = P256Verify::execute(input, 0).unwrap();
+			assert_eq!(exit, ExitSucceed::Returned);
+			assert_eq!(output, [0u8; 0].to_vec());
+		}
+	}
+
+	#[test]
+	fn invalid_input_length() {
+		let (exit, output) = P256Verify::execute(&VALID[0][..159], 0).unwrap();
+		assert_eq!(exit, ExitSucceed::Returned);
+		assert_eq!(output, [0u8; 0].to_vec());
+
+		let mut input = VALID[0].to_vec();
+		input.push(0);
+		let (exit, output) = P256Verify::execute(&input, 0).unwrap();
+		assert_eq!(exit, ExitSucceed::Returned);
+		assert_eq!(output, [0u8; 0].to_vec());
+
+		let (exit, output) = P256Verify::execute(&[], 0).unwrap();
+		assert_eq!(exit, ExitSucceed::Returned);
+		assert_eq!(output, [0u8; 0].to_vec());
+	}
+
+	#[test]
+	fn zero_signature_is_invalid() {
+		let mut input = VALID[0];
+		input[32..96].copy_from_slice(&[0u8; 64]);
+		let (exit, output) = P256Verify::execute(&input, 0).unwrap();
+		assert_eq!(exit, ExitSucceed::Returned);
+		assert_eq!(output, [0u8; 0].to_vec());
+	}
+}
@@ -26,7 +26,7 @@ pub mod tracing;
 
 use crate::{BalanceOf, CallInfo, Config, CreateInfo};
 use module_evm_utility::evm;
-pub use primitives::evm::{EvmAddress, Vicinity};
+pub use primitives::evm::{AccessListInfo, EvmAddress, Vicinity};
 use sp_core::{H160, H256};
 use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
@@ -99,4 +99,15 @@ pub trait RunnerExtended<T: Config>: Runner<T> {
 		access_list: Vec<(H160, Vec<H256>)>,
 		config: &evm::Config,
 	) -> Result<CreateInfo, DispatchError>;
+
+	fn rpc_create_access_list(
+		source: H160,
+		target: Option<H160>,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+		config: &evm::Config,
+	) -> Result<AccessListInfo, DispatchError>;
 }
@@ -24,7 +24,8 @@ use crate::{
 		state::{Accessed, StackExecutor, StackState as StackStateT, StackSubstateMetadata},
 		Runner as RunnerT, RunnerExtended,
 	},
-	AccountStorages, BalanceOf, CallInfo, Config, CreateInfo, Error, ExecutionInfo, Pallet, STORAGE_SIZE,
+	AccountStorages, BalanceOf, CallInfo, Config, CreateInfo, Error, ExecutionInfo, IsPrecompileResult, Pallet,
+	PrecompileSet, STORAGE_SIZE,
 };
 use frame_support::{
 	ensure,
@@ -38,7 +39,9 @@ use module_evm_utility::{
 };
 use module_support::{AddressMapping, EVMManager, EVM};
 pub use primitives::{
-	evm::{convert_decimals_from_evm, EvmAddress, Vicinity, MIRRORED_NFT_ADDRESS_START},
+	evm::{
+		convert_decimals_from_evm, AccessListInfo, AccessListItem, EvmAddress, Vicinity, MIRRORED_NFT_ADDRESS_START,
+	},
 	ReserveIdentifier,
 };
 use sp_core::{defer, H160, H256, U256};
@@ -81,6 +84,7 @@ impl<T: Config> Runner<T> {
 		let vicinity = Vicinity {
 			gas_price,
 			origin,
+			block_base_fee_per_gas: Some(Pallet::<T>::base_fee_per_gas()),
 			..Default::default()
 		};
 
@@ -96,6 +100,10 @@ impl<T: Config> Runner<T> {
 			Error::<T>::InvalidDecimals
 		);
 
+		// the prestate tracer needs the balance before the storage deposit is reserved
+		#[cfg(feature = "tracing")]
+		super::tracing::touch(origin);
+
 		if !skip_storage_rent {
 			Pallet::<T>::reserve_storage(&origin, storage_limit).map_err(|e| {
 				log::debug!(
@@ -214,6 +222,8 @@ impl<T: Config> Runner<T> {
 			state.substate.logs
 		);
 
+		Pallet::<T>::record_block_gas_used(used_gas.low_u64());
+
 		Ok(ExecutionInfo {
 			value: retv,
 			exit_reason: reason,
@@ -426,6 +436,115 @@ impl<T: Config> RunnerExtended<T> for Runner<T> {
 			},
 		)
 	}
+
+	/// Special method for rpc which generates the access list of a call, or a create if `target`
+	/// is `None`, like `eth_createAccessList`. The execution is repeated with the generated access
+	/// list until it stops changing, at most `MAX_ACCESS_LIST_ITERATIONS` times, after which the
+	/// last list executed with is returned. State changes are discarded.
+	fn rpc_create_access_list(
+		source: H160,
+		target: Option<H160>,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+		config: &evm::Config,
+	) -> Result<AccessListInfo, DispatchError> {
+		Pallet::<T>::set_origin(T::AddressMapping::get_account_id(&source));
+		defer!(Pallet::<T>::kill_origin());
+
+		let precompiles = T::PrecompilesValue::get();
+		let value = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(value));
+
+		let mut access_list = access_list;
+		let mut initial_used_gas = None;
+		let mut iterations = 0;
+		loop {
+			iterations += 1;
+			// every execution starts from the same state
+			sp_io::storage::start_transaction();
+			let info = Self::execute(
+				source,
+				source,
+				value,
+				gas_limit,
+				storage_limit,
+				config,
+				true,
+				&precompiles,
+				|executor| {
+					executor.state_mut().metadata_mut().enable_access_tracker();
+					let (reason, target) = match target {
+						Some(target) => {
+							let (reason, _) = executor.transact_call(
+								source,
+								target,
+								value,
+								input.clone(),
+								gas_limit,
+								access_list.clone(),
+							);
+							(reason, target)
+						}
+						None => {
+							let address = executor
+								.create_address(evm::CreateScheme::Legacy { caller: source })
+								.unwrap_or_default();
+							let (reason, _) =
+								executor.transact_create(source, value, input.clone(), gas_limit, access_list.clone());
+							(reason, address)
+						}
+					};
+
+					// addresses which are always warm
+					let mut excluded = sp_std::vec![source, target];
+					if config.warm_coinbase_address {
+						excluded.push(executor.state().block_coinbase());
+					}
+
+					let accessed = executor.state().metadata().access_tracker().clone().unwrap_or_default();
+					let mut items = BTreeMap::<H160, Vec<H256>>::new();
+					for address in accessed.accessed_addresses {
+						let is_precompile = matches!(
+							executor.precompiles().is_precompile(address, u64::MAX),
+							IsPrecompileResult::Answer {
+								is_precompile: true,
+								..
+							}
+						);
+						if !is_precompile && !excluded.contains(&address) {
+							items.entry(address).or_default();
+						}
+					}
+					for (address, key) in accessed.accessed_storage {
+						items.entry(address).or_default().push(key);
+					}
+
+					(reason, items.into_iter().collect::<Vec<_>>())
+				},
+			);
+			sp_io::storage::rollback_transaction();
+			let info = info?;
+
+			let initial_used_gas = *initial_used_gas.get_or_insert(info.used_gas);
+			if info.value == access_list || iterations >= crate::MAX_ACCESS_LIST_ITERATIONS {
+				// the access list is not guaranteed to be stable if the execution depends on the
+				// gas left, return the one `used_gas` is measured with instead of looping forever
+				return Ok(AccessListInfo {
+					access_list: access_list
+						.into_iter()
+						.map(|(address, storage_keys)| AccessListItem { address, storage_keys })
+						.collect(),
+					exit_reason: info.exit_reason,
+					used_gas: info.used_gas,
+					used_storage: info.used_storage,
+					gas_saved: initial_used_gas.saturating_sub(info.used_gas),
+				});
+			}
+			access_list = info.value;
+		}
+	}
 }
 
 struct SubstrateStackSubstate<'config> {
@@ -435,6 +554,11 @@ struct SubstrateStackSubstate<'config> {
 	storage_logs: Vec<(H160, i32)>,
 	parent: Option<Box<SubstrateStackSubstate<'config>>>,
 	known_original_storage: BTreeMap<(H160, H256), H256>,
+	// EIP-1153: transient storage is discarded at the end of the transaction and never touches
+	// the storage meter.
+	transient_storage: BTreeMap<(H160, H256), U256>,
+	// EIP-6780: contracts created in the current transaction.
+	creates: BTreeSet<H160>,
 }
 
 impl<'config> SubstrateStackSubstate<'config> {
@@ -454,6 +578,8 @@ impl<'config> SubstrateStackSubstate<'config> {
 			logs: Vec::new(),
 			storage_logs: Vec::new(),
 			known_original_storage: BTreeMap::new(),
+			transient_storage: BTreeMap::new(),
+			creates: BTreeSet::new(),
 		};
 		mem::swap(&mut entering, self);
 
@@ -474,6 +600,8 @@ impl<'config> SubstrateStackSubstate<'config> {
 		})?;
 		self.logs.append(&mut exited.logs);
 		self.deletes.append(&mut exited.deletes);
+		self.transient_storage.append(&mut exited.transient_storage);
+		self.creates.append(&mut exited.creates);
 
 		exited.storage_logs.push((target, storage));
 		self.storage_logs.append(&mut exited.storage_logs);
@@ -520,6 +648,38 @@ impl<'config> SubstrateStackSubstate<'config> {
 		self.deletes.insert(address);
 	}
 
+	pub fn created(&self, address: H160) -> bool {
+		if self.creates.contains(&address) {
+			return true;
+		}
+
+		if let Some(parent) = self.parent.as_ref() {
+			return parent.created(address);
+		}
+
+		false
+	}
+
+	pub fn set_created(&mut self, address: H160) {
+		self.creates.insert(address);
+	}
+
+	pub fn tload(&self, address: H160, index: H256) -> U256 {
+		if let Some(value) = self.transient_storage.get(&(address, index)) {
+			return *value;
+		}
+
+		if let Some(parent) = self.parent.as_ref() {
+			return parent.tload(address, index);
+		}
+
+		U256::zero()
+	}
+
+	pub fn tstore(&mut self, address: H160, index: H256, value: U256) {
+		self.transient_storage.insert((address, index), value);
+	}
+
 	pub fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) {
 		self.logs.push(Log { address, topics, data });
 	}
@@ -591,6 +751,8 @@ impl<'vicinity, 'config, T: Config> SubstrateStackState<'vicinity, 'config, T> {
 				storage_logs: Vec::new(),
 				parent: None,
 				known_original_storage: BTreeMap::new(),
+				transient_storage: BTreeMap::new(),
+				creates: BTreeSet::new(),
 			},
 			_marker: PhantomData,
 		}
@@ -705,6 +867,15 @@ impl<T: Config> BackendT for SubstrateStackState<'_, '_, T> {
 	fn block_base_fee_per_gas(&self) -> sp_core::U256 {
 		self.vicinity.block_base_fee_per_gas.unwrap_or(U256::one())
 	}
+
+	fn blob_gas_price(&self) -> Option<u128> {
+		// There are no blob transactions, use the minimum blob base fee defined in EIP-4844.
+		Some(1)
+	}
+
+	fn get_blob_hash(&self, _index: usize) -> Option<U256> {
+		None
+	}
 }
 
 impl<'config, T: Config> StackStateT<'config> for SubstrateStackState<'_, 'config, T> {
@@ -798,6 +969,29 @@ impl<'config, T: Config> StackStateT<'config> for SubstrateStackState<'_, 'confi
 		self.substate.set_deleted(address)
 	}
 
+	fn created(&self, address: H160) -> bool {
+		self.substate.created(address)
+	}
+
+	fn set_created(&mut self, address: H160) {
+		self.substate.set_created(address)
+	}
+
+	fn tload(&self, address: H160, index: H256) -> U256 {
+		self.substate.tload(address, index)
+	}
+
+	fn tstore(&mut self, address: H160, index: H256, value: U256) {
+		log::debug!(
+			target: "evm",
+			"Updating transient storage for {:?} [index: {:?}, value: {:?}]",
+			address,
+			index,
+			value,
+		);
+		self.substate.tstore(address, index, value)
+	}
+
 	fn set_code(&mut self, address: H160, code: Vec<u8>) {
 		log::debug!(
 			target: "evm",
@@ -124,6 +124,9 @@ pub struct StackSubstateMetadata<'config> {
 	is_static: bool,
 	depth: Option<usize>,
 	accessed: Option<Accessed>,
+	// every address and storage accessed, including the ones of reverted calls, used to generate
+	// access lists
+	access_tracker: Option<Accessed>,
 	// save the caller which called `inner_create` to get maintainer
 	caller: Option<H160>,
 	// save the contract to charge storage
@@ -148,6 +151,7 @@ impl<'config> StackSubstateMetadata<'config> {
 			is_static: false,
 			depth: None,
 			accessed,
+			access_tracker: None,
 			caller: None,
 			target: None,
 			origin_code_address: None,
@@ -159,6 +163,7 @@ impl<'config> StackSubstateMetadata<'config> {
 	pub fn swallow_commit(&mut self, other: Self) -> Result<(), ExitError> {
 		self.gasometer.record_stipend(other.gasometer.gas())?;
 		self.gasometer.record_refund(other.gasometer.refunded_gas())?;
+		self.swallow_access_tracker(other.access_tracker);
 
 		if let (Some(mut other_accessed), Some(self_accessed)) = (other.accessed, self.accessed.as_mut()) {
 			self_accessed
@@ -182,14 +187,28 @@ impl<'config> StackSubstateMetadata<'config> {
 
 	pub fn swallow_revert(&mut self, other: Self) -> Result<(), ExitError> {
 		self.gasometer.record_stipend(other.gasometer.gas())?;
+		self.swallow_access_tracker(other.access_tracker);
 
 		Ok(())
 	}
 
-	pub fn swallow_discard(&mut self, _other: Self) -> Result<(), ExitError> {
+	pub fn swallow_discard(&mut self, other: Self) -> Result<(), ExitError> {
+		self.swallow_access_tracker(other.access_tracker);
+
 		Ok(())
 	}
 
+	fn swallow_access_tracker(&mut self, other: Option<Accessed>) {
+		if let (Some(mut other_tracker), Some(self_tracker)) = (other, self.access_tracker.as_mut()) {
+			self_tracker
+				.accessed_addresses
+				.append(&mut other_tracker.accessed_addresses);
+			self_tracker
+				.accessed_storage
+				.append(&mut other_tracker.accessed_storage);
+		}
+	}
+
 	pub fn spit_child(&self, gas_limit: u64, is_static: bool) -> Self {
 		event!(Enter {
 			depth: match self.depth {
@@ -206,6 +225,7 @@ impl<'config> StackSubstateMetadata<'config> {
 				Some(n) => Some(n + 1),
 			},
 			accessed: self.accessed.as_ref().map(|_| Accessed::default()),
+			access_tracker: self.access_tracker.as_ref().map(|_| Accessed::default()),
 			caller: None,
 			target: None,
 			origin_code_address: self.origin_code_address,
@@ -239,6 +259,9 @@ impl<'config> StackSubstateMetadata<'config> {
 	}
 
 	pub fn access_address(&mut self, address: H160) {
+		if let Some(tracker) = &mut self.access_tracker {
+			tracker.access_address(address)
+		}
 		if let Some(accessed) = &mut self.accessed {
 			accessed.access_address(address)
 		}
@@ -246,14 +269,20 @@ impl<'config> StackSubstateMetadata<'config> {
 
 	pub fn access_addresses<I>(&mut self, addresses: I)
 	where
-		I: Iterator<Item = H160>,
+		I: Iterator<Item = H160> + Clone,
 	{
+		if let Some(tracker) = &mut self.access_tracker {
+			tracker.access_addresses(addresses.clone());
+		}
 		if let Some(accessed) = &mut self.accessed {
 			accessed.access_addresses(addresses);
 		}
 	}
 
 	pub fn access_storage(&mut self, address: H160, key: H256) {
+		if let Some(tracker) = &mut self.access_tracker {
+			tracker.accessed_storage.insert((address, key));
+		}
 		if let Some(accessed) = &mut self.accessed {
 			accessed.accessed_storage.insert((address, key));
 		}
@@ -261,8 +290,11 @@ impl<'config> StackSubstateMetadata<'config> {
 
 	pub fn access_storages<I>(&mut self, storages: I)
 	where
-		I: Iterator<Item = (H160, H256)>,
+		I: Iterator<Item = (H160, H256)> + Clone,
 	{
+		if let Some(tracker) = &mut self.access_tracker {
+			tracker.access_storages(storages.clone());
+		}
 		if let Some(accessed) = &mut self.accessed {
 			accessed.access_storages(storages);
 		}
@@ -272,6 +304,15 @@ impl<'config> StackSubstateMetadata<'config> {
 		&self.accessed
 	}
 
+	/// Start tracking every accessed address and storage, including the ones of reverted calls.
+	pub fn enable_access_tracker(&mut self) {
+		self.access_tracker = Some(Accessed::default());
+	}
+
+	pub fn access_tracker(&self) -> &Option<Accessed> {
+		&self.access_tracker
+	}
+
 	pub fn caller(&self) -> &Option<H160> {
 		&self.caller
 	}
@@ -316,6 +357,10 @@ pub trait StackState<'config>: Backend {
 	fn reset_storage(&mut self, address: H160);
 	fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>);
 	fn set_deleted(&mut self, address: H160);
+	fn created(&self, address: H160) -> bool;
+	fn set_created(&mut self, address: H160);
+	fn tload(&self, address: H160, index: H256) -> U256;
+	fn tstore(&mut self, address: H160, index: H256, value: U256);
 	fn set_code(&mut self, address: H160, code: Vec<u8>);
 	fn transfer(&mut self, transfer: Transfer) -> Result<(), ExitError>;
 	fn reset_balance(&mut self, address: H160);
@@ -950,6 +995,9 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 			}
 		}
 
+		// EIP-6780: keep track of the contracts created in the current transaction
+		self.state.set_created(address);
+
 		if self.config.create_increase_nonce {
 			if let Err(e) = self.record_external_operation(crate::ExternalOperation::AccountBasicRead) {
 				let _ = self.exit_substate(StackExitKind::Failed);
@@ -1329,6 +1377,12 @@ impl<'config, S: StackState<'config>, P: PrecompileSet> Handler for StackExecuto
 	fn chain_id(&self) -> U256 {
 		self.state.chain_id()
 	}
+	fn blob_base_fee(&self) -> Option<u128> {
+		self.state.blob_gas_price()
+	}
+	fn get_blob_hash(&self, index: usize) -> Option<U256> {
+		self.state.get_blob_hash(index)
+	}
 
 	fn deleted(&self, address: H160) -> bool {
 		self.state.deleted(address)
@@ -1339,6 +1393,15 @@ impl<'config, S: StackState<'config>, P: PrecompileSet> Handler for StackExecuto
 		Ok(())
 	}
 
+	fn tload(&mut self, address: H160, index: H256) -> Result<U256, ExitError> {
+		Ok(self.state.tload(address, index))
+	}
+
+	fn tstore(&mut self, address: H160, index: H256, value: U256) -> Result<(), ExitError> {
+		self.state.tstore(address, index, value);
+		Ok(())
+	}
+
 	fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) -> Result<(), ExitError> {
 		event!(Log {
 			address,
@@ -1358,6 +1421,19 @@ impl<'config, S: StackState<'config>, P: PrecompileSet> Handler for StackExecuto
 			balance,
 		});
 
+		// EIP-6780: SELFDESTRUCT only deletes the contract if it was created in the same
+		// transaction, otherwise it just sends all the funds to the target.
+		if self.config.eip_6780_enabled && !self.state.created(address) {
+			if address != target {
+				self.state.transfer(Transfer {
+					source: address,
+					target,
+					value: balance,
+				})?;
+			}
+			return Ok(());
+		}
+
 		self.state.transfer(Transfer {
 			source: address,
 			target,
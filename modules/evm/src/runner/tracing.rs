@@ -16,14 +16,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{Config, Pallet};
 use module_evm_utility::{
 	evm::{Context, ExitError, ExitFatal, ExitReason, ExitSucceed, Opcode, Transfer},
 	evm_gasometer, evm_runtime,
 };
 use sp_core::{H160, H256, U256};
-use sp_std::prelude::*;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
-pub use primitives::evm::tracing::{CallTrace, CallType, LogTrace, OpcodeConfig, Step, TraceOutcome, TracerConfig};
+pub use primitives::evm::tracing::{
+	AccountState, CallTrace, CallType, LogTrace, OpcodeConfig, PrestateConfig, PrestateTrace, Step, TraceOutcome,
+	TracerConfig,
+};
 
 #[derive(Debug, Copy, Clone)]
 pub enum Event<'a> {
@@ -100,6 +105,14 @@ pub struct Tracer {
 	step_counter: u32,
 	gas: u64,
 	current_opcode: Option<Opcode>,
+	// reads the chain state, only set by `trace` for the prestate tracer
+	reader: Option<StateReader>,
+	// state of the touched accounts and storage slots when they were first accessed
+	prestate: BTreeMap<H160, AccountState>,
+	// state of the touched accounts and storage slots after the execution
+	poststate: Option<BTreeMap<H160, AccountState>>,
+	// whether any transaction was executed by the EVM
+	executed: bool,
 }
 
 impl Tracer {
@@ -113,9 +126,18 @@ impl Tracer {
 			step_counter: 0,
 			gas: 0,
 			current_opcode: None,
+			reader: None,
+			prestate: BTreeMap::new(),
+			poststate: None,
+			executed: false,
 		}
 	}
 
+	/// Returns `true` if the EVM executed a transaction while tracing.
+	pub fn executed(&self) -> bool {
+		self.executed
+	}
+
 	#[inline]
 	fn trace_memory(&self) -> bool {
 		matches!(
@@ -143,6 +165,34 @@ impl Tracer {
 		matches!(self.config, TracerConfig::CallTracer)
 	}
 
+	#[inline]
+	fn trace_prestate(&self) -> bool {
+		matches!(self.config, TracerConfig::PrestateTracer(_))
+	}
+
+	// record the state of the account if this is the first time it is accessed
+	#[inline]
+	fn touch(&mut self, address: H160) {
+		if let Some(reader) = self.reader {
+			self.prestate
+				.entry(address)
+				.or_insert_with(|| (reader.account)(&address));
+		}
+	}
+
+	// record the value of the storage slot if this is the first time it is accessed
+	#[inline]
+	fn touch_storage(&mut self, address: H160, index: H256) {
+		if let Some(reader) = self.reader {
+			self.prestate
+				.entry(address)
+				.or_insert_with(|| (reader.account)(&address))
+				.storage
+				.entry(index)
+				.or_insert_with(|| (reader.storage)(&address, &index));
+		}
+	}
+
 	// increment step counter and check if we should record this step
 	#[inline]
 	fn count_step(&mut self) -> bool {
@@ -162,9 +212,37 @@ impl Tracer {
 				TraceOutcome::Calls(self.calls.drain(..).collect())
 			}
 			TracerConfig::OpcodeTracer(_) => TraceOutcome::Steps(self.steps.drain(..).collect()),
+			TracerConfig::PrestateTracer(PrestateConfig { diff_mode }) => {
+				// the state is only read when executed with `trace`
+				let pre = sp_std::mem::take(&mut self.prestate);
+				let post = self.poststate.take().unwrap_or_default();
+				if diff_mode {
+					diff(pre, post)
+				} else {
+					TraceOutcome::Prestate(PrestateTrace::Prestate(pre))
+				}
+			}
 		}
 	}
 
+	/// Read the current state of the touched accounts and storage slots.
+	fn read_state<T: Config>(&self) -> BTreeMap<H160, AccountState> {
+		self.prestate
+			.iter()
+			.map(|(address, pre)| {
+				let state = AccountState {
+					storage: pre
+						.storage
+						.keys()
+						.map(|index| (*index, read_storage::<T>(address, index)))
+						.collect(),
+					..read_account::<T>(address)
+				};
+				(*address, state)
+			})
+			.collect()
+	}
+
 	#[inline]
 	fn evm_runtime_event(&mut self, event: evm_runtime::tracing::Event) {
 		match event {
@@ -226,6 +304,9 @@ impl Tracer {
 				}
 			}
 			evm_runtime::tracing::Event::SLoad { address, index, value } => {
+				if self.trace_prestate() {
+					self.touch_storage(address, index);
+				}
 				if !self.trace_call() {
 					return;
 				}
@@ -233,6 +314,9 @@ impl Tracer {
 				trace.logs.push(LogTrace::SLoad { address, index, value });
 			}
 			evm_runtime::tracing::Event::SStore { address, index, value } => {
+				if self.trace_prestate() {
+					self.touch_storage(address, index);
+				}
 				if !self.trace_call() {
 					return;
 				}
@@ -403,6 +487,127 @@ impl Tracer {
 			}
 		}
 	}
+
+	#[inline]
+	fn prestate_event(&mut self, event: Event) {
+		match event {
+			Event::Call {
+				code_address,
+				transfer,
+				context,
+				..
+			}
+			| Event::PrecompileSubcall {
+				code_address,
+				transfer,
+				context,
+				..
+			} => {
+				self.touch(code_address);
+				self.touch(context.address);
+				self.touch(context.caller);
+				if let Some(transfer) = transfer {
+					self.touch(transfer.source);
+					self.touch(transfer.target);
+				}
+			}
+			Event::TransactCall { caller, address, .. }
+			| Event::Create { caller, address, .. }
+			| Event::TransactCreate { caller, address, .. }
+			| Event::TransactCreate2 { caller, address, .. } => {
+				self.touch(caller);
+				self.touch(address);
+			}
+			Event::Suicide { address, target, .. } => {
+				self.touch(address);
+				self.touch(target);
+			}
+			Event::Exit { .. } | Event::Enter { .. } | Event::Log { .. } => {}
+		}
+	}
+}
+
+#[derive(Clone, Copy)]
+struct StateReader {
+	account: fn(&H160) -> AccountState,
+	storage: fn(&H160, &H256) -> H256,
+}
+
+/// Read the balance, nonce and code hash of the account.
+fn read_account<T: Config>(address: &H160) -> AccountState {
+	let account = Pallet::<T>::account_basic(address);
+	AccountState {
+		balance: Some(account.balance),
+		nonce: Some(account.nonce.unique_saturated_into()),
+		code_hash: Pallet::<T>::accounts(address)
+			.and_then(|info| info.contract_info)
+			.map(|contract_info| contract_info.code_hash),
+		storage: BTreeMap::new(),
+	}
+}
+
+fn read_storage<T: Config>(address: &H160, index: &H256) -> H256 {
+	Pallet::<T>::account_storages(address, index)
+}
+
+/// Build the diff of the touched accounts.
+fn diff(pre: BTreeMap<H160, AccountState>, mut post: BTreeMap<H160, AccountState>) -> TraceOutcome {
+	let is_empty = |state: &AccountState| {
+		state.balance.unwrap_or_default().is_zero()
+			&& state.nonce.unwrap_or_default() == 0
+			&& state.code_hash.is_none()
+			&& state.storage.values().all(|value| value.is_zero())
+	};
+
+	let mut pre_diff = BTreeMap::new();
+	let mut post_diff = BTreeMap::new();
+
+	for (address, before) in pre {
+		let after = post.remove(&address).unwrap_or_default();
+		if before == after {
+			continue;
+		}
+
+		let changed_slots = before
+			.storage
+			.iter()
+			.filter(|(index, value)| after.storage.get(index) != Some(value))
+			.map(|(index, _)| *index)
+			.collect::<Vec<_>>();
+
+		if !is_empty(&after) {
+			post_diff.insert(
+				address,
+				AccountState {
+					balance: after.balance.filter(|balance| Some(*balance) != before.balance),
+					nonce: after.nonce.filter(|nonce| Some(*nonce) != before.nonce),
+					code_hash: after.code_hash.filter(|code_hash| Some(*code_hash) != before.code_hash),
+					storage: changed_slots
+						.iter()
+						.filter_map(|index| after.storage.get(index).map(|value| (*index, *value)))
+						.collect(),
+				},
+			);
+		}
+
+		if !is_empty(&before) {
+			pre_diff.insert(
+				address,
+				AccountState {
+					storage: changed_slots
+						.iter()
+						.filter_map(|index| before.storage.get(index).map(|value| (*index, *value)))
+						.collect(),
+					..before
+				},
+			);
+		}
+	}
+
+	TraceOutcome::Prestate(PrestateTrace::Diff {
+		pre: pre_diff,
+		post: post_diff,
+	})
 }
 
 pub trait EventListener {
@@ -411,8 +616,17 @@ pub trait EventListener {
 
 impl EventListener for Tracer {
 	fn event(&mut self, event: Event) {
+		if matches!(
+			event,
+			Event::TransactCall { .. } | Event::TransactCreate { .. } | Event::TransactCreate2 { .. }
+		) {
+			self.executed = true;
+		}
+
 		if self.trace_call() {
 			self.call_event(event);
+		} else if self.trace_prestate() {
+			self.prestate_event(event);
 		} else {
 			match event {
 				Event::Exit { reason, .. } => {
@@ -459,10 +673,36 @@ pub fn using<R, F: FnOnce() -> R>(new: &mut Tracer, f: F) -> R {
 	})
 }
 
+/// Run `f` with the given tracer. The changes made by `f` are kept.
+///
+/// The prestate tracer reads the state of an account or storage slot the first time it is
+/// accessed, which is after the transaction fee has been withdrawn, and reads the touched
+/// accounts again once `f` returns.
+pub fn trace<T: Config, R, F: FnOnce() -> R>(tracer: &mut Tracer, f: F) -> R {
+	if !tracer.trace_prestate() {
+		return using(tracer, f);
+	}
+
+	tracer.reader = Some(StateReader {
+		account: read_account::<T>,
+		storage: read_storage::<T>,
+	});
+	let result = using(tracer, f);
+	tracer.reader = None;
+	tracer.poststate = Some(tracer.read_state::<T>());
+
+	result
+}
+
 pub(crate) fn with<F: FnOnce(&mut Tracer)>(f: F) {
 	tracer::with(f);
 }
 
+/// Record the state of the account before it is modified outside of the EVM execution.
+pub(crate) fn touch(address: H160) {
+	tracer::with(|tracer| tracer.touch(address));
+}
+
 trait Stringify {
 	fn stringify(&self) -> &str;
 }
@@ -25,7 +25,7 @@ use crate::runner::{
 	stack::SubstrateStackState,
 	state::{StackExecutor, StackState, StackSubstateMetadata},
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use insta::assert_debug_snapshot;
 use module_support::{mocks::MockAddressMapping, AddressMapping};
 use sp_core::{
@@ -1642,6 +1642,210 @@ fn should_selfdestruct_with_schedule_task() {
 	});
 }
 
+#[test]
+fn scheduled_call_task_works() {
+	// pragma solidity ^0.8.0;
+	//
+	// contract Test {
+	//     mapping(uint256 => uint256) private data;
+	//
+	//     constructor() public payable {}
+	//
+	//     function setValue(uint256 key, uint256 value) public {
+	//         data[key] = value;
+	//     }
+	// }
+	let contract = from_hex(
+		"0x6080604052610105806100136000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c80637b8d56e314602d575b600080fd5b60436004803603810190603f91906096565b6045565b005b80600080848152602001908152602001600020819055505050565b600080fd5b6000819050919050565b6076816065565b8114608057600080fd5b50565b600081359050609081606f565b92915050565b6000806040838503121560aa5760a96060565b5b600060b6858286016083565b925050602060c5858286016083565b915050925092905056fea26469706673582212201cbfb5695481e8cf4c7a1206d22d0a707cb85907a10b47038ac14af0c386344464736f6c63430008120033"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+
+		// setValue(1, 1)
+		let mut input: Vec<u8> = from_hex("0x7b8d56e3").unwrap();
+		input.append(&mut H256::from_low_u64_be(1).as_bytes().to_vec());
+		input.append(&mut H256::from_low_u64_be(1).as_bytes().to_vec());
+
+		let gas_limit = 100_000;
+		assert_ok!(module_idle_scheduler::Pallet::<Runtime>::schedule_task(
+			RuntimeOrigin::root(),
+			EvmTask::<Runtime>::Schedule {
+				from: alice(),
+				target: contract_address,
+				input,
+				value: 0,
+				gas_limit,
+				storage_limit: 1000,
+			}
+			.into()
+		));
+
+		let on_idle_base = <() as module_idle_scheduler::WeightInfo>::on_idle_base();
+
+		// not enough weight, the task is kept
+		module_idle_scheduler::Pallet::<Runtime>::on_idle(0, on_idle_base + Weight::from_parts(gas_limit / 2, 0));
+		assert!(module_idle_scheduler::Tasks::<Runtime>::contains_key(0));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 0);
+
+		module_idle_scheduler::Pallet::<Runtime>::on_idle(0, on_idle_base + Weight::from_parts(gas_limit * 10, 0));
+		assert!(!module_idle_scheduler::Tasks::<Runtime>::contains_key(0));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+
+		System::assert_has_event(RuntimeEvent::IdleScheduler(
+			module_idle_scheduler::Event::TaskDispatched {
+				task_id: 0,
+				result: Ok(()),
+			},
+		));
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::EVM(crate::Event::Executed { from, contract, .. })
+				if *from == alice() && *contract == contract_address
+		)));
+	});
+}
+
+#[test]
+fn scheduled_call_task_waits_for_enough_weight() {
+	new_test_ext().execute_with(|| {
+		let gas_limit = 100_000;
+		let task = EvmTask::<Runtime>::Schedule {
+			from: alice(),
+			target: bob(),
+			input: vec![],
+			value: 0,
+			gas_limit,
+			storage_limit: 1000,
+		};
+
+		// the weight can't cover the gas limit, the task is kept without using any weight
+		let weight = <Runtime as Config>::GasToWeight::convert(gas_limit - 1);
+		assert_eq!(
+			task.clone().dispatch(weight),
+			TaskResult {
+				result: Ok(()),
+				used_weight: Weight::zero(),
+				finished: false,
+			}
+		);
+		assert!(!System::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::EVM(crate::Event::Executed { .. } | crate::Event::ExecutedFailed { .. })
+		)));
+
+		let result = task.dispatch(<Runtime as Config>::GasToWeight::convert(gas_limit));
+		assert_eq!(result.result, Ok(()));
+		assert!(result.finished);
+	});
+}
+
+#[test]
+fn failed_scheduled_call_task_is_dropped() {
+	// pragma solidity ^0.8.0;
+	//
+	// contract Test {
+	//     mapping(uint256 => uint256) private data;
+	//
+	//     constructor() public payable {}
+	//
+	//     function setValue(uint256 key, uint256 value) public {
+	//         data[key] = value;
+	//     }
+	// }
+	let contract = from_hex(
+		"0x6080604052610105806100136000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c80637b8d56e314602d575b600080fd5b60436004803603810190603f91906096565b6045565b005b80600080848152602001908152602001600020819055505050565b600080fd5b6000819050919050565b6076816065565b8114608057600080fd5b50565b600081359050609081606f565b92915050565b6000806040838503121560aa5760a96060565b5b600060b6858286016083565b925050602060c5858286016083565b915050925092905056fea26469706673582212201cbfb5695481e8cf4c7a1206d22d0a707cb85907a10b47038ac14af0c386344464736f6c63430008120033"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+
+		// setValue(1, 1)
+		let mut input: Vec<u8> = from_hex("0x7b8d56e3").unwrap();
+		input.append(&mut H256::from_low_u64_be(1).as_bytes().to_vec());
+		input.append(&mut H256::from_low_u64_be(1).as_bytes().to_vec());
+
+		// the caller without balance can't pay the storage deposit
+		let poor = H160::from_low_u64_be(0x1234);
+		assert_eq!(balance(poor), 0);
+		assert_ok!(module_idle_scheduler::Pallet::<Runtime>::schedule_task(
+			RuntimeOrigin::root(),
+			EvmTask::<Runtime>::Schedule {
+				from: poor,
+				target: contract_address,
+				input: input.clone(),
+				value: 0,
+				gas_limit: 100_000,
+				storage_limit: 1000,
+			}
+			.into()
+		));
+
+		// the call with an unknown selector reverts
+		assert_ok!(module_idle_scheduler::Pallet::<Runtime>::schedule_task(
+			RuntimeOrigin::root(),
+			EvmTask::<Runtime>::Schedule {
+				from: alice(),
+				target: contract_address,
+				input: from_hex("0x12345678").unwrap(),
+				value: 0,
+				gas_limit: 100_000,
+				storage_limit: 1000,
+			}
+			.into()
+		));
+
+		let on_idle_base = <() as module_idle_scheduler::WeightInfo>::on_idle_base();
+		module_idle_scheduler::Pallet::<Runtime>::on_idle(0, on_idle_base + Weight::from_parts(1_000_000_000, 0));
+
+		// both tasks are dropped instead of being retried
+		assert_eq!(module_idle_scheduler::Tasks::<Runtime>::iter().count(), 0);
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 0);
+		for task_id in 0..2 {
+			System::assert_has_event(RuntimeEvent::IdleScheduler(
+				module_idle_scheduler::Event::TaskDispatched {
+					task_id,
+					result: Ok(()),
+				},
+			));
+		}
+		for caller in [poor, alice()] {
+			assert!(System::events().iter().any(|record| matches!(
+				&record.event,
+				RuntimeEvent::EVM(crate::Event::ExecutedFailed { from, contract, .. })
+					if *from == caller && *contract == contract_address
+			)));
+		}
+
+		// nothing is dispatched again
+		System::reset_events();
+		module_idle_scheduler::Pallet::<Runtime>::on_idle(0, on_idle_base + Weight::from_parts(1_000_000_000, 0));
+		assert!(!System::events()
+			.iter()
+			.any(|record| matches!(&record.event, RuntimeEvent::EVM(crate::Event::ExecutedFailed { .. }))));
+	});
+}
+
 #[test]
 fn storage_limit_should_work() {
 	// pragma solidity ^0.5.0;
@@ -2122,6 +2326,101 @@ fn should_update_storage() {
 	});
 }
 
+#[test]
+fn transient_storage_works() {
+	// tload(1), tstore(1, 42), tload(1) and return both loaded values
+	//
+	// PUSH1 0x01 TLOAD PUSH1 0x00 MSTORE
+	// PUSH1 0x2a PUSH1 0x01 TSTORE
+	// PUSH1 0x01 TLOAD PUSH1 0x20 MSTORE
+	// PUSH1 0x40 PUSH1 0x00 RETURN
+	let contract = from_hex("0x6016600c60003960166000f360015c600052602a60015d60015c60205260406000f3").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_address = result.value;
+
+		let mut expected = [0u8; 64];
+		expected[63] = 42;
+
+		// transient storage is discarded at the end of each transaction
+		for _ in 0..2 {
+			let alice_balance = balance(alice());
+			let result = <Runtime as Config>::Runner::call(
+				alice(),
+				alice(),
+				contract_address,
+				vec![],
+				0,
+				1000000,
+				1000,
+				vec![],
+				<Runtime as Config>::config(),
+			)
+			.unwrap();
+			assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(result.value, expected.to_vec());
+
+			// transient storage is not charged for storage deposit
+			assert_eq!(result.used_storage, 0);
+			assert_eq!(balance(alice()), alice_balance);
+			assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 0);
+		}
+	});
+}
+
+#[test]
+fn selfdestruct_only_removes_contract_created_in_same_transaction() {
+	// CALLER SELFDESTRUCT
+	let contract = from_hex("0x6002600c60003960026000f333ff").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			convert_decimals_to_evm(1000),
+			1000000,
+			1000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_address = result.value;
+		assert_eq!(balance(contract_address), 1000);
+
+		let alice_balance = balance(alice());
+		let result = <Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_address,
+			vec![],
+			0,
+			1000000,
+			1000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Suicided));
+
+		// EIP-6780: the funds are sent to the target, but the contract is kept
+		assert_eq!(balance(contract_address), 0);
+		assert_eq!(balance(alice()), alice_balance + 1000);
+		assert!(EVM::is_contract(&contract_address));
+	});
+}
+
 #[test]
 fn code_hash_with_non_existent_address_should_work() {
 	new_test_ext().execute_with(|| {
@@ -3252,3 +3551,595 @@ fn tracer_works() {
 		}
 	})
 }
+
+#[cfg(feature = "tracing")]
+#[test]
+fn prestate_tracer_works() {
+	// pragma solidity ^0.8.0;
+	//
+	// contract Test {
+	//     mapping(uint256 => uint256) private data;
+	//
+	//     constructor() public payable {}
+	//
+	//     function setValue(uint256 key, uint256 value) public {
+	//         data[key] = value;
+	//     }
+	// }
+	let contract = from_hex(
+		"0x6080604052610105806100136000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c80637b8d56e314602d575b600080fd5b60436004803603810190603f91906096565b6045565b005b80600080848152602001908152602001600020819055505050565b600080fd5b6000819050919050565b6076816065565b8114608057600080fd5b50565b600081359050609081606f565b92915050565b6000806040838503121560aa5760a96060565b5b600060b6858286016083565b925050602060c5858286016083565b915050925092905056fea26469706673582212201cbfb5695481e8cf4c7a1206d22d0a707cb85907a10b47038ac14af0c386344464736f6c63430008120033"
+	).unwrap();
+
+	use crate::runner::tracing::{AccountState, PrestateConfig, PrestateTrace, TraceOutcome, Tracer, TracerConfig};
+
+	new_test_ext().execute_with(|| {
+		let contract_address = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap()
+		.value;
+		let code_hash = EVM::code_hash_at_address(&contract_address);
+
+		// data[1]
+		let slot = H256::from(sp_io::hashing::keccak_256(
+			&[H256::from_low_u64_be(1).as_bytes(), H256::zero().as_bytes()].concat(),
+		));
+
+		let alice_basic = EVM::account_basic(&alice());
+		let contract_basic = EVM::account_basic(&contract_address);
+
+		let trace = |diff_mode, value| {
+			// setValue(1, value)
+			let mut input: Vec<u8> = from_hex("0x7b8d56e3").unwrap();
+			input.append(&mut H256::from_low_u64_be(1).as_bytes().to_vec());
+			input.append(&mut H256::from_low_u64_be(value).as_bytes().to_vec());
+
+			let mut tracer = Tracer::new(TracerConfig::PrestateTracer(PrestateConfig { diff_mode }));
+			crate::runner::tracing::trace::<Runtime, _, _>(&mut tracer, || {
+				<Runtime as Config>::Runner::call(
+					alice(),
+					alice(),
+					contract_address,
+					input,
+					0,
+					1000000,
+					1000,
+					vec![],
+					<Runtime as Config>::config(),
+				)
+				.unwrap()
+			});
+			tracer.finalize()
+		};
+
+		let TraceOutcome::Prestate(PrestateTrace::Prestate(pre)) = trace(false, 2) else {
+			panic!("unexpected trace outcome");
+		};
+		assert_eq!(
+			pre.get(&alice()),
+			Some(&AccountState {
+				balance: Some(alice_basic.balance),
+				nonce: Some(alice_basic.nonce.as_u64()),
+				code_hash: None,
+				storage: Default::default(),
+			})
+		);
+		assert_eq!(
+			pre.get(&contract_address),
+			Some(&AccountState {
+				balance: Some(contract_basic.balance),
+				nonce: Some(contract_basic.nonce.as_u64()),
+				code_hash: Some(code_hash),
+				storage: [(slot, H256::zero())].into(),
+			})
+		);
+
+		// changes are kept
+		assert_eq!(EVM::account_basic(&alice()).nonce, alice_basic.nonce + 1);
+		assert_eq!(EVM::account_storages(contract_address, slot), H256::from_low_u64_be(2));
+
+		let alice_basic = EVM::account_basic(&alice());
+		let contract_basic = EVM::account_basic(&contract_address);
+
+		let TraceOutcome::Prestate(PrestateTrace::Diff { pre, post }) = trace(true, 3) else {
+			panic!("unexpected trace outcome");
+		};
+		assert_eq!(
+			pre.get(&contract_address),
+			Some(&AccountState {
+				balance: Some(contract_basic.balance),
+				nonce: Some(contract_basic.nonce.as_u64()),
+				code_hash: Some(code_hash),
+				storage: [(slot, H256::from_low_u64_be(2))].into(),
+			})
+		);
+		assert_eq!(
+			post.get(&contract_address),
+			Some(&AccountState {
+				balance: None,
+				nonce: None,
+				code_hash: None,
+				storage: [(slot, H256::from_low_u64_be(3))].into(),
+			})
+		);
+		assert_eq!(
+			post.get(&alice()).and_then(|state| state.nonce),
+			Some(alice_basic.nonce.as_u64() + 1)
+		);
+	});
+}
+
+#[test]
+fn create_access_list_works() {
+	// pragma solidity ^0.8.0;
+	//
+	// contract Test {
+	//     mapping(uint256 => uint256) private data;
+	//
+	//     constructor() public payable {}
+	//
+	//     function setValue(uint256 key, uint256 value) public {
+	//         data[key] = value;
+	//     }
+	// }
+	let contract = from_hex(
+		"0x6080604052610105806100136000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c80637b8d56e314602d575b600080fd5b60436004803603810190603f91906096565b6045565b005b80600080848152602001908152602001600020819055505050565b600080fd5b6000819050919050565b6076816065565b8114608057600080fd5b50565b600081359050609081606f565b92915050565b6000806040838503121560aa5760a96060565b5b600060b6858286016083565b925050602060c5858286016083565b915050925092905056fea26469706673582212201cbfb5695481e8cf4c7a1206d22d0a707cb85907a10b47038ac14af0c386344464736f6c63430008120033"
+	).unwrap();
+
+	use crate::runner::{stack::Runner, RunnerExtended};
+
+	new_test_ext().execute_with(|| {
+		let contract_address = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap()
+		.value;
+
+		// setValue(1, 2)
+		let mut input: Vec<u8> = from_hex("0x7b8d56e3").unwrap();
+		input.append(&mut H256::from_low_u64_be(1).as_bytes().to_vec());
+		input.append(&mut H256::from_low_u64_be(2).as_bytes().to_vec());
+
+		// data[1]
+		let slot = H256::from(sp_io::hashing::keccak_256(
+			&[H256::from_low_u64_be(1).as_bytes(), H256::zero().as_bytes()].concat(),
+		));
+
+		let alice_basic = EVM::account_basic(&alice());
+
+		let info = Runner::<Runtime>::rpc_create_access_list(
+			alice(),
+			Some(contract_address),
+			input.clone(),
+			0,
+			1000000,
+			1000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+		assert_eq!(
+			info.access_list,
+			vec![AccessListItem {
+				address: contract_address,
+				storage_keys: vec![slot],
+			}]
+		);
+		// the target is already warm, listing it costs more than warming the slot saves
+		assert_eq!(info.gas_saved, U256::zero());
+
+		// changes are discarded
+		assert_eq!(EVM::account_basic(&alice()), alice_basic);
+		assert_eq!(EVM::account_storages(contract_address, slot), H256::zero());
+
+		let call_info = Runner::<Runtime>::rpc_call(
+			alice(),
+			alice(),
+			contract_address,
+			input.clone(),
+			0,
+			1000000,
+			1000,
+			vec![(contract_address, vec![slot])],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(info.used_gas, call_info.used_gas);
+	});
+}
+
+#[test]
+fn with_state_overrides_works() {
+	// pragma solidity ^0.8.0;
+	//
+	// contract Test {
+	//     mapping(uint256 => uint256) private data;
+	//
+	//     constructor() public payable {}
+	//
+	//     function setValue(uint256 key, uint256 value) public {
+	//         data[key] = value;
+	//     }
+	// }
+	let contract = from_hex(
+		"0x6080604052610105806100136000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c80637b8d56e314602d575b600080fd5b60436004803603810190603f91906096565b6045565b005b80600080848152602001908152602001600020819055505050565b600080fd5b6000819050919050565b6076816065565b8114608057600080fd5b50565b600081359050609081606f565b92915050565b6000806040838503121560aa5760a96060565b5b600060b6858286016083565b925050602060c5858286016083565b915050925092905056fea26469706673582212201cbfb5695481e8cf4c7a1206d22d0a707cb85907a10b47038ac14af0c386344464736f6c63430008120033"
+	).unwrap();
+
+	use crate::runner::{stack::Runner, RunnerExtended};
+
+	new_test_ext().execute_with(|| {
+		let contract_address = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap()
+		.value;
+		let code = EVM::code_at_address(&contract_address).into_inner();
+		let fake_contract = H160::repeat_byte(0x11);
+
+		// setValue(1, 2)
+		let mut input: Vec<u8> = from_hex("0x7b8d56e3").unwrap();
+		input.append(&mut H256::from_low_u64_be(1).as_bytes().to_vec());
+		input.append(&mut H256::from_low_u64_be(2).as_bytes().to_vec());
+
+		// data[1]
+		let slot = H256::from(sp_io::hashing::keccak_256(
+			&[H256::from_low_u64_be(1).as_bytes(), H256::zero().as_bytes()].concat(),
+		));
+		let other_slot = H256::from_low_u64_be(10);
+
+		let bob_basic = EVM::account_basic(&bob());
+		let overridden_balance = 1_000_000_000_000_000_000u128;
+
+		assert_ok!(EVM::with_state_overrides(
+			vec![
+				(
+					bob(),
+					StateOverride {
+						balance: Some(overridden_balance),
+						nonce: Some(5),
+						..Default::default()
+					}
+				),
+				(
+					fake_contract,
+					StateOverride {
+						code: Some(code.clone()),
+						state: Some([(other_slot, H256::from_low_u64_be(3))].into()),
+						..Default::default()
+					}
+				),
+			],
+			|| {
+				assert_eq!(eth_balance(bob()), U256::from(overridden_balance));
+				assert_eq!(EVM::account_basic(&bob()).nonce, U256::from(5));
+				assert_eq!(EVM::code_at_address(&fake_contract).into_inner(), code);
+				assert_eq!(
+					EVM::account_storages(fake_contract, other_slot),
+					H256::from_low_u64_be(3)
+				);
+
+				let info = Runner::<Runtime>::rpc_call(
+					alice(),
+					alice(),
+					fake_contract,
+					input.clone(),
+					0,
+					1000000,
+					1000,
+					vec![],
+					<Runtime as Config>::config(),
+				)?;
+				assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+				assert_eq!(EVM::account_storages(fake_contract, slot), H256::from_low_u64_be(2));
+				Ok(())
+			}
+		));
+
+		// changes are discarded
+		assert_eq!(EVM::account_basic(&bob()), bob_basic);
+		assert!(!EVM::is_contract(&fake_contract));
+		assert_eq!(EVM::account_storages(fake_contract, slot), H256::zero());
+		assert_eq!(EVM::account_storages(fake_contract, other_slot), H256::zero());
+
+		assert_ok!(<Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_address,
+			input,
+			0,
+			1000000,
+			1000,
+			vec![],
+			<Runtime as Config>::config(),
+		));
+		assert_eq!(EVM::account_storages(contract_address, slot), H256::from_low_u64_be(2));
+		let storage_size = ContractStorageSizes::<Runtime>::get(contract_address);
+
+		// full storage replacement
+		let another_slot = H256::from_low_u64_be(11);
+		assert_ok!(EVM::with_state_overrides(
+			vec![(
+				contract_address,
+				StateOverride {
+					state: Some(
+						[
+							(other_slot, H256::from_low_u64_be(3)),
+							(another_slot, H256::from_low_u64_be(4))
+						]
+						.into()
+					),
+					..Default::default()
+				}
+			)],
+			|| {
+				assert_eq!(EVM::account_storages(contract_address, slot), H256::zero());
+				assert_eq!(
+					EVM::account_storages(contract_address, other_slot),
+					H256::from_low_u64_be(3)
+				);
+				// one slot removed and two slots added
+				assert_eq!(
+					ContractStorageSizes::<Runtime>::get(contract_address),
+					storage_size + STORAGE_SIZE
+				);
+				Ok(())
+			}
+		));
+		assert_eq!(ContractStorageSizes::<Runtime>::get(contract_address), storage_size);
+
+		// partial storage update
+		assert_ok!(EVM::with_state_overrides(
+			vec![(
+				contract_address,
+				StateOverride {
+					state_diff: Some([(slot, H256::zero()), (other_slot, H256::from_low_u64_be(3))].into()),
+					..Default::default()
+				}
+			)],
+			|| {
+				// one slot removed and one slot added
+				assert_eq!(ContractStorageSizes::<Runtime>::get(contract_address), storage_size);
+				Ok(())
+			}
+		));
+
+		assert_noop!(
+			EVM::with_state_overrides(
+				vec![(
+					contract_address,
+					StateOverride {
+						state: Some(Default::default()),
+						state_diff: Some(Default::default()),
+						..Default::default()
+					}
+				)],
+				|| Ok(())
+			),
+			Error::<Runtime>::InvalidStateOverride
+		);
+	});
+}
+
+#[test]
+fn next_base_fee_per_gas_works() {
+	let base_fee = U256::from(1_000_000_000u64);
+	let min_base_fee = U256::from(100u64);
+
+	// at the target
+	assert_eq!(next_base_fee_per_gas(base_fee, 1_000, 1_000, min_base_fee), base_fee);
+	// full block, increase 1/8
+	assert_eq!(
+		next_base_fee_per_gas(base_fee, 2_000, 1_000, min_base_fee),
+		U256::from(1_125_000_000u64)
+	);
+	// empty block, decrease 1/8
+	assert_eq!(
+		next_base_fee_per_gas(base_fee, 0, 1_000, min_base_fee),
+		U256::from(875_000_000u64)
+	);
+	// increase at least 1
+	assert_eq!(
+		next_base_fee_per_gas(U256::from(100u64), 1_001, 1_000, min_base_fee),
+		U256::from(101u64)
+	);
+	// never below the minimum
+	assert_eq!(
+		next_base_fee_per_gas(U256::from(110u64), 0, 1_000, min_base_fee),
+		min_base_fee
+	);
+}
+
+#[test]
+fn base_fee_and_fee_history_works() {
+	new_test_ext().execute_with(|| {
+		let min_base_fee = U256::from(20_000_000u64);
+		assert_eq!(EVM::base_fee_per_gas(), min_base_fee);
+		assert_eq!(
+			EVM::fee_history(10),
+			FeeHistory {
+				oldest_block: 1,
+				base_fee_per_gas: vec![min_base_fee],
+				gas_used: vec![],
+				gas_target: 15_000_000,
+			}
+		);
+
+		// full block
+		EVM::record_block_gas_used(20_000_000);
+		EVM::record_block_gas_used(10_000_000);
+		assert_eq!(EVM::block_gas_used(), 30_000_000);
+		EVM::on_finalize(1);
+		assert_eq!(EVM::block_gas_used(), 0);
+		assert_eq!(EVM::block_fees(1), Some((min_base_fee, 30_000_000)));
+		assert_eq!(EVM::base_fee_per_gas(), U256::from(22_500_000u64));
+
+		System::set_block_number(2);
+		EVM::record_block_gas_used(15_000_000);
+		// the current block is not finalized
+		assert_eq!(
+			EVM::fee_history(10),
+			FeeHistory {
+				oldest_block: 1,
+				base_fee_per_gas: vec![min_base_fee, U256::from(22_500_000u64)],
+				gas_used: vec![30_000_000],
+				gas_target: 15_000_000,
+			}
+		);
+		EVM::on_finalize(2);
+
+		// empty block
+		System::set_block_number(3);
+		EVM::on_finalize(3);
+		// decrease to 19_687_500, but not below the minimum
+		assert_eq!(EVM::base_fee_per_gas(), min_base_fee);
+		assert_eq!(
+			EVM::fee_history(2),
+			FeeHistory {
+				oldest_block: 2,
+				base_fee_per_gas: vec![U256::from(22_500_000u64), U256::from(22_500_000u64), min_base_fee],
+				gas_used: vec![15_000_000, 0],
+				gas_target: 15_000_000,
+			}
+		);
+
+		// the history is pruned
+		let n = u64::from(FEE_HISTORY_LENGTH) + 1;
+		System::set_block_number(n);
+		EVM::on_finalize(n);
+		assert_eq!(EVM::block_fees(1), None);
+		assert!(EVM::block_fees(2).is_some());
+	});
+}
+
+#[test]
+fn base_fee_is_charged_and_exposed() {
+	// BASEFEE PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+	let contract = from_hex("0x684860005260206000f360005260096017f3").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let contract_address = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap()
+		.value;
+
+		let min_base_fee = U256::from(20_000_000u64);
+		let base_fee = U256::from(50_000_000u64);
+		BaseFeePerGas::<Runtime>::put(base_fee);
+
+		let alice_balance = eth_balance(alice());
+		let info = <Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_address,
+			vec![],
+			0,
+			1000000,
+			0,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from_big_endian(&info.value), base_fee);
+
+		// the calls made by the runtime through the runner don't pay the base fee
+		assert_eq!(eth_balance(alice()), alice_balance);
+
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		assert_ok!(EVM::call(
+			RuntimeOrigin::signed(alice_account_id),
+			contract_address,
+			vec![],
+			0,
+			1000000,
+			0,
+			vec![]
+		));
+		let used_gas = System::events()
+			.iter()
+			.find_map(|record| match &record.event {
+				RuntimeEvent::EVM(crate::Event::Executed { used_gas, .. }) => Some(*used_gas),
+				_ => None,
+			})
+			.unwrap();
+
+		// the base fee above the minimum is burned for the gas used by the signed transaction
+		assert_eq!(
+			eth_balance(alice()),
+			alice_balance - (base_fee - min_base_fee) * used_gas
+		);
+	});
+}
+
+#[test]
+fn account_entries_works() {
+	new_test_ext().execute_with(|| {
+		let slot = H256::from_low_u64_be(1);
+		AccountStorages::<Runtime>::insert(contract_a(), slot, H256::from_low_u64_be(2));
+
+		let code_hash = EVM::accounts(contract_a()).unwrap().contract_info.unwrap().code_hash;
+		let entries = EVM::account_entries(contract_a(), vec![slot, H256::from_low_u64_be(3)]);
+
+		assert_eq!(entries.address, contract_a());
+		assert_eq!(entries.account.key, Accounts::<Runtime>::hashed_key_for(contract_a()));
+		assert_eq!(entries.account.hashers, vec![StorageKeyHasher::Twox64Concat]);
+		assert_eq!(entries.account.value, EVM::accounts(contract_a()).map(|v| v.encode()));
+
+		let code_info = entries.code_info.unwrap();
+		assert_eq!(code_info.key, CodeInfos::<Runtime>::hashed_key_for(code_hash));
+		assert_eq!(code_info.value, EVM::code_infos(code_hash).map(|v| v.encode()));
+		let code = entries.code.unwrap();
+		assert_eq!(code.key, Codes::<Runtime>::hashed_key_for(code_hash));
+		assert_eq!(code.value, Some(EVM::codes(code_hash).encode()));
+
+		// the key is reproducible from the prefix and the hashers
+		let (index, entry) = &entries.storage[0];
+		assert_eq!(index, &slot);
+		assert_eq!(
+			entry.key,
+			[
+				entry.prefix.clone(),
+				sp_io::hashing::twox_64(&contract_a().encode()).to_vec(),
+				contract_a().encode(),
+				sp_io::hashing::blake2_128(&slot.encode()).to_vec(),
+				slot.encode(),
+			]
+			.concat()
+		);
+		assert_eq!(entry.value, Some(H256::from_low_u64_be(2).encode()));
+		assert_eq!(entries.storage[1].1.value, None);
+
+		// not a contract
+		let entries = EVM::account_entries(bob(), vec![]);
+		assert_eq!(entries.account.value, EVM::accounts(bob()).map(|v| v.encode()));
+		assert_eq!(entries.code_info, None);
+		assert_eq!(entries.code, None);
+		assert!(entries.storage.is_empty());
+	});
+}
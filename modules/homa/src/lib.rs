@@ -464,37 +464,7 @@ pub mod module {
 		pub fn claim_redemption(origin: OriginFor<T>, redeemer: T::AccountId) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
-			let mut available_staking: Balance = Zero::zero();
-			let current_era = Self::relay_chain_current_era();
-			for (expired_era_index, unbonded) in Unbondings::<T>::iter_prefix(&redeemer) {
-				if expired_era_index <= current_era {
-					available_staking = available_staking.saturating_add(unbonded);
-					Unbondings::<T>::remove(&redeemer, expired_era_index);
-				}
-			}
-
-			if !available_staking.is_zero() {
-				UnclaimedRedemption::<T>::try_mutate(|total| -> DispatchResult {
-					*total = total
-						.checked_sub(available_staking)
-						.ok_or(Error::<T>::InsufficientUnclaimedRedemption)?;
-					Ok(())
-				})?;
-				T::Currency::transfer(
-					T::StakingCurrencyId::get(),
-					&Self::account_id(),
-					&redeemer,
-					available_staking,
-					ExistenceRequirement::AllowDeath,
-				)?;
-
-				Self::deposit_event(Event::<T>::WithdrawRedemption {
-					redeemer,
-					redemption_amount: available_staking,
-				});
-			}
-
-			Ok(())
+			Self::do_claim_redemption(redeemer)
 		}
 
 		/// Sets the params of Homa.
@@ -829,6 +799,41 @@ pub mod module {
 			})
 		}
 
+		/// Withdraw the expired redemption of `redeemer` by unbond.
+		pub fn do_claim_redemption(redeemer: T::AccountId) -> DispatchResult {
+			let mut available_staking: Balance = Zero::zero();
+			let current_era = Self::relay_chain_current_era();
+			for (expired_era_index, unbonded) in Unbondings::<T>::iter_prefix(&redeemer) {
+				if expired_era_index <= current_era {
+					available_staking = available_staking.saturating_add(unbonded);
+					Unbondings::<T>::remove(&redeemer, expired_era_index);
+				}
+			}
+
+			if !available_staking.is_zero() {
+				UnclaimedRedemption::<T>::try_mutate(|total| -> DispatchResult {
+					*total = total
+						.checked_sub(available_staking)
+						.ok_or(Error::<T>::InsufficientUnclaimedRedemption)?;
+					Ok(())
+				})?;
+				T::Currency::transfer(
+					T::StakingCurrencyId::get(),
+					&Self::account_id(),
+					&redeemer,
+					available_staking,
+					ExistenceRequirement::AllowDeath,
+				)?;
+
+				Self::deposit_event(Event::<T>::WithdrawRedemption {
+					redeemer,
+					redemption_amount: available_staking,
+				});
+			}
+
+			Ok(())
+		}
+
 		/// Get the soft cap of total staking currency of Homa.
 		/// Soft cap = ActiveSubAccountsIndexList.len() * SoftBondedCapPerSubAccount
 		pub fn get_staking_currency_soft_cap() -> Balance {
@@ -155,6 +155,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type Erc20TransferLog = ();
 }
 
 impl BlockNumberProvider for MockRelayBlockNumberProvider {
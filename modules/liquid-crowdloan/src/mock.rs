@@ -112,6 +112,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type Erc20TransferLog = ();
 }
 
 parameter_types! {
@@ -28,7 +28,7 @@ use frame_support::{
 		tokens::nonfungibles::{Inspect, Mutate, Transfer},
 		Currency,
 		ExistenceRequirement::{AllowDeath, KeepAlive},
-		NamedReservableCurrency,
+		GetStorageVersion, NamedReservableCurrency, OnRuntimeUpgrade,
 	},
 	PalletId,
 };
@@ -190,9 +190,58 @@ pub mod module {
 			owner: T::AccountId,
 			class_id: ClassIdOf<T>,
 		},
+		/// The approved account of a NFT token is changed.
+		Approved {
+			owner: T::AccountId,
+			approved: Option<T::AccountId>,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// The operator of all the NFT tokens of an owner in a class is enabled or disabled.
+		ApprovedForAll {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			class_id: ClassIdOf<T>,
+			approved: bool,
+		},
 	}
 
+	/// The account approved to transfer a NFT token, cleared when the token is transferred or
+	/// burned.
+	///
+	/// TokenApprovals: double_map ClassId, TokenId => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn token_approvals)]
+	pub type TokenApprovals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, TokenIdOf<T>, T::AccountId, OptionQuery>;
+
+	/// The operators approved to transfer all the NFT tokens of an owner in a class.
+	///
+	/// OperatorApprovals: double_map (ClassId, owner: AccountId), operator: AccountId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn operator_approvals)]
+	pub type OperatorApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, T::AccountId),
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// The number of the NFT tokens of an owner in a class.
+	///
+	/// ClassBalances: double_map owner: AccountId, ClassId => NFTBalance
+	#[pallet::storage]
+	#[pallet::getter(fn class_balances)]
+	pub type ClassBalances<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, ClassIdOf<T>, NFTBalance, ValueQuery>;
+
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -211,43 +260,7 @@ pub mod module {
 			attributes: Attributes,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let next_id = orml_nft::Pallet::<T>::next_class_id();
-			let owner: T::AccountId = T::PalletId::get().into_sub_account_truncating(next_id);
-			let class_deposit = T::CreateClassDeposit::get();
-
-			let data_deposit = Self::data_deposit(&metadata, &attributes)?;
-			let proxy_deposit = <pallet_proxy::Pallet<T>>::deposit(1u32);
-			let deposit = class_deposit.saturating_add(data_deposit);
-			let total_deposit = proxy_deposit.saturating_add(deposit);
-
-			// https://github.com/paritytech/substrate/blob/569aae5341ea0c1d10426fa1ec13a36c0b64393b/frame/balances/src/lib.rs#L965
-			// Now the pallet-balances judges whether does provider is based on the `free balance` instead of
-			// `total balance`. When there's no other providers, error will throw in following reserve
-			// operation, which want to make `free balance` is zero and `reserved balance` is not zero.
-			// If receiver account has not enough ed, transfer an additional ED to make sure the subsequent
-			// reserve operation.
-			let total_transfer_amount =
-				total_deposit.saturating_add(<T as module::Config>::Currency::minimum_balance());
-
-			// ensure enough token for proxy deposit + class deposit + data deposit + ed
-			<T as module::Config>::Currency::transfer(&who, &owner, total_transfer_amount, KeepAlive)?;
-
-			<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &owner, deposit)?;
-
-			// owner add proxy delegate to origin
-			<pallet_proxy::Pallet<T>>::add_proxy_delegate(&owner, who, Default::default(), Zero::zero())?;
-
-			let data = ClassData {
-				deposit,
-				properties,
-				attributes,
-			};
-			orml_nft::Pallet::<T>::create_class(&owner, metadata, data)?;
-
-			Self::deposit_event(Event::CreatedClass {
-				owner,
-				class_id: next_id,
-			});
+			Self::do_create_class(&who, metadata, properties, attributes)?;
 			Ok(().into())
 		}
 
@@ -269,7 +282,7 @@ pub mod module {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(to)?;
-			Self::do_mint(&who, &to, class_id, metadata, attributes, quantity)?;
+			Self::do_mint(&who, &who, &to, class_id, metadata, attributes, quantity)?;
 			Ok(())
 		}
 
@@ -328,30 +341,7 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let dest = T::Lookup::lookup(dest)?;
-			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
-			ensure!(who == class_info.owner, Error::<T>::NoPermission);
-			ensure!(
-				class_info.total_issuance == Zero::zero(),
-				Error::<T>::CannotDestroyClass
-			);
-
-			let data = class_info.data;
-
-			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, data.deposit);
-
-			orml_nft::Pallet::<T>::destroy_class(&who, class_id)?;
-
-			// this should unresere proxy deposit
-			pallet_proxy::Pallet::<T>::remove_proxy_delegate(&who, dest.clone(), Default::default(), Zero::zero())?;
-
-			<T as module::Config>::Currency::transfer(
-				&who,
-				&dest,
-				<T as module::Config>::Currency::free_balance(&who),
-				AllowDeath,
-			)?;
-
-			Self::deposit_event(Event::DestroyedClass { owner: who, class_id });
+			Self::do_destroy_class(&who, class_id, &dest)?;
 			Ok(().into())
 		}
 
@@ -368,25 +358,127 @@ pub mod module {
 			properties: Properties,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			orml_nft::Classes::<T>::try_mutate(class_id, |class_info| {
-				let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
-				ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			Self::do_update_class_properties(&who, class_id, properties)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	#[require_transactional]
+	pub fn do_create_class(
+		who: &T::AccountId,
+		metadata: CID,
+		properties: Properties,
+		attributes: Attributes,
+	) -> Result<ClassIdOf<T>, DispatchError> {
+		let next_id = orml_nft::Pallet::<T>::next_class_id();
+		let owner: T::AccountId = T::PalletId::get().into_sub_account_truncating(next_id);
+		let class_deposit = T::CreateClassDeposit::get();
+
+		let data_deposit = Self::data_deposit(&metadata, &attributes)?;
+		let proxy_deposit = <pallet_proxy::Pallet<T>>::deposit(1u32);
+		let deposit = class_deposit.saturating_add(data_deposit);
+		let total_deposit = proxy_deposit.saturating_add(deposit);
+
+		// https://github.com/paritytech/substrate/blob/569aae5341ea0c1d10426fa1ec13a36c0b64393b/frame/balances/src/lib.rs#L965
+		// Now the pallet-balances judges whether does provider is based on the `free balance` instead of
+		// `total balance`. When there's no other providers, error will throw in following reserve
+		// operation, which want to make `free balance` is zero and `reserved balance` is not zero.
+		// If receiver account has not enough ed, transfer an additional ED to make sure the subsequent
+		// reserve operation.
+		let total_transfer_amount = total_deposit.saturating_add(<T as module::Config>::Currency::minimum_balance());
+
+		// ensure enough token for proxy deposit + class deposit + data deposit + ed
+		<T as module::Config>::Currency::transfer(who, &owner, total_transfer_amount, KeepAlive)?;
+
+		<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &owner, deposit)?;
+
+		// owner add proxy delegate to origin
+		<pallet_proxy::Pallet<T>>::add_proxy_delegate(&owner, who.clone(), Default::default(), Zero::zero())?;
+
+		let data = ClassData {
+			deposit,
+			properties,
+			attributes,
+		};
+		orml_nft::Pallet::<T>::create_class(&owner, metadata, data)?;
+
+		Self::deposit_event(Event::CreatedClass {
+			owner,
+			class_id: next_id,
+		});
+		Ok(next_id)
+	}
+
+	#[require_transactional]
+	pub fn do_destroy_class(who: &T::AccountId, class_id: ClassIdOf<T>, dest: &T::AccountId) -> DispatchResult {
+		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(*who == class_info.owner, Error::<T>::NoPermission);
+		ensure!(
+			class_info.total_issuance == Zero::zero(),
+			Error::<T>::CannotDestroyClass
+		);
+
+		let data = class_info.data;
+
+		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, who, data.deposit);
+
+		orml_nft::Pallet::<T>::destroy_class(who, class_id)?;
+
+		// this should unresere proxy deposit
+		pallet_proxy::Pallet::<T>::remove_proxy_delegate(who, dest.clone(), Default::default(), Zero::zero())?;
+
+		<T as module::Config>::Currency::transfer(
+			who,
+			dest,
+			<T as module::Config>::Currency::free_balance(who),
+			AllowDeath,
+		)?;
+
+		Self::deposit_event(Event::DestroyedClass {
+			owner: who.clone(),
+			class_id,
+		});
+		Ok(())
+	}
 
-				let data = &mut class_info.data;
-				ensure!(
-					data.properties.0.contains(ClassProperty::ClassPropertiesMutable),
-					Error::<T>::Immutable
-				);
+	pub fn do_update_class_properties(
+		who: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		properties: Properties,
+	) -> DispatchResult {
+		orml_nft::Classes::<T>::try_mutate(class_id, |class_info| {
+			let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(*who == class_info.owner, Error::<T>::NoPermission);
 
-				data.properties = properties;
+			let data = &mut class_info.data;
+			ensure!(
+				data.properties.0.contains(ClassProperty::ClassPropertiesMutable),
+				Error::<T>::Immutable
+			);
+
+			data.properties = properties;
 
-				Ok(())
-			})
+			Ok(())
+		})
+	}
+
+	/// Ensure `who` is the owner of the class or the proxy delegate added to the owner when the
+	/// class was created, returns the owner of the class.
+	pub fn ensure_class_owner_or_delegate(
+		who: &T::AccountId,
+		class_id: ClassIdOf<T>,
+	) -> Result<T::AccountId, DispatchError> {
+		let owner = orml_nft::Pallet::<T>::classes(class_id)
+			.ok_or(Error::<T>::ClassIdNotFound)?
+			.owner;
+		if *who != owner {
+			pallet_proxy::Pallet::<T>::find_proxy(&owner, who, Some(Default::default()))
+				.map_err(|_| Error::<T>::NoPermission)?;
 		}
+		Ok(owner)
 	}
-}
 
-impl<T: Config> Pallet<T> {
 	#[require_transactional]
 	pub fn do_transfer(from: &T::AccountId, to: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
 		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
@@ -399,6 +491,11 @@ impl<T: Config> Pallet<T> {
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
 
 		orml_nft::Pallet::<T>::transfer(from, to, token)?;
+		TokenApprovals::<T>::remove(token.0, token.1);
+		if from != to {
+			Self::mutate_class_balance(from, token.0, |balance| balance.saturating_sub(1));
+			Self::mutate_class_balance(to, token.0, |balance| balance.saturating_add(1));
+		}
 
 		let reserve_balance = token_info.data.deposit;
 
@@ -428,9 +525,11 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Mint NFT tokens, `who` must be the owner of the class and `payer` pays the deposits.
 	#[require_transactional]
-	fn do_mint(
+	pub fn do_mint(
 		who: &T::AccountId,
+		payer: &T::AccountId,
 		to: &T::AccountId,
 		class_id: ClassIdOf<T>,
 		metadata: CID,
@@ -465,7 +564,7 @@ impl<T: Config> Pallet<T> {
 
 		// `repatriate_reserved` will check `to` account exist and may return
 		// `DeadAccount`.
-		<T as module::Config>::Currency::transfer(who, to, total_transfer_amount, KeepAlive)?;
+		<T as module::Config>::Currency::transfer(payer, to, total_transfer_amount, KeepAlive)?;
 		<T as module::Config>::Currency::reserve_named(&RESERVE_ID, to, total_deposit)?;
 
 		let mut token_ids = Vec::with_capacity(quantity as usize);
@@ -478,6 +577,7 @@ impl<T: Config> Pallet<T> {
 				data.clone(),
 			)?);
 		}
+		Self::mutate_class_balance(to, class_id, |balance| balance.saturating_add(quantity.into()));
 
 		Self::deposit_event(Event::MintedToken {
 			from: who.clone(),
@@ -488,7 +588,7 @@ impl<T: Config> Pallet<T> {
 		Ok(token_ids)
 	}
 
-	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
+	pub fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
 		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
 		ensure!(
@@ -500,6 +600,8 @@ impl<T: Config> Pallet<T> {
 		ensure!(who == token_info.owner, Error::<T>::NoPermission);
 
 		orml_nft::Pallet::<T>::burn(&who, token)?;
+		TokenApprovals::<T>::remove(token.0, token.1);
+		Self::mutate_class_balance(&who, token.0, |balance| balance.saturating_sub(1));
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, token_info.data.deposit);
 
@@ -522,6 +624,79 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Approve `approved` to transfer the token, `None` clears the approval. `who` must be the
+	/// owner of the token or an operator of the owner.
+	pub fn approve(
+		who: &T::AccountId,
+		approved: Option<T::AccountId>,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		let owner = orml_nft::Pallet::<T>::tokens(token.0, token.1)
+			.ok_or(Error::<T>::TokenIdNotFound)?
+			.owner;
+		ensure!(
+			*who == owner || Self::operator_approvals((token.0, owner.clone()), who),
+			Error::<T>::NoPermission
+		);
+
+		TokenApprovals::<T>::set(token.0, token.1, approved.clone());
+
+		Self::deposit_event(Event::Approved {
+			owner,
+			approved,
+			class_id: token.0,
+			token_id: token.1,
+		});
+		Ok(())
+	}
+
+	/// Enable or disable `operator` to transfer all the tokens of `owner` in the class.
+	pub fn set_approval_for_all(
+		owner: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		operator: &T::AccountId,
+		approved: bool,
+	) -> DispatchResult {
+		ensure!(
+			orml_nft::Pallet::<T>::classes(class_id).is_some(),
+			Error::<T>::ClassIdNotFound
+		);
+
+		if approved {
+			OperatorApprovals::<T>::insert((class_id, owner.clone()), operator, true);
+		} else {
+			OperatorApprovals::<T>::remove((class_id, owner.clone()), operator);
+		}
+
+		Self::deposit_event(Event::ApprovedForAll {
+			owner: owner.clone(),
+			operator: operator.clone(),
+			class_id,
+			approved,
+		});
+		Ok(())
+	}
+
+	/// Whether `spender` is the owner, the approved account or an operator of the token.
+	pub fn is_approved_or_owner(spender: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		orml_nft::Pallet::<T>::tokens(token.0, token.1).is_some_and(|token_info| {
+			*spender == token_info.owner
+				|| Self::token_approvals(token.0, token.1).as_ref() == Some(spender)
+				|| Self::operator_approvals((token.0, token_info.owner), spender)
+		})
+	}
+
+	/// The number of tokens of `who` in the class.
+	pub fn class_balance(who: &T::AccountId, class_id: ClassIdOf<T>) -> NFTBalance {
+		Self::class_balances(who, class_id)
+	}
+
+	fn mutate_class_balance(who: &T::AccountId, class_id: ClassIdOf<T>, f: impl FnOnce(NFTBalance) -> NFTBalance) {
+		ClassBalances::<T>::mutate_exists(who, class_id, |balance| {
+			*balance = Some(f(balance.unwrap_or_default())).filter(|balance| !balance.is_zero());
+		});
+	}
+
 	fn data_deposit(metadata: &[u8], attributes: &Attributes) -> Result<BalanceOf<T>, DispatchError> {
 		// Addition can't overflow because we will be out of memory before that
 		let attributes_len = attributes.iter().fold(0, |acc, (k, v)| {
@@ -579,7 +754,15 @@ impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
 
 		let class_owner =
 			<Self as Inspect<T::AccountId>>::collection_owner(class).ok_or(Error::<T>::ClassIdNotFound)?;
-		Self::do_mint(&class_owner, who, *class, Default::default(), Default::default(), 1u32)?;
+		Self::do_mint(
+			&class_owner,
+			&class_owner,
+			who,
+			*class,
+			Default::default(),
+			Default::default(),
+			1u32,
+		)?;
 		Ok(())
 	}
 
@@ -601,3 +784,22 @@ impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
 		Self::do_transfer(&owner, destination, (*class, *instance))
 	}
 }
+
+/// Initialize `ClassBalances` from the tokens of the owners.
+pub struct InitializeClassBalances<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for InitializeClassBalances<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut count: u64 = 0;
+		for (owner, class_id, _) in orml_nft::TokensByOwner::<T>::iter_keys() {
+			ClassBalances::<T>::mutate(&owner, class_id, |balance| *balance = balance.saturating_add(1));
+			count = count.saturating_add(1);
+		}
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(count.saturating_mul(2).saturating_add(1), count.saturating_add(1))
+	}
+}
@@ -166,6 +166,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<One, AccountId>;
 	type OnDust = ();
+	type Erc20TransferLog = ();
 }
 
 parameter_types! {
@@ -736,3 +736,189 @@ fn update_class_properties_should_work() {
 		);
 	});
 }
+
+#[test]
+fn approve_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			2 * (CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE) + Balances::minimum_balance()
+		));
+		assert_ok!(NFTModule::mint(
+			RuntimeOrigin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			2
+		));
+		assert_eq!(NFTModule::class_balance(&BOB, CLASS_ID), 2);
+		assert_eq!(NFTModule::class_balance(&BOB, CLASS_ID_NOT_EXIST), 0);
+
+		assert_noop!(
+			NFTModule::approve(&ALICE, Some(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::approve(&BOB, Some(ALICE), (CLASS_ID, 2)),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		assert_ok!(NFTModule::approve(&BOB, Some(ALICE), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::Approved {
+			owner: BOB,
+			approved: Some(ALICE),
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(NFTModule::token_approvals(CLASS_ID, TOKEN_ID), Some(ALICE));
+		assert!(NFTModule::is_approved_or_owner(&ALICE, (CLASS_ID, TOKEN_ID)));
+		assert!(!NFTModule::is_approved_or_owner(&ALICE, (CLASS_ID, 1)));
+		assert!(NFTModule::is_approved_or_owner(&BOB, (CLASS_ID, 1)));
+
+		// transfer clears the approval
+		assert_ok!(NFTModule::transfer(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			(CLASS_ID, TOKEN_ID)
+		));
+		assert_eq!(NFTModule::token_approvals(CLASS_ID, TOKEN_ID), None);
+
+		assert_noop!(
+			NFTModule::set_approval_for_all(&BOB, CLASS_ID_NOT_EXIST, &ALICE, true),
+			Error::<Runtime>::ClassIdNotFound
+		);
+		assert_ok!(NFTModule::set_approval_for_all(&BOB, CLASS_ID, &ALICE, true));
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::ApprovedForAll {
+			owner: BOB,
+			operator: ALICE,
+			class_id: CLASS_ID,
+			approved: true,
+		}));
+		assert!(NFTModule::operator_approvals((CLASS_ID, BOB), ALICE));
+		assert!(NFTModule::is_approved_or_owner(&ALICE, (CLASS_ID, 1)));
+
+		// operator can approve
+		assert_ok!(NFTModule::approve(&ALICE, Some(ALICE), (CLASS_ID, 1)));
+		assert_ok!(NFTModule::approve(&ALICE, None, (CLASS_ID, 1)));
+		assert_eq!(NFTModule::token_approvals(CLASS_ID, 1), None);
+
+		assert_ok!(NFTModule::set_approval_for_all(&BOB, CLASS_ID, &ALICE, false));
+		assert!(!NFTModule::operator_approvals((CLASS_ID, BOB), ALICE));
+		assert!(!NFTModule::is_approved_or_owner(&ALICE, (CLASS_ID, 1)));
+	});
+}
+
+#[test]
+fn class_delegate_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		let metadata_2 = vec![2, 3];
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			test_attr(1),
+		));
+
+		assert_eq!(
+			NFTModule::ensure_class_owner_or_delegate(&ALICE, CLASS_ID),
+			Ok(class_id_account())
+		);
+		assert_eq!(
+			NFTModule::ensure_class_owner_or_delegate(&class_id_account(), CLASS_ID),
+			Ok(class_id_account())
+		);
+		assert_noop!(
+			NFTModule::ensure_class_owner_or_delegate(&BOB, CLASS_ID),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::ensure_class_owner_or_delegate(&ALICE, CLASS_ID_NOT_EXIST),
+			Error::<Runtime>::ClassIdNotFound
+		);
+
+		// the deposits are paid by the payer
+		let class_owner_free_balance = free_balance(&class_id_account());
+		let alice_free_balance = free_balance(&ALICE);
+		let mint_token_deposit =
+			2 * (CREATE_TOKEN_DEPOSIT + ((metadata_2.len() as u128 + TEST_ATTR_LEN) * DATA_DEPOSIT_PER_BYTE));
+		assert_eq!(
+			frame_support::storage::with_storage_layer(|| NFTModule::do_mint(
+				&class_id_account(),
+				&ALICE,
+				&BOB,
+				CLASS_ID,
+				metadata_2.clone(),
+				test_attr(2),
+				2
+			)),
+			Ok(vec![0, 1])
+		);
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::MintedToken {
+			from: class_id_account(),
+			to: BOB,
+			class_id: CLASS_ID,
+			quantity: 2,
+		}));
+		assert_eq!(free_balance(&class_id_account()), class_owner_free_balance);
+		assert_eq!(
+			free_balance(&ALICE),
+			alice_free_balance - mint_token_deposit - Balances::minimum_balance()
+		);
+		assert_eq!(reserved_balance(&BOB), mint_token_deposit);
+	});
+}
+
+#[test]
+fn class_balances_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			2 * (CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE) + Balances::minimum_balance()
+		));
+		assert_ok!(NFTModule::mint(
+			RuntimeOrigin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			2
+		));
+		assert_eq!(NFTModule::class_balances(BOB, CLASS_ID), 2);
+
+		assert_ok!(NFTModule::transfer(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			(CLASS_ID, TOKEN_ID)
+		));
+		assert_eq!(NFTModule::class_balances(BOB, CLASS_ID), 1);
+		assert_eq!(NFTModule::class_balances(ALICE, CLASS_ID), 1);
+
+		assert_ok!(NFTModule::burn(RuntimeOrigin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::class_balances(ALICE, CLASS_ID), 0);
+		assert!(!ClassBalances::<Runtime>::contains_key(ALICE, CLASS_ID));
+
+		// the migration counts the tokens of the owners
+		let _ = ClassBalances::<Runtime>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<NFTModule>();
+		InitializeClassBalances::<Runtime>::on_runtime_upgrade();
+		assert_eq!(NFTModule::class_balances(BOB, CLASS_ID), 1);
+		assert_eq!(NFTModule::class_balances(ALICE, CLASS_ID), 0);
+		assert_eq!(NFTModule::on_chain_storage_version(), 1);
+	});
+}
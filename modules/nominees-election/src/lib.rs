@@ -191,21 +191,7 @@ pub mod module {
 		#[pallet::weight(T::WeightInfo::bond())]
 		pub fn bond(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let change = <Self as BondingController>::bond(&who, amount)?;
-
-			if let Some(change) = change {
-				let old_nominations = Self::nominations(&who);
-
-				Self::update_votes(change.old, &old_nominations, change.new, &old_nominations);
-
-				T::OnBonded::handle(&(who.clone(), change.change))?;
-
-				Self::deposit_event(Event::Bond {
-					who,
-					amount: change.change,
-				});
-			}
+			Self::do_bond(&who, amount)?;
 			Ok(())
 		}
 
@@ -213,23 +199,7 @@ pub mod module {
 		#[pallet::weight(T::WeightInfo::bond())]
 		pub fn unbond(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let unbond_at = T::CurrentEra::get().saturating_add(T::BondingDuration::get());
-			let change = <Self as BondingController>::unbond(&who, amount, unbond_at)?;
-
-			if let Some(change) = change {
-				let old_nominations = Self::nominations(&who);
-
-				Self::update_votes(change.old, &old_nominations, change.new, &old_nominations);
-
-				T::OnUnbonded::handle(&(who.clone(), change.change))?;
-
-				Self::deposit_event(Event::Unbond {
-					who,
-					amount: change.change,
-				});
-			}
-
+			Self::do_unbond(&who, amount)?;
 			Ok(())
 		}
 
@@ -237,22 +207,7 @@ pub mod module {
 		#[pallet::weight(T::WeightInfo::rebond(T::MaxUnbondingChunks::get()))]
 		pub fn rebond(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let change = <Self as BondingController>::rebond(&who, amount)?;
-
-			if let Some(change) = change {
-				let old_nominations = Self::nominations(&who);
-
-				Self::update_votes(change.old, &old_nominations, change.new, &old_nominations);
-
-				T::OnBonded::handle(&(who.clone(), change.change))?;
-
-				Self::deposit_event(Event::Rebond {
-					who,
-					amount: change.change,
-				});
-			}
-
+			Self::do_rebond(&who, amount)?;
 			Ok(())
 		}
 
@@ -260,16 +215,7 @@ pub mod module {
 		#[pallet::weight(T::WeightInfo::withdraw_unbonded(T::MaxUnbondingChunks::get()))]
 		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let change = <Self as BondingController>::withdraw_unbonded(&who, T::CurrentEra::get())?;
-
-			if let Some(change) = change {
-				Self::deposit_event(Event::WithdrawUnbonded {
-					who,
-					amount: change.change,
-				});
-			}
-
+			Self::do_withdraw_unbonded(&who)?;
 			Ok(())
 		}
 
@@ -277,56 +223,14 @@ pub mod module {
 		#[pallet::weight(T::WeightInfo::nominate(targets.len() as u32))]
 		pub fn nominate(origin: OriginFor<T>, targets: Vec<T::NomineeId>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let ledger = Self::ledger(&who).ok_or(Error::<T, I>::NotBonded)?;
-
-			let bounded_targets: BoundedVec<<T as Config<I>>::NomineeId, <T as Config<I>>::MaxNominateesCount> = {
-				if targets.is_empty() {
-					Err(Error::<T, I>::InvalidTargetsLength)
-				} else {
-					targets.try_into().map_err(|_| Error::<T, I>::InvalidTargetsLength)
-				}
-			}?;
-
-			let bounded_targets = bounded_targets
-				.try_mutate(|targets| {
-					targets.sort();
-					targets.dedup();
-				})
-				.expect("This only reduce size of the vector; qed");
-
-			for validator in bounded_targets.iter() {
-				ensure!(T::NomineeFilter::contains(validator), Error::<T, I>::InvalidNominee);
-			}
-
-			let old_nominations = Self::nominations(&who);
-			let old_active = ledger.active();
-
-			Self::update_votes(old_active, &old_nominations, old_active, &bounded_targets);
-			Nominations::<T, I>::insert(&who, &bounded_targets);
-
-			Self::deposit_event(Event::Nominate {
-				who,
-				targets: bounded_targets.to_vec(),
-			});
-			Ok(())
+			Self::do_nominate(&who, targets)
 		}
 
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::chill(T::MaxNominateesCount::get()))]
 		pub fn chill(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let ledger = Self::ledger(&who).ok_or(Error::<T, I>::NotBonded)?;
-
-			let old_nominations = Self::nominations(&who);
-			let old_active = ledger.active();
-
-			Self::update_votes(old_active, &old_nominations, Zero::zero(), &[]);
-			Nominations::<T, I>::remove(&who);
-
-			Self::deposit_event(Event::Nominate { who, targets: vec![] });
-			Ok(())
+			Self::do_chill(&who)
 		}
 
 		#[pallet::call_index(6)]
@@ -358,6 +262,138 @@ pub mod module {
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Bond `amount` for `who`, returns the actual bonded amount.
+	pub fn do_bond(who: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let change = <Self as BondingController>::bond(who, amount)?;
+
+		if let Some(change) = change {
+			let old_nominations = Self::nominations(who);
+
+			Self::update_votes(change.old, &old_nominations, change.new, &old_nominations);
+
+			T::OnBonded::handle(&(who.clone(), change.change))?;
+
+			Self::deposit_event(Event::Bond {
+				who: who.clone(),
+				amount: change.change,
+			});
+			Ok(change.change)
+		} else {
+			Ok(Zero::zero())
+		}
+	}
+
+	/// Unbond `amount` for `who`, returns the actual unbonded amount.
+	pub fn do_unbond(who: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let unbond_at = T::CurrentEra::get().saturating_add(T::BondingDuration::get());
+		let change = <Self as BondingController>::unbond(who, amount, unbond_at)?;
+
+		if let Some(change) = change {
+			let old_nominations = Self::nominations(who);
+
+			Self::update_votes(change.old, &old_nominations, change.new, &old_nominations);
+
+			T::OnUnbonded::handle(&(who.clone(), change.change))?;
+
+			Self::deposit_event(Event::Unbond {
+				who: who.clone(),
+				amount: change.change,
+			});
+			Ok(change.change)
+		} else {
+			Ok(Zero::zero())
+		}
+	}
+
+	/// Rebond `amount` of the unbonding chunks for `who`, returns the actual rebonded amount.
+	pub fn do_rebond(who: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let change = <Self as BondingController>::rebond(who, amount)?;
+
+		if let Some(change) = change {
+			let old_nominations = Self::nominations(who);
+
+			Self::update_votes(change.old, &old_nominations, change.new, &old_nominations);
+
+			T::OnBonded::handle(&(who.clone(), change.change))?;
+
+			Self::deposit_event(Event::Rebond {
+				who: who.clone(),
+				amount: change.change,
+			});
+			Ok(change.change)
+		} else {
+			Ok(Zero::zero())
+		}
+	}
+
+	/// Withdraw the expired unbonding chunks for `who`, returns the withdrawn amount.
+	pub fn do_withdraw_unbonded(who: &T::AccountId) -> Result<Balance, DispatchError> {
+		let change = <Self as BondingController>::withdraw_unbonded(who, T::CurrentEra::get())?;
+
+		if let Some(change) = change {
+			Self::deposit_event(Event::WithdrawUnbonded {
+				who: who.clone(),
+				amount: change.change,
+			});
+			Ok(change.change)
+		} else {
+			Ok(Zero::zero())
+		}
+	}
+
+	/// Replace the nominations of `who` with `targets`.
+	pub fn do_nominate(who: &T::AccountId, targets: Vec<T::NomineeId>) -> DispatchResult {
+		let ledger = Self::ledger(who).ok_or(Error::<T, I>::NotBonded)?;
+
+		let bounded_targets: BoundedVec<<T as Config<I>>::NomineeId, <T as Config<I>>::MaxNominateesCount> = {
+			if targets.is_empty() {
+				Err(Error::<T, I>::InvalidTargetsLength)
+			} else {
+				targets.try_into().map_err(|_| Error::<T, I>::InvalidTargetsLength)
+			}
+		}?;
+
+		let bounded_targets = bounded_targets
+			.try_mutate(|targets| {
+				targets.sort();
+				targets.dedup();
+			})
+			.expect("This only reduce size of the vector; qed");
+
+		for validator in bounded_targets.iter() {
+			ensure!(T::NomineeFilter::contains(validator), Error::<T, I>::InvalidNominee);
+		}
+
+		let old_nominations = Self::nominations(who);
+		let old_active = ledger.active();
+
+		Self::update_votes(old_active, &old_nominations, old_active, &bounded_targets);
+		Nominations::<T, I>::insert(who, &bounded_targets);
+
+		Self::deposit_event(Event::Nominate {
+			who: who.clone(),
+			targets: bounded_targets.to_vec(),
+		});
+		Ok(())
+	}
+
+	/// Remove all the nominations of `who`.
+	pub fn do_chill(who: &T::AccountId) -> DispatchResult {
+		let ledger = Self::ledger(who).ok_or(Error::<T, I>::NotBonded)?;
+
+		let old_nominations = Self::nominations(who);
+		let old_active = ledger.active();
+
+		Self::update_votes(old_active, &old_nominations, Zero::zero(), &[]);
+		Nominations::<T, I>::remove(who);
+
+		Self::deposit_event(Event::Nominate {
+			who: who.clone(),
+			targets: vec![],
+		});
+		Ok(())
+	}
+
 	fn update_votes(
 		old_active: Balance,
 		old_nominations: &[T::NomineeId],
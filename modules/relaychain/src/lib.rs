@@ -35,6 +35,10 @@ use sp_std::{boxed::Box, marker::PhantomData, prelude::*};
 pub use cumulus_primitives_core::ParaId;
 use xcm::v4::{prelude::*, Weight as XcmWeight};
 
+/// The weight of the XCM instructions around the `Transact` of an encoded call, bought on top of
+/// the weight of the call.
+pub const TRANSACT_OVERHEAD_WEIGHT: XcmWeight = XcmWeight::from_parts(1_000_000_000, 65_536);
+
 /// The encoded index corresponds to Kusama's Runtime module configuration.
 /// https://github.com/paritytech/polkadot/blob/444e96ae34bcec8362f0f947a07bd912b32ca48f/runtime/kusama/src/lib.rs#L1379
 #[derive(Encode, Decode, RuntimeDebug)]
@@ -231,4 +235,73 @@ where
 		]
 		.concat())
 	}
+
+	fn finalize_transact_into_xcm_message(
+		call: Vec<u8>,
+		fee: Asset,
+		weight: XcmWeight,
+		beneficiary: Location,
+	) -> Xcm<()> {
+		Xcm(vec![
+			WithdrawAsset(fee.clone().into()),
+			BuyExecution {
+				fees: fee,
+				weight_limit: Limited(weight.saturating_add(TRANSACT_OVERHEAD_WEIGHT)),
+			},
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: weight,
+				call: call.into(),
+			},
+			RefundSurplus,
+			DepositAsset {
+				assets: AllCounted(1).into(),
+				beneficiary,
+			},
+		])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::parameter_types;
+
+	parameter_types! {
+		pub const ParachainId: ParaId = ParaId::new(2000);
+	}
+
+	type PolkadotCallBuilder = RelayChainCallBuilder<ParachainId, PolkadotRelayChainCall>;
+
+	#[test]
+	fn finalize_transact_into_xcm_message_works() {
+		let fee: Asset = (Location::here(), 1_000_000).into();
+		let beneficiary = Location::new(0, Parachain(2001));
+
+		assert_eq!(
+			PolkadotCallBuilder::finalize_transact_into_xcm_message(
+				vec![1, 2, 3],
+				fee.clone(),
+				XcmWeight::from_parts(100, 10),
+				beneficiary.clone(),
+			),
+			Xcm(vec![
+				WithdrawAsset(fee.clone().into()),
+				BuyExecution {
+					fees: fee,
+					weight_limit: Limited(XcmWeight::from_parts(1_000_000_100, 65_546)),
+				},
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					require_weight_at_most: XcmWeight::from_parts(100, 10),
+					call: vec![1, 2, 3].into(),
+				},
+				RefundSurplus,
+				DepositAsset {
+					assets: AllCounted(1).into(),
+					beneficiary,
+				},
+			])
+		);
+	}
 }
@@ -153,6 +153,16 @@ impl<AccountId, Balance: Default> EVMBridge<AccountId, Balance> for () {
 	}
 }
 
+/// Emits the ERC20 `Transfer` log of a currency balance change made outside of the EVM.
+pub trait Erc20TransferLog<AccountId, Balance> {
+	/// `from` is `None` when minting, `to` is `None` when burning.
+	fn log_transfer(currency_id: CurrencyId, from: Option<&AccountId>, to: Option<&AccountId>, amount: Balance);
+}
+
+impl<AccountId, Balance> Erc20TransferLog<AccountId, Balance> for () {
+	fn log_transfer(_currency_id: CurrencyId, _from: Option<&AccountId>, _to: Option<&AccountId>, _amount: Balance) {}
+}
+
 /// EVM bridge for collateral liquidation.
 pub trait LiquidationEvmBridge {
 	/// Execute liquidation. Sufficient repayment is expected to be transferred to `repay_dest`,
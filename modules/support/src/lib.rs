@@ -213,3 +213,10 @@ impl<AccountId> LiquidateCollateral<AccountId> for Tuple {
 pub trait BuyWeightRate {
 	fn calculate_rate(location: Location) -> Option<Ratio>;
 }
+
+pub trait XcmTransact<AccountId> {
+	/// Send XCM message to `dest` to execute the SCALE-encoded `call` with the origin derived from
+	/// the `sender`. The `fee` asset, relative to `dest`, is withdrawn from the derived account to
+	/// buy the execution, and the unused fee is deposited back to it.
+	fn transact(sender: &AccountId, dest: Location, call: Vec<u8>, fee: Asset, weight: Weight) -> DispatchResult;
+}
@@ -156,4 +156,17 @@ pub trait CallBuilder {
 		calls: Vec<(Self::RelayChainCall, XcmWeight)>,
 		extra_fee: Self::Balance,
 	) -> Xcm<()>;
+
+	/// Wrap the encoded call into the Xcm format, paying the fee with the given asset.
+	///  params:
+	/// - call: The encoded call to be executed on the destination.
+	/// - fee: The asset withdrawn to buy the `weight` and the weight of the XCM instructions.
+	/// - weight: The weight limit used for XCM.
+	/// - beneficiary: The location where the unused fee is deposited to.
+	fn finalize_transact_into_xcm_message(
+		call: Vec<u8>,
+		fee: Asset,
+		weight: XcmWeight,
+		beneficiary: Location,
+	) -> Xcm<()>;
 }
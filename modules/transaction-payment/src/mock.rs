@@ -141,6 +141,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<Zero, AccountId>;
 	type OnDust = ();
+	type Erc20TransferLog = ();
 }
 
 ord_parameter_types! {
@@ -29,7 +29,7 @@
 
 use frame_support::{pallet_prelude::*, traits::Get};
 use frame_system::pallet_prelude::*;
-use module_support::{relaychain::CallBuilder, HomaSubAccountXcm};
+use module_support::{relaychain::CallBuilder, HomaSubAccountXcm, XcmTransact};
 use orml_traits::XcmTransfer;
 use primitives::{Balance, CurrencyId, EraIndex};
 use scale_info::TypeInfo;
@@ -38,6 +38,7 @@ use sp_std::{convert::From, prelude::*, vec, vec::Vec};
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 
 mod mocks;
+mod tests;
 
 pub use module::*;
 
@@ -101,6 +102,8 @@ pub mod module {
 	pub enum Error<T> {
 		/// The xcm operation have failed
 		XcmFailed,
+		/// The destination is neither the relay chain nor a sibling parachain
+		InvalidDest,
 	}
 
 	#[pallet::event]
@@ -306,4 +309,41 @@ pub mod module {
 			Self::xcm_dest_weight_and_fee(XcmInterfaceOperation::ParachainFee(Box::new(location))).1
 		}
 	}
+
+	impl<T: Config> XcmTransact<T::AccountId> for Pallet<T> {
+		/// Send XCM message to `dest` to execute the call. The origin of the message is descended
+		/// to the `sender`, so the call is dispatched by the account derived from it on `dest`.
+		/// Only the relay chain and the sibling parachains are allowed as `dest`.
+		fn transact(
+			sender: &T::AccountId,
+			dest: Location,
+			call: Vec<u8>,
+			fee: Asset,
+			weight: XcmWeight,
+		) -> DispatchResult {
+			ensure!(
+				matches!(dest.unpack(), (1, []) | (1, [Parachain(_)])),
+				Error::<T>::InvalidDest
+			);
+
+			let sender_location = T::AccountIdToLocation::convert(sender.clone());
+			// the unused fee is deposited back to the derived account on `dest`
+			let beneficiary = sender_location
+				.clone()
+				.reanchored(&dest, &<T as pallet_xcm::Config>::UniversalLocation::get())
+				.map_err(|_| Error::<T>::XcmFailed)?;
+			let xcm_message =
+				T::RelayChainCallBuilder::finalize_transact_into_xcm_message(call, fee, weight, beneficiary);
+			let result =
+				pallet_xcm::Pallet::<T>::send_xcm(sender_location.interior().clone(), dest.clone(), xcm_message);
+			log::debug!(
+				target: "xcm-interface",
+				"{:?} send XCM to transact on {:?}, result: {:?}",
+				sender, dest, result
+			);
+
+			ensure!(result.is_ok(), Error::<T>::XcmFailed);
+			Ok(())
+		}
+	}
 }
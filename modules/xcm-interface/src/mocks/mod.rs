@@ -28,6 +28,7 @@ use frame_system::{EnsureRoot, EnsureSignedBy};
 use orml_traits::xcm_transfer::Transferred;
 use primitives::{CurrencyId, TokenSymbol};
 use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage};
+use std::cell::RefCell;
 use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds, SignedToAccountId32};
 use xcm_executor::traits::XcmAssetTransfers;
 
@@ -136,6 +137,31 @@ impl XcmTransfer<AccountId, Balance, CurrencyId> for MockXcmTransfer {
 	}
 }
 
+thread_local! {
+	static SENT_XCM: RefCell<Vec<(Location, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+/// The XCM messages sent by `MockXcmRouter`.
+pub fn sent_xcm() -> Vec<(Location, Xcm<()>)> {
+	SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+/// Records the XCM messages sent.
+pub struct MockXcmRouter;
+impl SendXcm for MockXcmRouter {
+	type Ticket = (Location, Xcm<()>);
+
+	fn validate(dest: &mut Option<Location>, msg: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+		let pair = (dest.take().unwrap(), msg.take().unwrap());
+		Ok((pair, Assets::new()))
+	}
+
+	fn deliver(pair: Self::Ticket) -> Result<XcmHash, SendError> {
+		SENT_XCM.with(|sent| sent.borrow_mut().push(pair));
+		Ok([0u8; 32])
+	}
+}
+
 pub struct AccountIdToLocation;
 impl Convert<AccountId, Location> for AccountIdToLocation {
 	fn convert(account: AccountId) -> Location {
@@ -243,7 +269,7 @@ macro_rules! impl_mock {
 		impl pallet_xcm::Config for Runtime {
 			type RuntimeEvent = RuntimeEvent;
 			type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
-			type XcmRouter = ();
+			type XcmRouter = MockXcmRouter;
 			type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
 			type XcmExecuteFilter = Everything;
 			type XcmExecutor = MockExec;
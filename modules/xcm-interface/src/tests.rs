@@ -0,0 +1,105 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the xcm interface module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mocks::{polkadot::*, sent_xcm, ExtBuilder, ALICE};
+use module_relaychain::TRANSACT_OVERHEAD_WEIGHT;
+
+#[test]
+fn transact_works() {
+	ExtBuilder::default().build::<Runtime>().execute_with(|| {
+		let fee: Asset = (Location::here(), 1_000_000).into();
+		let weight = XcmWeight::from_parts(100, 10);
+		let alice = AccountId32 {
+			network: None,
+			id: ALICE.into(),
+		};
+
+		assert_ok!(XcmInterface::transact(
+			&ALICE,
+			Location::parent(),
+			vec![1, 2, 3],
+			fee.clone(),
+			weight
+		));
+		let sibling = Location::new(1, Parachain(2001));
+		assert_ok!(XcmInterface::transact(
+			&ALICE,
+			sibling.clone(),
+			vec![1, 2, 3],
+			fee.clone(),
+			weight
+		));
+
+		// the origin is descended to the sender, and the unused fee is refunded to the account derived
+		// from it on `dest`
+		let message = |beneficiary: Location| {
+			Xcm(vec![
+				DescendOrigin(alice.into()),
+				WithdrawAsset(fee.clone().into()),
+				BuyExecution {
+					fees: fee.clone(),
+					weight_limit: Limited(weight.saturating_add(TRANSACT_OVERHEAD_WEIGHT)),
+				},
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					require_weight_at_most: weight,
+					call: vec![1, 2, 3].into(),
+				},
+				RefundSurplus,
+				DepositAsset {
+					assets: AllCounted(1).into(),
+					beneficiary,
+				},
+			])
+		};
+		assert_eq!(
+			sent_xcm(),
+			vec![
+				(Location::parent(), message(Location::new(0, [Parachain(2000), alice]))),
+				(sibling, message(Location::new(1, [Parachain(2000), alice]))),
+			]
+		);
+	});
+}
+
+#[test]
+fn transact_rejects_invalid_dest() {
+	ExtBuilder::default().build::<Runtime>().execute_with(|| {
+		let fee: Asset = (Location::here(), 1_000_000).into();
+		let weight = XcmWeight::from_parts(100, 10);
+
+		for dest in [
+			Location::here(),
+			Location::new(0, Parachain(2001)),
+			Location::new(1, [Parachain(2001), PalletInstance(1)]),
+			Location::new(2, GlobalConsensus(NetworkId::Kusama)),
+		] {
+			assert_noop!(
+				XcmInterface::transact(&ALICE, dest, vec![1, 2, 3], fee.clone(), weight),
+				Error::<Runtime>::InvalidDest
+			);
+		}
+		assert!(sent_xcm().is_empty());
+	});
+}
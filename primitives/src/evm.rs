@@ -32,7 +32,7 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_core::{H160, H256, U256};
 use sp_runtime::{traits::Zero, RuntimeDebug, SaturatedConversion};
-use sp_std::vec::Vec;
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 /// Evm Address.
 pub type EvmAddress = sp_core::H160;
@@ -57,6 +57,9 @@ const GAS_LIMIT_CHUNK: u64 = 30_000u64;
 // MAX GAS_LIMIT CC, log2(BLOCK_STORAGE_LIMIT)
 pub const MAX_GAS_LIMIT_CC: u32 = 22u32;
 
+/// keccak256("Transfer(address,address,uint256)"), topic of the ERC20 `Transfer` event
+pub const ERC20_TRANSFER_TOPIC: H256 = H256(hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"));
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 /// External input from the transaction.
@@ -90,6 +93,99 @@ pub struct ExecutionInfo<T> {
 pub type CallInfo = ExecutionInfo<Vec<u8>>;
 pub type CreateInfo = ExecutionInfo<H160>;
 
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AccessListInfo {
+	/// Access list of the execution, excluding the sender, the target and the precompiles
+	pub access_list: Vec<AccessListItem>,
+	/// Exit reason of the execution with the access list
+	pub exit_reason: ExitReason,
+	/// Gas used by the execution with the access list
+	pub used_gas: U256,
+	/// Storage used by the execution with the access list
+	pub used_storage: i32,
+	/// Gas saved compared to the execution with the requested access list
+	pub gas_saved: U256,
+}
+
+/// Overrides of an account state for a single RPC execution, like the state overrides of
+/// Geth's `eth_call`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct StateOverride<Balance> {
+	/// Balance, with the EVM decimals
+	pub balance: Option<Balance>,
+	/// Nonce
+	pub nonce: Option<Nonce>,
+	/// Code, empty code removes the contract
+	pub code: Option<Vec<u8>>,
+	/// Replaces the whole storage, can't be used with `state_diff`
+	pub state: Option<BTreeMap<H256, H256>>,
+	/// Replaces the given storage slots, can't be used with `state`
+	pub state_diff: Option<BTreeMap<H256, H256>>,
+}
+
+/// Base fees and gas usage of recent blocks, like Ethereum's `eth_feeHistory`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FeeHistory {
+	/// Lowest block number of the history
+	pub oldest_block: BlockNumber,
+	/// Base fee per gas of each block, followed by the base fee per gas of the next block
+	pub base_fee_per_gas: Vec<U256>,
+	/// EVM gas used by each block
+	pub gas_used: Vec<u64>,
+	/// EVM gas used per block targeted by the base fee
+	pub gas_target: u64,
+}
+
+/// Hasher applied to a key of an EVM storage map.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum StorageKeyHasher {
+	/// The key itself
+	Identity,
+	/// `twox_64(key) ++ key`
+	Twox64Concat,
+	/// `blake2_128(key) ++ key`
+	Blake2_128Concat,
+}
+
+/// A storage entry read from the state trie.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct StorageEntry {
+	/// Storage key in the state trie, `prefix` followed by the hashed map keys
+	pub key: Vec<u8>,
+	/// `twox_128(pallet_prefix) ++ twox_128(storage_prefix)`
+	pub prefix: Vec<u8>,
+	/// Hasher of each map key, in order
+	pub hashers: Vec<StorageKeyHasher>,
+	/// SCALE encoded value, `None` if the entry does not exist
+	pub value: Option<Vec<u8>>,
+}
+
+/// The storage entries of an EVM account. Their Merkle proof against the state root can be fetched
+/// with `state_getReadProof` using the keys.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AccountEntries {
+	/// The EVM address
+	pub address: EvmAddress,
+	/// `Accounts` entry of the address
+	pub account: StorageEntry,
+	/// `CodeInfos` entry of the contract code, `None` if the address is not a contract
+	pub code_info: Option<StorageEntry>,
+	/// `Codes` entry of the contract code, `None` if the address is not a contract
+	pub code: Option<StorageEntry>,
+	/// `AccountStorages` entries of the requested slots
+	pub storage: Vec<(H256, StorageEntry)>,
+}
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct BlockLimits {
@@ -145,6 +241,9 @@ pub const PRECOMPILE_ADDRESS_START: EvmAddress = H160(hex!("00000000000000000000
 pub const PREDEPLOY_ADDRESS_START: EvmAddress = H160(hex!("0000000000000000000000000000000000000800"));
 pub const MIRRORED_TOKENS_ADDRESS_START: EvmAddress = H160(hex!("0000000000000000000100000000000000000000"));
 pub const MIRRORED_NFT_ADDRESS_START: u64 = 0x2000000;
+/// Mirrored ERC721 contracts of the NFT classes
+/// 0x0000000000000000000600000000000000000000 - 0x00000000000000000006000000000000ffffffff
+pub const MIRRORED_NFT_CLASS_ADDRESS_START: EvmAddress = H160(hex!("0000000000000000000600000000000000000000"));
 /// ERC20 Holding Account used for transfer ERC20 token
 pub const ERC20_HOLDING_ACCOUNT: EvmAddress = H160(hex_literal::hex!("000000000000000000ff00000000000000000000"));
 /// System contract address prefix
@@ -161,6 +260,7 @@ pub const SYSTEM_CONTRACT_ADDRESS_PREFIX: [u8; 9] = [0u8; 9];
 ///                     ^^                     CurrencyId Type: 1-Token 2-DexShare 3-StableAsset
 ///                                                             4-LiquidCrowdloan
 ///                                                             5-ForeignAsset(ignore Erc20, without the prefix of system contracts)
+///                                                             6-NFT class, the mirrored ERC721 contract
 ///                                                             FF-Erc20 Holding Account
 ///                                         ^^ CurrencyId Type is 1-Token, Token
 ///                                   ^^^^^^^^ CurrencyId Type is 1-Token, NFT
@@ -173,6 +273,7 @@ pub const SYSTEM_CONTRACT_ADDRESS_PREFIX: [u8; 9] = [0u8; 9];
 ///                                   ^^^^^^^^ CurrencyId Type is 3-StableAsset, StableAssetPoolId
 ///                                   ^^^^^^^^ CurrencyId Type is 4-LiquidCrowdloan, Lease
 ///                                       ^^^^ CurrencyId Type is 5-ForeignAsset, ForeignAssetId
+///                                   ^^^^^^^^ CurrencyId Type is 6-NFT class, ClassId
 /// Check if the given `address` is a system contract.
 ///
 /// It's system contract if the address starts with SYSTEM_CONTRACT_ADDRESS_PREFIX.
@@ -190,6 +291,22 @@ pub const H160_POSITION_DEXSHARE_RIGHT_FIELD: Range<usize> = 16..20;
 pub const H160_POSITION_STABLE_ASSET: Range<usize> = 16..20;
 pub const H160_POSITION_LIQUID_CROADLOAN: Range<usize> = 16..20;
 pub const H160_POSITION_FOREIGN_ASSET: Range<usize> = 18..20;
+pub const H160_POSITION_NFT_CLASS: Range<usize> = 16..20;
+
+/// Generate the EvmAddress of the mirrored ERC721 contract of the NFT class.
+pub fn nft_class_address(class_id: u32) -> EvmAddress {
+	let mut address = MIRRORED_NFT_CLASS_ADDRESS_START;
+	address[H160_POSITION_NFT_CLASS].copy_from_slice(&class_id.to_be_bytes());
+	address
+}
+
+/// Decode the NFT class id from the EvmAddress of the mirrored ERC721 contract.
+pub fn nft_class_id(address: &EvmAddress) -> Option<u32> {
+	if address[..H160_POSITION_NFT_CLASS.start] != MIRRORED_NFT_CLASS_ADDRESS_START[..H160_POSITION_NFT_CLASS.start] {
+		return None;
+	}
+	Some(u32::from_be_bytes(address[H160_POSITION_NFT_CLASS].try_into().ok()?))
+}
 
 /// Generate the EvmAddress from CurrencyId so that evm contracts can call the erc20 contract.
 /// NOTE: Can not be used directly, need to check the erc20 is mapped.
@@ -233,6 +350,9 @@ impl TryFrom<CurrencyId> for EvmAddress {
 	}
 }
 
+/// The step of the tip encoded in the gas price of `eth_call_v2`, 10% of the 100 Gwei fee per gas.
+const TEN_GWEI: u64 = 10_000_000_000u64;
+
 pub fn decode_gas_price(gas_price: u64, gas_limit: u64, tx_fee_per_gas: u128) -> Option<(u128, u32)> {
 	// ensure gas_price >= 100 Gwei
 	if u128::from(gas_price) < tx_fee_per_gas {
@@ -241,7 +361,6 @@ pub fn decode_gas_price(gas_price: u64, gas_limit: u64, tx_fee_per_gas: u128) ->
 
 	let mut tip: u128 = 0;
 	let mut actual_gas_price = gas_price;
-	const TEN_GWEI: u64 = 10_000_000_000u64;
 
 	// tip = 10% * tip_number
 	let tip_number = gas_price.checked_div(TEN_GWEI)?.checked_sub(10)?;
@@ -263,6 +382,12 @@ pub fn decode_gas_price(gas_price: u64, gas_limit: u64, tx_fee_per_gas: u128) ->
 	Some((tip, valid_until))
 }
 
+/// Decode the max fee per gas the signer pays from the gas price of `eth_call_v2`, which is the fee
+/// per gas with the tip steps on top. The `valid_until` encoded below the tip step is not paid.
+pub fn decode_max_fee_per_gas(gas_price: u64) -> u128 {
+	u128::from(gas_price - gas_price % TEN_GWEI)
+}
+
 pub fn decode_gas_limit(gas_limit: u64) -> (u64, u32) {
 	let gas_and_storage: u64 = gas_limit.checked_rem(GAS_MASK).expect("constant never failed; qed");
 	let actual_gas_limit: u64 = gas_and_storage
@@ -340,7 +465,7 @@ pub mod tracing {
 	use scale_info::TypeInfo;
 	use sp_core::{H160, H256, U256};
 	use sp_runtime::RuntimeDebug;
-	use sp_std::vec::Vec;
+	use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 	#[cfg(feature = "std")]
 	use serde::{Deserialize, Serialize};
@@ -483,6 +608,35 @@ pub mod tracing {
 	pub enum TraceOutcome {
 		Calls(Vec<CallTrace>),
 		Steps(Vec<Step>),
+		Prestate(PrestateTrace),
+	}
+
+	#[derive(Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct AccountState {
+		// balance in EVM decimals
+		pub balance: Option<U256>,
+		pub nonce: Option<u64>,
+		// code hash, if the account is a contract
+		pub code_hash: Option<H256>,
+		// touched storage slots
+		pub storage: BTreeMap<H256, H256>,
+	}
+
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub enum PrestateTrace {
+		// State of every touched account before execution
+		Prestate(BTreeMap<H160, AccountState>),
+		// Accounts modified by the execution. `pre` holds the state before execution and `post` only
+		// holds the fields that changed. Accounts created by the execution are only in `post` and
+		// accounts removed by the execution are only in `pre`.
+		Diff {
+			pre: BTreeMap<H160, AccountState>,
+			post: BTreeMap<H160, AccountState>,
+		},
 	}
 
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -490,6 +644,7 @@ pub mod tracing {
 	pub enum TracerConfig {
 		CallTracer,
 		OpcodeTracer(OpcodeConfig),
+		PrestateTracer(PrestateConfig),
 	}
 
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -504,4 +659,12 @@ pub mod tracing {
 		pub disable_stack: bool,
 		pub enable_memory: bool,
 	}
+
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct PrestateConfig {
+		// Return the changes made by the execution instead of the state of every touched account.
+		pub diff_mode: bool,
+	}
 }
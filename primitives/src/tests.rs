@@ -18,8 +18,8 @@
 
 use super::*;
 use crate::evm::{
-	decode_gas_limit, decode_gas_price, is_system_contract, EvmAddress, MAX_GAS_LIMIT_CC,
-	SYSTEM_CONTRACT_ADDRESS_PREFIX,
+	decode_gas_limit, decode_gas_price, decode_max_fee_per_gas, is_system_contract, nft_class_address, nft_class_id,
+	EvmAddress, MAX_GAS_LIMIT_CC, SYSTEM_CONTRACT_ADDRESS_PREFIX,
 };
 use frame_support::assert_ok;
 use sp_core::H160;
@@ -228,6 +228,16 @@ fn decode_gas_price_works() {
 	);
 }
 
+#[test]
+fn decode_max_fee_per_gas_works() {
+	// fee = 100 Gwei, valid_until = 0
+	assert_eq!(decode_max_fee_per_gas(100_000_000_000), 100_000_000_000);
+	// fee = 100 Gwei, valid_until = u32::MAX
+	assert_eq!(decode_max_fee_per_gas(104_294_967_295), 100_000_000_000);
+	// fee = 100 Gwei, tip = 20%, valid_until = 1000
+	assert_eq!(decode_max_fee_per_gas(120_000_001_000), 120_000_000_000);
+}
+
 #[test]
 fn decode_gas_limit_works() {
 	assert_eq!(decode_gas_limit(u64::MAX), (15_480_000, 32768));
@@ -238,3 +248,27 @@ fn decode_gas_limit_works() {
 		(15330000, 2u32.pow(MAX_GAS_LIMIT_CC))
 	);
 }
+
+#[test]
+fn nft_class_address_works() {
+	assert_eq!(
+		nft_class_address(0),
+		EvmAddress::from_str("0x0000000000000000000600000000000000000000").unwrap()
+	);
+	assert_eq!(
+		nft_class_address(0x01020304),
+		EvmAddress::from_str("0x0000000000000000000600000000000001020304").unwrap()
+	);
+	assert!(is_system_contract(&nft_class_address(u32::MAX)));
+
+	assert_eq!(nft_class_id(&nft_class_address(0)), Some(0));
+	assert_eq!(nft_class_id(&nft_class_address(u32::MAX)), Some(u32::MAX));
+	assert_eq!(
+		nft_class_id(&EvmAddress::from_str("0x0000000000000000000100000000000000000000").unwrap()),
+		None
+	);
+	assert_eq!(
+		nft_class_id(&EvmAddress::from_str("0x0000000000000000000600000000000100000000").unwrap()),
+		None
+	);
+}
@@ -16,7 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{evm::EthereumTransactionMessage, signature::AcalaMultiSignature, to_bytes, Address, Balance};
+use crate::{
+	evm::{decode_max_fee_per_gas, EthereumTransactionMessage},
+	signature::AcalaMultiSignature,
+	to_bytes, Address, Balance,
+};
 use frame_support::ensure;
 use frame_support::{
 	dispatch::{DispatchInfo, GetDispatchInfo},
@@ -28,7 +32,7 @@ use module_evm_utility::ethereum::{
 use module_evm_utility_macro::keccak256;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_core::{H160, H256};
+use sp_core::{H160, H256, U256};
 use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
 use sp_runtime::{
 	generic::{CheckedExtrinsic, UncheckedExtrinsic},
@@ -42,13 +46,20 @@ use sp_std::{marker::PhantomData, prelude::*};
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(ConvertEthTx))]
-pub struct AcalaUncheckedExtrinsic<Call, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>(
+pub struct AcalaUncheckedExtrinsic<
+	Call,
+	Extra: SignedExtension,
+	ConvertEthTx,
+	StorageDepositPerByte,
+	TxFeePerGas,
+	BaseFeePerGas,
+>(
 	pub UncheckedExtrinsic<Address, Call, AcalaMultiSignature, Extra>,
-	PhantomData<(ConvertEthTx, StorageDepositPerByte, TxFeePerGas)>,
+	PhantomData<(ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas)>,
 );
 
-impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> Extrinsic
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas> Extrinsic
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
 {
 	type Call = Call;
 
@@ -70,29 +81,31 @@ impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte
 	}
 }
 
-impl<Call, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> ExtrinsicMetadata
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas> ExtrinsicMetadata
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
 {
 	const VERSION: u8 = UncheckedExtrinsic::<Address, Call, AcalaMultiSignature, Extra>::VERSION;
 	type SignedExtensions = Extra;
 }
 
-impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> ExtrinsicCall
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
+	ExtrinsicCall
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
 {
 	fn call(&self) -> &Self::Call {
 		self.0.call()
 	}
 }
 
-impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, Lookup> Checkable<Lookup>
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas, Lookup> Checkable<Lookup>
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
 where
 	Call: Encode + Member,
 	Extra: SignedExtension<AccountId = AccountId32>,
 	ConvertEthTx: Convert<(Call, Extra), Result<(EthereumTransactionMessage, Extra), InvalidTransaction>>,
 	StorageDepositPerByte: Get<Balance>,
 	TxFeePerGas: Get<Balance>,
+	BaseFeePerGas: Get<U256>,
 	Lookup: traits::Lookup<Source = Address, Target = AccountId32>,
 {
 	type Checked = CheckedExtrinsic<AccountId32, Call, Extra>;
@@ -113,11 +126,6 @@ where
 					ensure!(eth_msg.tip.is_zero(), InvalidTransaction::BadProof);
 				}
 
-				if !eth_msg.access_list.len().is_zero() {
-					// Not yet supported, require empty
-					return Err(InvalidTransaction::BadProof.into());
-				}
-
 				let (tx_gas_price, tx_gas_limit) = if eth_msg.gas_price.is_zero() {
 					recover_sign_data(&eth_msg, TxFeePerGas::get(), StorageDepositPerByte::get())
 						.ok_or(InvalidTransaction::BadProof)?
@@ -126,20 +134,42 @@ where
 					(eth_msg.gas_price as u128, eth_msg.gas_limit as u128)
 				};
 
-				let msg = LegacyTransactionMessage {
-					nonce: eth_msg.nonce.into(),
-					gas_price: tx_gas_price.into(),
-					gas_limit: tx_gas_limit.into(),
-					action: eth_msg.action,
-					value: eth_msg.value.into(),
-					input: eth_msg.input,
-					chain_id: Some(eth_msg.chain_id),
+				ensure_base_fee_covered(&eth_msg, TxFeePerGas::get(), BaseFeePerGas::get())?;
+
+				// A legacy transaction can't carry an access list, so a legacy signature with a
+				// non-empty access list must sign the EIP-2930 message, which covers the access list.
+				let msg_hash = if eth_msg.access_list.is_empty() {
+					let msg = LegacyTransactionMessage {
+						nonce: eth_msg.nonce.into(),
+						gas_price: tx_gas_price.into(),
+						gas_limit: tx_gas_limit.into(),
+						action: eth_msg.action,
+						value: eth_msg.value.into(),
+						input: eth_msg.input,
+						chain_id: Some(eth_msg.chain_id),
+					};
+					log::trace!(
+						target: "evm", "tx msg: {:?}", msg
+					);
+
+					msg.hash()
+				} else {
+					let msg = EIP2930TransactionMessage {
+						chain_id: eth_msg.chain_id,
+						nonce: eth_msg.nonce.into(),
+						gas_price: tx_gas_price.into(),
+						gas_limit: tx_gas_limit.into(),
+						action: eth_msg.action,
+						value: eth_msg.value.into(),
+						input: eth_msg.input,
+						access_list: eth_msg.access_list,
+					};
+					log::trace!(
+						target: "evm", "tx msg: {:?}", msg
+					);
+
+					msg.hash()
 				};
-				log::trace!(
-					target: "evm", "tx msg: {:?}", msg
-				);
-
-				let msg_hash = msg.hash(); // TODO: consider rewrite this to use `keccak_256` for hashing because it could be faster
 
 				let signer = recover_signer(&sig, msg_hash.as_fixed_bytes()).ok_or(InvalidTransaction::BadProof)?;
 
@@ -175,6 +205,8 @@ where
 					(eth_msg.gas_price as u128, eth_msg.gas_limit as u128)
 				};
 
+				ensure_base_fee_covered(&eth_msg, TxFeePerGas::get(), BaseFeePerGas::get())?;
+
 				let msg = EIP2930TransactionMessage {
 					chain_id: eth_msg.chain_id,
 					nonce: eth_msg.nonce.into(),
@@ -222,6 +254,10 @@ where
 				// tip = priority_fee * gas_limit
 				let priority_fee = eth_msg.tip.checked_div(eth_msg.gas_limit.into()).unwrap_or_default();
 
+				// the tip is always paid in full on top of the fee, which must cover the base fee.
+				ensure!(priority_fee <= tx_gas_price, InvalidTransaction::BadProof);
+				ensure_base_fee_covered(&eth_msg, TxFeePerGas::get(), BaseFeePerGas::get())?;
+
 				let msg = EIP1559TransactionMessage {
 					chain_id: eth_msg.chain_id,
 					nonce: eth_msg.nonce.into(),
@@ -286,8 +322,8 @@ where
 	}
 }
 
-impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> GetDispatchInfo
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas> GetDispatchInfo
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
 where
 	Call: GetDispatchInfo,
 	Extra: SignedExtension,
@@ -297,8 +333,9 @@ where
 	}
 }
 
-impl<Call: Encode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> serde::Serialize
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call: Encode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
+	serde::Serialize
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
 {
 	fn serialize<S>(&self, seq: S) -> Result<S::Ok, S::Error>
 	where
@@ -308,8 +345,9 @@ impl<Call: Encode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte,
 	}
 }
 
-impl<'a, Call: Decode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> serde::Deserialize<'a>
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<'a, Call: Decode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
+	serde::Deserialize<'a>
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, BaseFeePerGas>
 {
 	fn deserialize<D>(de: D) -> Result<Self, D::Error>
 	where
@@ -377,6 +415,28 @@ fn verify_eip712_signature(eth_msg: EthereumTransactionMessage, sig: [u8; 65]) -
 	recover_signer(&sig, &msg_hash)
 }
 
+/// Ensure the max fee per gas paid by the signer covers the current base fee. The gas price signed
+/// by the signer also encodes `valid_until` and the storage limit, which are not paid, so the fee
+/// is decoded from it first.
+fn ensure_base_fee_covered(
+	eth_msg: &EthereumTransactionMessage,
+	tx_fee_per_gas: u128,
+	base_fee_per_gas: U256,
+) -> Result<(), InvalidTransaction> {
+	let max_fee_per_gas = if eth_msg.gas_price.is_zero() {
+		// eth_call, the fee per gas is fixed
+		tx_fee_per_gas
+	} else {
+		// eth_call_v2, the tip is encoded in the gas price
+		decode_max_fee_per_gas(eth_msg.gas_price)
+	};
+	ensure!(
+		U256::from(max_fee_per_gas) >= base_fee_per_gas,
+		InvalidTransaction::Payment
+	);
+	Ok(())
+}
+
 fn recover_sign_data(
 	eth_msg: &EthereumTransactionMessage,
 	ts_fee_per_gas: u128,
@@ -570,6 +630,49 @@ mod tests {
 		assert_ne!(recover_signer(&sign, new_msg.hash().as_fixed_bytes()), sender);
 	}
 
+	#[test]
+	fn verify_eth_with_access_list_should_works() {
+		// a legacy signature with an access list signs the EIP-2930 message
+		let msg = EIP2930TransactionMessage {
+			chain_id: 595,
+			nonce: U256::from(1),
+			gas_price: U256::from("0x640000006a"),
+			gas_limit: U256::from(21000),
+			action: TransactionAction::Call(H160::from_str("0x1111111111222222222233333333334444444444").unwrap()),
+			value: U256::from(123123),
+			input: vec![],
+			access_list: vec![AccessListItem {
+				address: hex!("bb9bc244d798123fde783fcc1c72d3bb8c189413").into(),
+				storage_keys: vec![H256::zero()],
+			}],
+		};
+
+		let sign = hex!("d5f00f010660ddcba5ee5f883a246dbb0b9bb809cc7a0660b607be51255b6ed12e475c83cb966353e63d54496b6b450fcbd65cddbc15b2182d56a3d1fce45ee31c");
+		let sender = Some(H160::from_str("0x17c5185167401ed00cf5f5b2fc97d9bbfdb7d025").unwrap());
+
+		assert_eq!(recover_signer(&sign, msg.hash().as_fixed_bytes()), sender);
+
+		// the signature doesn't cover the legacy message without the access list
+		let legacy_msg = LegacyTransactionMessage {
+			nonce: msg.nonce,
+			gas_price: msg.gas_price,
+			gas_limit: msg.gas_limit,
+			action: msg.action,
+			value: msg.value,
+			input: msg.input.clone(),
+			chain_id: Some(msg.chain_id),
+		};
+		assert_ne!(recover_signer(&sign, legacy_msg.hash().as_fixed_bytes()), sender);
+
+		let mut new_msg = msg.clone();
+		new_msg.access_list[0].storage_keys = vec![H256::from_low_u64_be(1)];
+		assert_ne!(recover_signer(&sign, new_msg.hash().as_fixed_bytes()), sender);
+
+		let mut new_msg = msg;
+		new_msg.access_list = vec![];
+		assert_ne!(recover_signer(&sign, new_msg.hash().as_fixed_bytes()), sender);
+	}
+
 	#[test]
 	fn verify_eth_1559_should_works() {
 		let msg = EIP1559TransactionMessage {
@@ -683,4 +786,66 @@ mod tests {
 
 		assert_eq!(recover_sign_data(&msg, u128::MAX, u128::MAX), None);
 	}
+
+	#[test]
+	fn ensure_base_fee_covered_should_works() {
+		let mut msg = EthereumTransactionMessage {
+			chain_id: 595,
+			genesis: Default::default(),
+			nonce: 1,
+			tip: 0,
+			gas_price: 0,
+			gas_limit: 2100000,
+			storage_limit: 64000,
+			action: TransactionAction::Call(H160::from_str("0x1111111111222222222233333333334444444444").unwrap()),
+			value: 0,
+			input: vec![],
+			access_list: vec![],
+			valid_until: 30,
+		};
+
+		let ts_fee_per_gas = 200u128.saturating_mul(10u128.saturating_pow(9)) & !0xffff;
+		let base_fee_per_gas = U256::from(ts_fee_per_gas);
+
+		// eth_call, the fee per gas is fixed
+		assert_eq!(ensure_base_fee_covered(&msg, ts_fee_per_gas, base_fee_per_gas), Ok(()));
+		assert_eq!(
+			ensure_base_fee_covered(&msg, ts_fee_per_gas, base_fee_per_gas + 1),
+			Err(InvalidTransaction::Payment)
+		);
+
+		// a high valid_until and storage limit are encoded in the signed gas price, but not paid
+		msg.valid_until = u32::MAX;
+		msg.storage_limit = 0xffff * 64;
+		let tx_gas_price = recover_sign_data(&msg, ts_fee_per_gas, 100_000_000_000_000u128)
+			.unwrap()
+			.0;
+		assert!(U256::from(tx_gas_price) > base_fee_per_gas * 2);
+		assert_eq!(
+			ensure_base_fee_covered(&msg, ts_fee_per_gas, base_fee_per_gas * 2),
+			Err(InvalidTransaction::Payment)
+		);
+
+		// eth_call_v2, fee = 100 Gwei, valid_until = u32::MAX
+		msg.gas_price = 104_294_967_295;
+		assert_eq!(
+			ensure_base_fee_covered(&msg, ts_fee_per_gas, U256::from(100_000_000_000u64)),
+			Ok(())
+		);
+		assert_eq!(
+			ensure_base_fee_covered(&msg, ts_fee_per_gas, U256::from(101_000_000_000u64)),
+			Err(InvalidTransaction::Payment)
+		);
+
+		// eth_call_v2, fee = 100 Gwei, tip = 10%, valid_until = u32::MAX
+		msg.gas_price = 114_294_967_295;
+		assert_eq!(
+			ensure_base_fee_covered(&msg, ts_fee_per_gas, U256::from(110_000_000_000u64)),
+			Ok(())
+		);
+		assert_eq!(
+			ensure_base_fee_covered(&msg, ts_fee_per_gas, U256::from(111_000_000_000u64)),
+			Err(InvalidTransaction::Payment)
+		);
+	}
 }
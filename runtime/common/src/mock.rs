@@ -190,6 +190,8 @@ impl module_evm::Config for TestRuntime {
 	type NewContractExtraBytes = ConstU32<100>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<20_000_000>;
+	type MinBaseFeePerGas = ConstU128<20_000_000>;
+	type BaseFeeGasTarget = ConstU64<15_000_000>;
 
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType = ();
@@ -0,0 +1,506 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use super::input::{Input, InputPricer, InputT, Output};
+use crate::WeightToGas;
+use frame_support::traits::Get;
+use module_aggregated_dex::{SwapPath, WeightInfo};
+use module_evm::{
+	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use module_support::SwapLimit;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{traits::Convert, DispatchError, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The `AggregatedDex` impl precompile.
+///
+/// Swaps are routed through the governance-set `AggregatedSwapPaths` of the currency pair,
+/// which may combine DEX and Taiga pools. Caller-supplied paths are not supported, the DEX
+/// precompile swaps with a specific DEX path.
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Get swap target amount. Rest `input` bytes: `supply_currency_id`, `target_currency_id`,
+///   `supply_amount`.
+/// - Get swap supply amount. Rest `input` bytes: `supply_currency_id`, `target_currency_id`,
+///   `target_amount`.
+/// - Swap with exact supply. Rest `input` bytes: `who`, `supply_currency_id`, `target_currency_id`,
+///   `supply_amount`, `min_target_amount`.
+/// - Swap with exact target. Rest `input` bytes: `who`, `supply_currency_id`, `target_currency_id`,
+///   `target_amount`, `max_supply_amount`.
+pub struct AggregatedDexPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	GetSwapTargetAmount = "getSwapTargetAmount(address,address,uint256)",
+	GetSwapSupplyAmount = "getSwapSupplyAmount(address,address,uint256)",
+	SwapWithExactSupply = "swapWithExactSupply(address,address,address,uint256,uint256)",
+	SwapWithExactTarget = "swapWithExactTarget(address,address,address,uint256,uint256)",
+}
+
+impl<Runtime> Precompile for AggregatedDexPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_dex::Config + module_aggregated_dex::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			Runtime::AddressMapping,
+			<Runtime as module_dex::Config>::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		match action {
+			Action::GetSwapTargetAmount => {
+				let supply_currency_id = input.currency_id_at(1)?;
+				let target_currency_id = input.currency_id_at(2)?;
+				let supply_amount = input.balance_at(3)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: get_swap_target_amount supply_currency_id: {:?}, target_currency_id: {:?}, supply_amount: {:?}",
+					supply_currency_id, target_currency_id, supply_amount
+				);
+
+				// If there are no swap paths or get_aggregated_swap_amount fail, return 0.
+				let target = match module_aggregated_dex::Pallet::<Runtime>::aggregated_swap_paths((
+					supply_currency_id,
+					target_currency_id,
+				)) {
+					Some(paths) => {
+						handle.record_cost(Pricer::<Runtime>::quote_cost(&paths))?;
+						module_aggregated_dex::Pallet::<Runtime>::get_aggregated_swap_amount(
+							&paths,
+							SwapLimit::ExactSupply(supply_amount, Balance::MIN),
+						)
+						.map(|(_, target)| target)
+						.unwrap_or_default()
+					}
+					None => Default::default(),
+				};
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(target),
+				})
+			}
+			Action::GetSwapSupplyAmount => {
+				let supply_currency_id = input.currency_id_at(1)?;
+				let target_currency_id = input.currency_id_at(2)?;
+				let target_amount = input.balance_at(3)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: get_swap_supply_amount supply_currency_id: {:?}, target_currency_id: {:?}, target_amount: {:?}",
+					supply_currency_id, target_currency_id, target_amount
+				);
+
+				// If there are no swap paths or get_aggregated_swap_amount fail, return 0.
+				let supply = match module_aggregated_dex::Pallet::<Runtime>::aggregated_swap_paths((
+					supply_currency_id,
+					target_currency_id,
+				)) {
+					Some(paths) => {
+						// the exact target quote runs the paths twice
+						handle.record_cost(Pricer::<Runtime>::quote_cost(&paths).saturating_mul(2))?;
+						module_aggregated_dex::Pallet::<Runtime>::get_aggregated_swap_amount(
+							&paths,
+							SwapLimit::ExactTarget(Balance::MAX, target_amount),
+						)
+						.map(|(supply, _)| supply)
+						.unwrap_or_default()
+					}
+					None => Default::default(),
+				};
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(supply),
+				})
+			}
+			Action::SwapWithExactSupply => {
+				let who = input.account_id_at(1)?;
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+				let supply_amount = input.balance_at(4)?;
+				let min_target_amount = input.balance_at(5)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: swap_with_exact_supply who: {:?}, supply_currency_id: {:?}, target_currency_id: {:?}, supply_amount: {:?}, min_target_amount: {:?}",
+					who, supply_currency_id, target_currency_id, supply_amount, min_target_amount
+				);
+
+				let paths = Self::swap_paths(supply_currency_id, target_currency_id).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("AggregatedDex SwapWithExactSupply failed", e),
+					}
+				})?;
+				let weight = <Runtime as module_aggregated_dex::Config>::WeightInfo::swap_with_exact_supply(
+					swap_path_length(&paths),
+				);
+				handle.record_cost(WeightToGas::convert(weight))?;
+
+				let (_, value) = module_aggregated_dex::Pallet::<Runtime>::do_aggregated_swap(
+					&who,
+					&paths,
+					SwapLimit::ExactSupply(supply_amount, min_target_amount),
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("AggregatedDex SwapWithExactSupply failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(value),
+				})
+			}
+			Action::SwapWithExactTarget => {
+				let who = input.account_id_at(1)?;
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+				let target_amount = input.balance_at(4)?;
+				let max_supply_amount = input.balance_at(5)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: swap_with_exact_target who: {:?}, supply_currency_id: {:?}, target_currency_id: {:?}, target_amount: {:?}, max_supply_amount: {:?}",
+					who, supply_currency_id, target_currency_id, target_amount, max_supply_amount
+				);
+
+				let paths = Self::swap_paths(supply_currency_id, target_currency_id).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("AggregatedDex SwapWithExactTarget failed", e),
+					}
+				})?;
+				let weight = <Runtime as module_aggregated_dex::Config>::WeightInfo::swap_with_exact_target(
+					swap_path_length(&paths),
+				);
+				handle.record_cost(WeightToGas::convert(weight))?;
+
+				let (value, _) = module_aggregated_dex::Pallet::<Runtime>::do_aggregated_swap(
+					&who,
+					&paths,
+					SwapLimit::ExactTarget(max_supply_amount, target_amount),
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("AggregatedDex SwapWithExactTarget failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(value),
+				})
+			}
+		}
+	}
+}
+
+impl<Runtime> AggregatedDexPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_dex::Config + module_aggregated_dex::Config,
+{
+	fn swap_paths(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+	) -> Result<Vec<SwapPath>, DispatchError> {
+		module_aggregated_dex::Pallet::<Runtime>::aggregated_swap_paths((supply_currency_id, target_currency_id))
+			.map(|paths| paths.into_inner())
+			.ok_or_else(|| module_aggregated_dex::Error::<Runtime>::CannotSwap.into())
+	}
+}
+
+/// The path length used by the `module_aggregated_dex` weights.
+fn swap_path_length(paths: &[SwapPath]) -> u32 {
+	paths.iter().fold(0, |u, swap_path| match swap_path {
+		SwapPath::Dex(v) => u.saturating_add(v.len() as u32),
+		SwapPath::Taiga(_, _, _) => u.saturating_add(1),
+	})
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_dex::Config + module_aggregated_dex::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			Runtime::AddressMapping,
+			<Runtime as module_dex::Config>::Erc20InfoMapping,
+		>::new(handle.input());
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::GetSwapTargetAmount | Action::GetSwapSupplyAmount => {
+				let supply_currency_id = input.currency_id_at(1)?;
+				let target_currency_id = input.currency_id_at(2)?;
+				let read_supply_currency = InputPricer::<Runtime>::read_currency(supply_currency_id);
+				let read_target_currency = InputPricer::<Runtime>::read_currency(target_currency_id);
+
+				// AggregatedDex::AggregatedSwapPaths (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				Self::BASE_COST
+					.saturating_add(read_supply_currency)
+					.saturating_add(read_target_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SwapWithExactSupply | Action::SwapWithExactTarget => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+				let read_supply_currency = InputPricer::<Runtime>::read_currency(supply_currency_id);
+				let read_target_currency = InputPricer::<Runtime>::read_currency(target_currency_id);
+
+				// AggregatedDex::AggregatedSwapPaths (r: 1)
+				// the swap itself is charged once the paths are known
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_supply_currency)
+					.saturating_add(read_target_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(cost)
+	}
+
+	fn quote_cost(paths: &[SwapPath]) -> u64 {
+		// DEX::TradingPairStatuses (r: 1 * (path_len - 1))
+		// DEX::LiquidityPool (r: 1 * (path_len - 1))
+		// StableAsset::Pools (r: 1)
+		let reads = paths.iter().fold(0u64, |reads, swap_path| match swap_path {
+			SwapPath::Dex(v) => reads.saturating_add((v.len() as u64).saturating_sub(1).saturating_mul(2)),
+			SwapPath::Taiga(_, _, _) => reads.saturating_add(1),
+		});
+		WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(reads))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::precompile::mock::{
+		alice_evm_addr, new_test_ext, AggregatedDex, DexModule, RuntimeOrigin, Test, ALICE, AUSD, DOT,
+	};
+	use frame_support::{assert_noop, assert_ok};
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+
+	type AggregatedDexPrecompile = super::AggregatedDexPrecompile<Test>;
+
+	fn set_dot_ausd_swap_paths() {
+		// enable DOT/AUSD
+		assert_ok!(DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), DOT, AUSD,));
+
+		assert_ok!(DexModule::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			AUSD,
+			1_000,
+			1_000_000,
+			0,
+			true
+		));
+
+		assert_ok!(AggregatedDex::update_aggregated_swap_paths(
+			RuntimeOrigin::root(),
+			vec![((DOT, AUSD), Some(vec![SwapPath::Dex(vec![DOT, AUSD])]))]
+		));
+	}
+
+	#[test]
+	fn get_swap_amount_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getSwapTargetAmount(address,address,uint256) -> 0x6544c64d
+			// DOT
+			// AUSD
+			// supply_amount
+			let input = hex! {"
+				6544c64d
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			// no swap paths
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 32].to_vec());
+
+			set_dot_ausd_swap_paths();
+
+			// 989
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000003dd
+			"};
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// getSwapSupplyAmount(address,address,uint256) -> 0x16dc60a8
+			// DOT
+			// AUSD
+			// target_amount
+			let input = hex! {"
+				16dc60a8
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			// 1
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn swap_with_exact_supply_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// swapWithExactSupply(address,address,address,uint256,uint256) -> 0x894c633e
+			// who
+			// DOT
+			// AUSD
+			// supply_amount
+			// min_target_amount
+			let input = hex! {"
+				894c633e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			assert_noop!(
+				AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "AggregatedDex SwapWithExactSupply failed: CannotSwap".into(),
+				}
+			);
+
+			set_dot_ausd_swap_paths();
+
+			// 989
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000003dd
+			"};
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn swap_with_exact_target_works() {
+		new_test_ext().execute_with(|| {
+			set_dot_ausd_swap_paths();
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// swapWithExactTarget(address,address,address,uint256,uint256) -> 0xe8a203e6
+			// who
+			// DOT
+			// AUSD
+			// target_amount
+			// max_supply_amount
+			let input = hex! {"
+				e8a203e6
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			// 1
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// target_amount: 1_000_000
+			let input = hex! {"
+				e8a203e6
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000f4240
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			assert_noop!(
+				AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "AggregatedDex SwapWithExactTarget failed: CannotSwap".into(),
+				}
+			);
+		});
+	}
+}
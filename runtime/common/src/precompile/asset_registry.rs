@@ -0,0 +1,411 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputPricer, InputT, Output};
+use crate::WeightToGas;
+use frame_support::traits::Get;
+use module_evm::{
+	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use module_support::Erc20InfoMapping;
+
+use ethabi::Token;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode};
+use primitives::{currency::AssetIds, CurrencyId};
+use sp_core::U256;
+use sp_runtime::{traits::Convert, RuntimeDebug, SaturatedConversion};
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::{v3, VersionedLocation};
+
+/// The AssetRegistry precompile
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Get asset metadata. `input` bytes: `currency_id`.
+/// - Get asset kind. `input` bytes: `currency_id`.
+/// - Get location. `input` bytes: `currency_id`.
+/// - Get currency by location. `input` bytes: `location`.
+///
+/// Locations are SCALE-encoded `VersionedLocation`, the same as the Xtokens precompile.
+pub struct AssetRegistryPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	GetAssetMetadata = "getAssetMetadata(address)",
+	GetAssetKind = "getAssetKind(address)",
+	GetLocation = "getLocation(address)",
+	GetCurrencyByLocation = "getCurrencyByLocation(bytes)",
+}
+
+/// The kind of a currency, returned by `getAssetKind`.
+#[derive(RuntimeDebug, Eq, PartialEq, Clone, Copy, IntoPrimitive)]
+#[repr(u8)]
+pub enum AssetKind {
+	Token = 0,
+	DexShare = 1,
+	Erc20 = 2,
+	StableAssetPoolToken = 3,
+	LiquidCrowdloan = 4,
+	ForeignAsset = 5,
+}
+
+impl From<CurrencyId> for AssetKind {
+	fn from(currency_id: CurrencyId) -> Self {
+		match currency_id {
+			CurrencyId::Token(_) => AssetKind::Token,
+			CurrencyId::DexShare(_, _) => AssetKind::DexShare,
+			CurrencyId::Erc20(_) => AssetKind::Erc20,
+			CurrencyId::StableAssetPoolToken(_) => AssetKind::StableAssetPoolToken,
+			CurrencyId::LiquidCrowdloan(_) => AssetKind::LiquidCrowdloan,
+			CurrencyId::ForeignAsset(_) => AssetKind::ForeignAsset,
+		}
+	}
+}
+
+impl<Runtime> Precompile for AssetRegistryPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_asset_registry::Config + module_prices::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			Runtime::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		match action {
+			Action::GetAssetMetadata => {
+				let currency_id = input.currency_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"asset_registry: get_asset_metadata, currency_id: {:?}",
+					currency_id
+				);
+
+				let metadata = asset_ids(currency_id)
+					.and_then(module_asset_registry::Pallet::<Runtime>::asset_metadatas)
+					.ok_or_else(|| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "Get metadata failed".into(),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: ethabi::encode(&[
+						Token::Bytes(metadata.name),
+						Token::Bytes(metadata.symbol),
+						Token::Uint(U256::from(metadata.decimals)),
+						Token::Uint(U256::from(metadata.minimal_balance.saturated_into::<u128>())),
+					]),
+				})
+			}
+			Action::GetAssetKind => {
+				let currency_id = input.currency_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"asset_registry: get_asset_kind, currency_id: {:?}",
+					currency_id
+				);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(Into::<u8>::into(AssetKind::from(currency_id))),
+				})
+			}
+			Action::GetLocation => {
+				let currency_id = input.currency_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"asset_registry: get_location, currency_id: {:?}",
+					currency_id
+				);
+
+				// only foreign assets have a registered location, returns empty bytes otherwise.
+				let location = match currency_id {
+					CurrencyId::ForeignAsset(foreign_asset_id) => {
+						module_asset_registry::Pallet::<Runtime>::foreign_asset_locations(foreign_asset_id)
+							.map(|location| VersionedLocation::V3(location).encode())
+							.unwrap_or_default()
+					}
+					_ => vec![],
+				};
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&location),
+				})
+			}
+			Action::GetCurrencyByLocation => {
+				let location_bytes = input.bytes_at(1)?;
+				let location = decode_location(&location_bytes).ok_or(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid location".into(),
+				})?;
+
+				log::debug!(
+					target: "evm",
+					"asset_registry: get_currency_by_location, location: {:?}",
+					location
+				);
+
+				// returns the zero address if the location is not registered.
+				let address = module_asset_registry::Pallet::<Runtime>::location_to_currency_ids(location)
+					.and_then(Runtime::Erc20InfoMapping::encode_evm_address)
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_address(address),
+				})
+			}
+		}
+	}
+}
+
+// The key of `AssetMetadatas` for the currency, `DexShare` and `LiquidCrowdloan` have no metadata.
+fn asset_ids(currency_id: CurrencyId) -> Option<AssetIds> {
+	match currency_id {
+		CurrencyId::Token(_) => Some(AssetIds::NativeAssetId(currency_id)),
+		CurrencyId::Erc20(address) => Some(AssetIds::Erc20(address)),
+		CurrencyId::StableAssetPoolToken(stable_asset_id) => Some(AssetIds::StableAssetId(stable_asset_id)),
+		CurrencyId::ForeignAsset(foreign_asset_id) => Some(AssetIds::ForeignAssetId(foreign_asset_id)),
+		CurrencyId::DexShare(_, _) | CurrencyId::LiquidCrowdloan(_) => None,
+	}
+}
+
+fn decode_location(mut bytes: &[u8]) -> Option<v3::Location> {
+	VersionedLocation::decode(&mut bytes).ok()?.try_into().ok()
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_asset_registry::Config + module_prices::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::GetAssetMetadata => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+				// AssetRegistry::AssetMetadatas (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_currency.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetAssetKind => {
+				let currency_id = input.currency_id_at(1)?;
+				InputPricer::<Runtime>::read_currency(currency_id)
+			}
+			Action::GetLocation => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+				// AssetRegistry::ForeignAssetLocations (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_currency.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetCurrencyByLocation => {
+				// AssetRegistry::LocationToCurrencyIds (r: 1)
+				// AssetRegistry::AssetMetadatas (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{alice_evm_addr, new_test_ext, AssetRegistry, RuntimeOrigin, Test, ALICE};
+	use frame_support::assert_ok;
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use primitives::currency::AssetMetadata;
+	use sp_core::H160;
+	use xcm::v4::prelude::*;
+
+	type AssetRegistryPrecompile = super::AssetRegistryPrecompile<Test>;
+
+	fn execute(input: &[u8]) -> PrecompileResult {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+		AssetRegistryPrecompile::execute(&mut MockPrecompileHandle::new(input, None, &context, false))
+	}
+
+	fn register_foreign_asset(location: Location) {
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(VersionedLocation::V4(location)),
+			Box::new(AssetMetadata {
+				name: b"Foreign Token".to_vec(),
+				symbol: b"FT".to_vec(),
+				decimals: 12,
+				minimal_balance: 100,
+			})
+		));
+	}
+
+	#[test]
+	fn get_asset_metadata_and_kind_works() {
+		new_test_ext().execute_with(|| {
+			// getAssetMetadata(address) -> 0x33f12ab8
+			// ACA
+			let input = hex! {"
+				33f12ab8
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+			assert_eq!(
+				execute(&input).unwrap().output,
+				ethabi::encode(&[
+					Token::Bytes(b"Acala".to_vec()),
+					Token::Bytes(b"ACA".to_vec()),
+					Token::Uint(U256::from(12)),
+					Token::Uint(U256::from(1)),
+				])
+			);
+
+			// foreign asset 0 is not registered yet
+			// getAssetMetadata(address) -> 0x33f12ab8
+			// ForeignAsset(0)
+			let input = hex! {"
+				33f12ab8
+				000000000000000000000000 0000000000000000000500000000000000000000
+			"};
+			assert_eq!(
+				execute(&input),
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Get metadata failed".into(),
+				})
+			);
+
+			register_foreign_asset(Location::new(1, [Parachain(2000)]));
+			assert_eq!(
+				execute(&input).unwrap().output,
+				ethabi::encode(&[
+					Token::Bytes(b"Foreign Token".to_vec()),
+					Token::Bytes(b"FT".to_vec()),
+					Token::Uint(U256::from(12)),
+					Token::Uint(U256::from(100)),
+				])
+			);
+
+			// getAssetKind(address) -> 0x0a562ec1
+			// ForeignAsset(0)
+			let input = hex! {"
+				0a562ec1
+				000000000000000000000000 0000000000000000000500000000000000000000
+			"};
+			assert_eq!(
+				execute(&input).unwrap().output,
+				Output::encode_uint(Into::<u8>::into(AssetKind::ForeignAsset))
+			);
+
+			// getAssetKind(address) -> 0x0a562ec1
+			// DOT
+			let input = hex! {"
+				0a562ec1
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+			assert_eq!(
+				execute(&input).unwrap().output,
+				Output::encode_uint(Into::<u8>::into(AssetKind::Token))
+			);
+		});
+	}
+
+	#[test]
+	fn location_lookup_works() {
+		new_test_ext().execute_with(|| {
+			let location = Location::new(1, [Parachain(2000)]);
+			let versioned_location = VersionedLocation::V3(v3::Location::try_from(location.clone()).unwrap()).encode();
+
+			// getLocation(address) -> 0xd9e0dc59
+			// ForeignAsset(0)
+			let input = hex! {"
+				d9e0dc59
+				000000000000000000000000 0000000000000000000500000000000000000000
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_bytes(&[]));
+
+			// getCurrencyByLocation(bytes) -> 0x275787fe
+			let mut reverse_input = Into::<u32>::into(Action::GetCurrencyByLocation).to_be_bytes().to_vec();
+			reverse_input.extend_from_slice(&ethabi::encode(&[Token::Bytes(versioned_location.clone())]));
+			assert_eq!(
+				execute(&reverse_input).unwrap().output,
+				Output::encode_address(H160::default())
+			);
+
+			register_foreign_asset(location);
+
+			assert_eq!(
+				execute(&input).unwrap().output,
+				Output::encode_bytes(&versioned_location)
+			);
+			assert_eq!(
+				execute(&reverse_input).unwrap().output,
+				Output::encode_address(H160::from(hex!("0000000000000000000500000000000000000000")))
+			);
+
+			// getLocation(address) -> 0xd9e0dc59
+			// ACA has no location
+			let input = hex! {"
+				d9e0dc59
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_bytes(&[]));
+
+			// getCurrencyByLocation(bytes) -> 0x275787fe
+			let mut input = Into::<u32>::into(Action::GetCurrencyByLocation).to_be_bytes().to_vec();
+			input.extend_from_slice(&ethabi::encode(&[Token::Bytes(vec![0xff])]));
+			assert_eq!(
+				execute(&input),
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid location".into(),
+				})
+			);
+		});
+	}
+}
@@ -0,0 +1,487 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputT};
+use module_evm::{
+	evm::Transfer, precompiles::Precompile, Context, ExitError, ExitReason, ExitRevert, ExitSucceed, PrecompileFailure,
+	PrecompileHandle, PrecompileOutput, PrecompileResult,
+};
+
+use ethabi::{ParamType, Token};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sp_core::{H160, U256};
+use sp_runtime::RuntimeDebug;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The Batch precompile
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Batch all. `input` bytes: `calls`. Reverts all the calls if any of them fails.
+/// - Batch some. `input` bytes: `calls`. Continues on failure.
+/// - Batch some until failure. `input` bytes: `calls`. Stops at the first failure, the previous
+///   calls are kept.
+///
+/// Each call is a tuple of (`target`, `value`, `call_data`, `gas_limit`), a `gas_limit` of zero
+/// forwards all the remaining gas. The calls are made on behalf of the caller, and the result of
+/// each call is returned as (`success`, `output`).
+///
+/// Every call runs in its own substate with a child storage meter limited by the remaining storage.
+/// The meter is merged into the batch only if the call succeeds, so a failed call is neither
+/// charged for its storage nor can it use up the storage of the following calls.
+pub struct BatchPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	BatchAll = "batchAll((address,uint256,bytes,uint64)[])",
+	BatchSome = "batchSome((address,uint256,bytes,uint64)[])",
+	BatchSomeUntilFailure = "batchSomeUntilFailure((address,uint256,bytes,uint64)[])",
+}
+
+#[derive(RuntimeDebug, Eq, PartialEq)]
+struct SubCall {
+	target: H160,
+	value: U256,
+	call_data: Vec<u8>,
+	gas_limit: u64,
+}
+
+impl<Runtime> Precompile for BatchPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			Runtime::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		// the value of the calls is transferred from the caller directly
+		if !handle.context().apparent_value.is_zero() {
+			return Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "batch is not payable".into(),
+			});
+		}
+
+		let calls = decode_calls(handle.input())?;
+		let caller = handle.context().caller;
+
+		log::debug!(
+			target: "evm",
+			"batch: {:?}, caller: {:?}, calls: {:?}",
+			action, caller, calls.len()
+		);
+
+		let mut results: Vec<Token> = vec![];
+		for call in calls {
+			let gas_limit = match call.gas_limit {
+				0 => None,
+				gas_limit if gas_limit > handle.remaining_gas() => {
+					return Err(PrecompileFailure::Error {
+						exit_status: ExitError::OutOfGas,
+					})
+				}
+				gas_limit => Some(gas_limit),
+			};
+			let transfer = if call.value.is_zero() {
+				None
+			} else {
+				Some(Transfer {
+					source: caller,
+					target: call.target,
+					value: call.value,
+				})
+			};
+			let context = Context {
+				address: call.target,
+				caller,
+				apparent_value: call.value,
+			};
+
+			// the same permission check as calling the contract directly
+			let (reason, output) = if module_evm::Pallet::<Runtime>::can_call_contract(&call.target, &caller) {
+				handle.call(call.target, transfer, call.call_data, gas_limit, false, &context)
+			} else {
+				(
+					ExitReason::Error(ExitError::Other(
+						Into::<&str>::into(module_evm::Error::<Runtime>::NoPermission).into(),
+					)),
+					vec![],
+				)
+			};
+
+			log::debug!(
+				target: "evm",
+				"batch: call {:?}, reason: {:?}",
+				call.target, reason
+			);
+
+			let success = match reason {
+				ExitReason::Succeed(_) => true,
+				ExitReason::Revert(_) | ExitReason::Error(_) => false,
+				ExitReason::Fatal(exit_status) => return Err(PrecompileFailure::Fatal { exit_status }),
+			};
+
+			if !success && action == Action::BatchAll {
+				return Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "batch call failed".into(),
+				});
+			}
+
+			results.push(Token::Tuple(vec![Token::Bool(success), Token::Bytes(output)]));
+
+			if !success && action == Action::BatchSomeUntilFailure {
+				break;
+			}
+		}
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: ethabi::encode(&[Token::Array(results)]),
+		})
+	}
+}
+
+fn decode_calls(input: &[u8]) -> Result<Vec<SubCall>, PrecompileFailure> {
+	let invalid_calls = || PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: "invalid calls".into(),
+	};
+
+	let call_type = ParamType::Tuple(vec![
+		ParamType::Address,
+		ParamType::Uint(256),
+		ParamType::Bytes,
+		ParamType::Uint(64),
+	]);
+	// skip the action
+	let params = input.get(4..).ok_or_else(invalid_calls)?;
+	let tokens = ethabi::decode(&[ParamType::Array(Box::new(call_type))], params).map_err(|_| invalid_calls())?;
+
+	tokens
+		.into_iter()
+		.next()
+		.and_then(Token::into_array)
+		.ok_or_else(invalid_calls)?
+		.into_iter()
+		.map(|call| {
+			let mut call = call.into_tuple().ok_or_else(invalid_calls)?.into_iter();
+			let target = call.next().and_then(Token::into_address).ok_or_else(invalid_calls)?;
+			let value = call.next().and_then(Token::into_uint).ok_or_else(invalid_calls)?;
+			let call_data = call.next().and_then(Token::into_bytes).ok_or_else(invalid_calls)?;
+			let gas_limit = call.next().and_then(Token::into_uint).ok_or_else(invalid_calls)?;
+
+			Ok(SubCall {
+				target,
+				value,
+				call_data,
+				gas_limit: gas_limit.try_into().map_err(|_| invalid_calls())?,
+			})
+		})
+		.collect()
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			// the calls are charged by the executor when they are made
+			Action::BatchAll | Action::BatchSome | Action::BatchSomeUntilFailure => 0,
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::{
+		mock::{alice, alice_evm_addr, bob, bob_evm_addr, new_test_ext, Balances, EVMModule, Test},
+		BATCH,
+	};
+	use frame_support::assert_ok;
+	use hex_literal::hex;
+	use module_evm::Runner;
+	use module_support::AddressMapping;
+
+	// 1_000 native tokens in the 18 decimals of the EVM
+	const VALUE: u128 = 1_000_000_000;
+	// more than the balance of alice
+	const INSUFFICIENT_VALUE: u128 = 1_000_000_000_000_000_000_000_000;
+
+	fn encode_input(action: Action, calls: Vec<(H160, u128)>) -> Vec<u8> {
+		let mut input = Into::<u32>::into(action).to_be_bytes().to_vec();
+		input.extend_from_slice(&ethabi::encode(&[Token::Array(
+			calls
+				.into_iter()
+				.map(|(target, value)| {
+					Token::Tuple(vec![
+						Token::Address(target),
+						Token::Uint(U256::from(value)),
+						Token::Bytes(vec![]),
+						Token::Uint(U256::zero()),
+					])
+				})
+				.collect(),
+		)]));
+		input
+	}
+
+	fn encode_results(results: Vec<bool>) -> Vec<u8> {
+		ethabi::encode(&[Token::Array(
+			results
+				.into_iter()
+				.map(|success| Token::Tuple(vec![Token::Bool(success), Token::Bytes(vec![])]))
+				.collect(),
+		)])
+	}
+
+	fn batch(input: Vec<u8>) -> (ExitReason, Vec<u8>) {
+		batch_with_storage_limit(input, 1_000)
+	}
+
+	fn batch_with_storage_limit(input: Vec<u8>, storage_limit: u32) -> (ExitReason, Vec<u8>) {
+		let info = <Test as module_evm::Config>::Runner::call(
+			alice_evm_addr(),
+			alice_evm_addr(),
+			BATCH,
+			input,
+			0,
+			1_000_000,
+			storage_limit,
+			vec![],
+			<Test as module_evm::Config>::config(),
+		)
+		.unwrap();
+		(info.exit_reason, info.value)
+	}
+
+	// deploys a contract of alice with the given runtime code
+	fn deploy(code: &[u8]) -> H160 {
+		// codecopy the runtime code after the 12 bytes of the init code and return it
+		let mut init = hex!("6000600c60003960006000f3").to_vec();
+		init[1] = code.len() as u8;
+		init[8] = code.len() as u8;
+		init.extend_from_slice(code);
+
+		let info = <Test as module_evm::Config>::Runner::create(
+			alice_evm_addr(),
+			init,
+			0,
+			1_000_000,
+			1_000,
+			vec![],
+			<Test as module_evm::Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		info.value
+	}
+
+	// sstore(0, 1)
+	const STORE: [u8; 6] = hex!("600160005500");
+	// sstore(0, 1) revert(0, 0)
+	const STORE_AND_REVERT: [u8; 10] = hex!("600160005560006000fd");
+	// the deposit of a new storage slot
+	const SLOT_DEPOSIT: u128 = 10 * module_evm::STORAGE_SIZE as u128;
+
+	fn contract_reserved_balance(contract: &H160) -> u128 {
+		Balances::reserved_balance(<Test as module_evm::Config>::AddressMapping::get_account_id(contract))
+	}
+
+	#[test]
+	fn batch_some_works() {
+		new_test_ext().execute_with(|| {
+			let alice_balance = Balances::free_balance(alice());
+			let bob_balance = Balances::free_balance(bob());
+
+			let (reason, output) = batch(encode_input(
+				Action::BatchSome,
+				vec![
+					(bob_evm_addr(), VALUE),
+					(bob_evm_addr(), INSUFFICIENT_VALUE),
+					(bob_evm_addr(), VALUE),
+				],
+			));
+			assert_eq!(reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(output, encode_results(vec![true, false, true]));
+			assert_eq!(Balances::free_balance(alice()), alice_balance - 2_000);
+			assert_eq!(Balances::free_balance(bob()), bob_balance + 2_000);
+		});
+	}
+
+	#[test]
+	fn batch_some_until_failure_works() {
+		new_test_ext().execute_with(|| {
+			let alice_balance = Balances::free_balance(alice());
+			let bob_balance = Balances::free_balance(bob());
+
+			let (reason, output) = batch(encode_input(
+				Action::BatchSomeUntilFailure,
+				vec![
+					(bob_evm_addr(), VALUE),
+					(bob_evm_addr(), INSUFFICIENT_VALUE),
+					(bob_evm_addr(), VALUE),
+				],
+			));
+			assert_eq!(reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(output, encode_results(vec![true, false]));
+			assert_eq!(Balances::free_balance(alice()), alice_balance - 1_000);
+			assert_eq!(Balances::free_balance(bob()), bob_balance + 1_000);
+		});
+	}
+
+	#[test]
+	fn batch_all_works() {
+		new_test_ext().execute_with(|| {
+			let alice_balance = Balances::free_balance(alice());
+			let bob_balance = Balances::free_balance(bob());
+
+			let (reason, _) = batch(encode_input(
+				Action::BatchAll,
+				vec![(bob_evm_addr(), VALUE), (bob_evm_addr(), INSUFFICIENT_VALUE)],
+			));
+			assert_eq!(reason, ExitReason::Revert(ExitRevert::Reverted));
+			assert_eq!(Balances::free_balance(alice()), alice_balance);
+			assert_eq!(Balances::free_balance(bob()), bob_balance);
+
+			let (reason, output) = batch(encode_input(
+				Action::BatchAll,
+				vec![(bob_evm_addr(), VALUE), (bob_evm_addr(), VALUE)],
+			));
+			assert_eq!(reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(output, encode_results(vec![true, true]));
+			assert_eq!(Balances::free_balance(alice()), alice_balance - 2_000);
+			assert_eq!(Balances::free_balance(bob()), bob_balance + 2_000);
+		});
+	}
+
+	#[test]
+	fn batch_some_does_not_charge_storage_of_failed_calls() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(EVMModule::enable_account_contract_development(&alice()));
+			let store = deploy(&STORE);
+			let store_and_revert = deploy(&STORE_AND_REVERT);
+
+			let alice_balance = Balances::free_balance(alice());
+			let alice_reserved = Balances::reserved_balance(alice());
+			let store_size = EVMModule::contract_storage_sizes(store);
+			let store_and_revert_size = EVMModule::contract_storage_sizes(store_and_revert);
+			let store_reserved = contract_reserved_balance(&store);
+			let store_and_revert_reserved = contract_reserved_balance(&store_and_revert);
+
+			let (reason, output) = batch(encode_input(Action::BatchSome, vec![(store_and_revert, 0), (store, 0)]));
+			assert_eq!(reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(output, encode_results(vec![false, true]));
+
+			// only the slot of the successful call is charged
+			assert_eq!(Balances::free_balance(alice()), alice_balance - SLOT_DEPOSIT);
+			assert_eq!(Balances::reserved_balance(alice()), alice_reserved);
+			assert_eq!(
+				EVMModule::contract_storage_sizes(store),
+				store_size + module_evm::STORAGE_SIZE
+			);
+			assert_eq!(contract_reserved_balance(&store), store_reserved + SLOT_DEPOSIT);
+			assert_eq!(
+				EVMModule::contract_storage_sizes(store_and_revert),
+				store_and_revert_size
+			);
+			assert_eq!(contract_reserved_balance(&store_and_revert), store_and_revert_reserved);
+		});
+	}
+
+	#[test]
+	fn batch_calls_share_storage_limit() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(EVMModule::enable_account_contract_development(&alice()));
+			let first = deploy(&STORE);
+			let second = deploy(&STORE);
+
+			let alice_balance = Balances::free_balance(alice());
+			let alice_reserved = Balances::reserved_balance(alice());
+			// enough for one new slot only
+			let storage_limit = module_evm::STORAGE_SIZE + 10;
+
+			let (reason, _) = batch_with_storage_limit(
+				encode_input(Action::BatchAll, vec![(first, 0), (second, 0)]),
+				storage_limit,
+			);
+			assert_eq!(reason, ExitReason::Revert(ExitRevert::Reverted));
+			assert_eq!(Balances::free_balance(alice()), alice_balance);
+			assert_eq!(Balances::reserved_balance(alice()), alice_reserved);
+
+			let (reason, output) = batch_with_storage_limit(
+				encode_input(Action::BatchSome, vec![(first, 0), (second, 0)]),
+				storage_limit,
+			);
+			assert_eq!(reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(output, encode_results(vec![true, false]));
+			assert_eq!(Balances::free_balance(alice()), alice_balance - SLOT_DEPOSIT);
+			assert_eq!(Balances::reserved_balance(alice()), alice_reserved);
+			assert_eq!(
+				contract_reserved_balance(&second),
+				contract_reserved_balance(&first) - SLOT_DEPOSIT
+			);
+		});
+	}
+
+	#[test]
+	fn decode_calls_works() {
+		let input = encode_input(Action::BatchAll, vec![(bob_evm_addr(), VALUE)]);
+		assert_eq!(
+			decode_calls(&input),
+			Ok(vec![SubCall {
+				target: bob_evm_addr(),
+				value: U256::from(VALUE),
+				call_data: vec![],
+				gas_limit: 0,
+			}])
+		);
+
+		assert_eq!(
+			decode_calls(&input[..input.len() - 1]),
+			Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "invalid calls".into(),
+			})
+		);
+	}
+}
@@ -695,11 +695,20 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		alice, alice_evm_addr, new_test_ext, run_to_block, Currencies, DexModule, RuntimeOrigin, Test, ALICE, AUSD, DOT,
+		alice, alice_evm_addr, new_test_ext, run_to_block, Currencies, DEXPalletId, DexModule, RuntimeEvent,
+		RuntimeOrigin, System, Test, ALICE, AUSD, DOT,
 	};
 	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
-	use module_evm::{precompiles::tests::MockPrecompileHandle, Context, ExitRevert};
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context, ExitRevert, Log};
+	use module_evm_accounts::EvmAddressMapping;
+	use module_support::AddressMapping;
+	use primitives::{
+		evm::{EvmAddress, ERC20_TRANSFER_TOPIC},
+		AccountId,
+	};
+	use sp_core::H160;
+	use sp_runtime::traits::AccountIdConversion;
 
 	type DEXPrecompile = crate::DEXPrecompile<Test>;
 
@@ -954,6 +963,65 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn swap_logs_each_transfer_once() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), DOT, AUSD,));
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AUSD,
+				1_000,
+				1_000_000,
+				0,
+				true
+			));
+			System::reset_events();
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// swapWithExactSupply(address,address[],uint256,uint256) -> 0x579baa18
+			let input = hex! {"
+				579baa18
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000000000000000000000000000 00000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+			let resp = DEXPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+
+			let dex_account: AccountId = DEXPalletId::get().into_account_truncating();
+			let dex_address = EvmAddressMapping::<Test>::get_default_evm_address(&dex_account);
+			let transfer_log = |currency_id: CurrencyId, from: H160, to: H160, amount: Balance| Log {
+				address: EvmAddress::try_from(currency_id).unwrap(),
+				topics: vec![ERC20_TRANSFER_TOPIC, from.into(), to.into()],
+				data: Output::encode_uint(amount),
+			};
+			let logs: Vec<Log> = System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::EVMModule(module_evm::Event::Logged { log }) => Some(log),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(
+				logs,
+				vec![
+					transfer_log(DOT, alice_evm_addr(), dex_address, 1),
+					transfer_log(AUSD, dex_address, alice_evm_addr(), 989),
+				]
+			);
+		});
+	}
+
 	#[test]
 	fn dex_precompile_swap_with_exact_target_should_work() {
 		new_test_ext().execute_with(|| {
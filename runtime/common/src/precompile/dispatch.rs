@@ -0,0 +1,300 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputT, Output};
+use crate::WeightToGas;
+use frame_support::{
+	dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+	traits::{Contains, OriginTrait},
+	weights::Weight,
+};
+use module_evm::{
+	precompiles::Precompile, AddressMapping, ExitError, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle,
+	PrecompileOutput, PrecompileResult,
+};
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::DecodeLimit;
+use sp_runtime::{
+	traits::{Convert, Dispatchable},
+	RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The max depth of the nested calls when decoding, the same as `sp_api::MAX_EXTRINSIC_DEPTH`.
+const MAX_CALL_DEPTH: u32 = 256;
+
+/// The configuration of the Dispatch precompile, implemented by the runtime.
+pub trait DispatchPrecompileConfig: frame_system::Config {
+	/// The calls that EVM accounts are allowed to dispatch, including the calls nested in them.
+	type CallFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+}
+
+/// The Dispatch precompile
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Dispatch. `input` bytes: `call`.
+///
+/// The SCALE-encoded `RuntimeCall` is dispatched with the signed origin of the caller's mapped
+/// account. The remaining gas must cover the weight of the call, and the actual weight is charged
+/// as gas after the dispatch.
+pub struct DispatchPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Dispatch = "dispatch(bytes)",
+}
+
+impl<Runtime> Precompile for DispatchPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config + DispatchPrecompileConfig,
+	<Runtime as frame_system::Config>::RuntimeCall: GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			Runtime::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		match action {
+			Action::Dispatch => {
+				if handle.is_static() {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "cannot dispatch in static call".into(),
+					});
+				}
+
+				let call_bytes = input.bytes_at(1)?;
+				let call = <Runtime as frame_system::Config>::RuntimeCall::decode_with_depth_limit(
+					MAX_CALL_DEPTH,
+					&mut &call_bytes[..],
+				)
+				.map_err(|_| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid call".into(),
+				})?;
+
+				if !Runtime::CallFilter::contains(&call) {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "call not allowed".into(),
+					});
+				}
+
+				let who = Runtime::AddressMapping::get_account_id(&handle.context().caller);
+
+				log::debug!(
+					target: "evm",
+					"dispatch: who: {:?}, call: {:?}",
+					&who, call
+				);
+
+				// The weight of the call is paid with gas, taking the larger of its ref time and proof size.
+				// The gasometer can't refund recorded gas, so the max weight is charged up front by
+				// checking it against the remaining gas, and only the actual weight is recorded after the
+				// dispatch, which therefore can't run out of gas.
+				let info = call.get_dispatch_info();
+				if weight_to_gas(info.weight) > handle.remaining_gas() {
+					return Err(PrecompileFailure::Error {
+						exit_status: ExitError::OutOfGas,
+					});
+				}
+
+				// the calls nested in a whitelisted batch are filtered as well
+				let mut origin = <Runtime as frame_system::Config>::RuntimeOrigin::signed(who);
+				origin.add_filter(Runtime::CallFilter::contains);
+
+				let result = call.dispatch(origin);
+
+				let actual_weight = extract_actual_weight(&result, &info);
+				handle.record_cost(weight_to_gas(actual_weight))?;
+
+				result.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Dispatch failed", e.error),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+		}
+	}
+}
+
+/// The gas of the weight, which is the larger of the gas of its ref time and of its proof size.
+fn weight_to_gas(weight: Weight) -> u64 {
+	WeightToGas::convert(weight).max(WeightToGas::convert(Weight::from_parts(weight.proof_size(), 0)))
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			// the weight of the call is charged when it is dispatched
+			Action::Dispatch => 0,
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::{
+		mock::{
+			alice, alice_evm_addr, bob, new_test_ext, Balances, Proxy, ProxyType, RuntimeCall, RuntimeOrigin, Test,
+		},
+		DISPATCH,
+	};
+	use ethabi::Token;
+	use frame_support::assert_ok;
+	use module_evm::{ExitReason, Runner};
+	use parity_scale_codec::Encode;
+
+	fn dispatch(call: RuntimeCall) -> ExitReason {
+		let mut input = Into::<u32>::into(Action::Dispatch).to_be_bytes().to_vec();
+		input.extend_from_slice(&ethabi::encode(&[Token::Bytes(call.encode())]));
+
+		<Test as module_evm::Config>::Runner::call(
+			alice_evm_addr(),
+			alice_evm_addr(),
+			DISPATCH,
+			input,
+			0,
+			1_000_000_000,
+			1_000,
+			vec![],
+			<Test as module_evm::Config>::config(),
+		)
+		.unwrap()
+		.exit_reason
+	}
+
+	fn transfer_to_bob(value: u128) -> RuntimeCall {
+		RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest: bob(), value })
+	}
+
+	#[test]
+	fn dispatch_works() {
+		new_test_ext().execute_with(|| {
+			let bob_balance = Balances::free_balance(bob());
+
+			assert_eq!(
+				dispatch(transfer_to_bob(1_000)),
+				ExitReason::Succeed(ExitSucceed::Returned)
+			);
+			assert_eq!(Balances::free_balance(bob()), bob_balance + 1_000);
+
+			assert_eq!(
+				dispatch(RuntimeCall::Utility(pallet_utility::Call::batch_all {
+					calls: vec![transfer_to_bob(1_000), transfer_to_bob(2_000)]
+				})),
+				ExitReason::Succeed(ExitSucceed::Returned)
+			);
+			assert_eq!(Balances::free_balance(bob()), bob_balance + 4_000);
+		});
+	}
+
+	#[test]
+	fn dispatch_filters_calls() {
+		new_test_ext().execute_with(|| {
+			let bob_balance = Balances::free_balance(bob());
+
+			assert_eq!(
+				dispatch(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+				ExitReason::Revert(ExitRevert::Reverted)
+			);
+
+			// the nested calls are filtered as well
+			assert_eq!(
+				dispatch(RuntimeCall::Utility(pallet_utility::Call::batch_all {
+					calls: vec![
+						transfer_to_bob(1_000),
+						RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+					]
+				})),
+				ExitReason::Revert(ExitRevert::Reverted)
+			);
+			assert_eq!(Balances::free_balance(bob()), bob_balance);
+		});
+	}
+
+	#[test]
+	fn dispatch_filters_proxy_calls() {
+		new_test_ext().execute_with(|| {
+			let alice_balance = Balances::free_balance(alice());
+
+			// the whitelisted proxy management calls are allowed
+			assert_eq!(
+				dispatch(RuntimeCall::Proxy(pallet_proxy::Call::add_proxy {
+					delegate: bob(),
+					proxy_type: ProxyType::Any,
+					delay: 0
+				})),
+				ExitReason::Succeed(ExitSucceed::Returned)
+			);
+			assert_eq!(pallet_proxy::Proxies::<Test>::get(alice()).0.len(), 1);
+
+			// the proxied call is dispatched with a new origin without the filter, so `proxy` is rejected
+			assert_ok!(Proxy::add_proxy(
+				RuntimeOrigin::signed(bob()),
+				alice(),
+				ProxyType::Any,
+				0
+			));
+			assert_eq!(
+				dispatch(RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+					real: bob(),
+					force_proxy_type: None,
+					call: Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+						dest: alice(),
+						value: 1_000
+					}))
+				})),
+				ExitReason::Revert(ExitRevert::Reverted)
+			);
+			// only the deposit of the proxy is paid
+			assert_eq!(Balances::free_balance(alice()), alice_balance - 2);
+		});
+	}
+}
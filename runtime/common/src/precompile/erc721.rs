@@ -0,0 +1,741 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputPricer, InputT, Output};
+use crate::WeightToGas;
+use frame_support::traits::Get;
+use module_evm::{
+	precompiles::Precompile, Context, ExitReason, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle,
+	PrecompileOutput, PrecompileResult,
+};
+use module_evm_utility_macro::keccak256;
+use module_nft::WeightInfo;
+use module_support::AddressMapping;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::evm::{nft_class_id, ERC20_TRANSFER_TOPIC};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// keccak256("Approval(address,address,uint256)")
+const ERC721_APPROVAL_TOPIC: H256 = H256(*keccak256!("Approval(address,address,uint256)"));
+/// keccak256("ApprovalForAll(address,address,bool)")
+const ERC721_APPROVAL_FOR_ALL_TOPIC: H256 = H256(*keccak256!("ApprovalForAll(address,address,bool)"));
+
+/// ERC165 interface id
+const INTERFACE_ID_ERC165: u32 = 0x01ffc9a7;
+/// ERC721 interface id
+const INTERFACE_ID_ERC721: u32 = 0x80ac58cd;
+/// ERC721Metadata interface id
+const INTERFACE_ID_ERC721_METADATA: u32 = 0x5b5e139f;
+/// bytes4(keccak256("onERC721Received(address,address,uint256,bytes)"))
+const ON_ERC721_RECEIVED_SELECTOR: [u8; 4] = [0x15, 0x0b, 0x7a, 0x02];
+
+/// The mirrored ERC721 contract of the NFT classes of `module_nft`.
+///
+/// Each NFT class is available at `primitives::evm::nft_class_address(class_id)`, and the caller
+/// is the `msg.sender` of the ERC721 calls.
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Query total supply.
+/// - Query balance. Rest `input` bytes: `owner`.
+/// - Query owner. Rest `input` bytes: `token_id`.
+/// - Query token URI. Rest `input` bytes: `token_id`.
+/// - Query approved. Rest `input` bytes: `token_id`.
+/// - Query is approved for all. Rest `input` bytes: `owner`, `operator`.
+/// - Approve. Rest `input` bytes: `approved`, `token_id`.
+/// - Set approval for all. Rest `input` bytes: `operator`, `approved`.
+/// - Transfer from. Rest `input` bytes: `from`, `to`, `token_id`.
+/// - Safe transfer from. Rest `input` bytes: `from`, `to`, `token_id`, optional `data`.
+/// - Supports interface. Rest `input` bytes: `interface_id`.
+pub struct Erc721Precompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	QueryTotalSupply = "totalSupply()",
+	QueryBalance = "balanceOf(address)",
+	QueryOwner = "ownerOf(uint256)",
+	QueryTokenURI = "tokenURI(uint256)",
+	QueryApproved = "getApproved(uint256)",
+	QueryApprovedForAll = "isApprovedForAll(address,address)",
+	Approve = "approve(address,uint256)",
+	SetApprovalForAll = "setApprovalForAll(address,bool)",
+	TransferFrom = "transferFrom(address,address,uint256)",
+	SafeTransferFrom = "safeTransferFrom(address,address,uint256)",
+	SafeTransferFromWithData = "safeTransferFrom(address,address,uint256,bytes)",
+	SupportsInterface = "supportsInterface(bytes4)",
+}
+
+impl<Runtime> Precompile for Erc721Precompile<Runtime>
+where
+	Runtime: module_evm::Config
+		+ module_prices::Config
+		+ module_nft::Config
+		+ orml_nft::Config<ClassId = u32, TokenId = u64>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let class_id = nft_class_id(&handle.code_address()).ok_or_else(|| PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: "invalid nft class".into(),
+		})?;
+		let class_info = orml_nft::Pallet::<Runtime>::classes(class_id).ok_or_else(|| PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: "nft class not found".into(),
+		})?;
+
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+
+		let action = input.action()?;
+
+		if handle.is_static()
+			&& matches!(
+				action,
+				Action::Approve
+					| Action::SetApprovalForAll
+					| Action::TransferFrom
+					| Action::SafeTransferFrom
+					| Action::SafeTransferFromWithData
+			) {
+			return Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "cannot be called with STATICCALL".into(),
+			});
+		}
+
+		let caller = handle.context().caller;
+
+		match action {
+			Action::QueryTotalSupply => Ok(PrecompileOutput {
+				exit_status: ExitSucceed::Returned,
+				output: Output::encode_uint(class_info.total_issuance),
+			}),
+			Action::QueryBalance => {
+				let who = input.account_id_at(1)?;
+
+				log::debug!(target: "evm", "erc721: class_id: {:?}, query_balance who: {:?}", class_id, who);
+
+				let balance = module_nft::Pallet::<Runtime>::class_balance(&who, class_id);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(balance),
+				})
+			}
+			Action::QueryOwner => {
+				let token_id = input.u64_at(1)?;
+				let owner = Self::owner_of(class_id, token_id)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_address(Self::evm_address(&owner)),
+				})
+			}
+			Action::QueryTokenURI => {
+				let token_id = input.u64_at(1)?;
+				let token_info = orml_nft::Pallet::<Runtime>::tokens(class_id, token_id).ok_or_else(|| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "nft token not found".into(),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&token_info.metadata),
+				})
+			}
+			Action::QueryApproved => {
+				let token_id = input.u64_at(1)?;
+				Self::owner_of(class_id, token_id)?;
+				let approved = module_nft::Pallet::<Runtime>::token_approvals(class_id, token_id)
+					.map(|who| Self::evm_address(&who))
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_address(approved),
+				})
+			}
+			Action::QueryApprovedForAll => {
+				let owner = input.account_id_at(1)?;
+				let operator = input.account_id_at(2)?;
+				let approved = module_nft::Pallet::<Runtime>::operator_approvals((class_id, owner), operator);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bool(approved),
+				})
+			}
+			Action::Approve => {
+				let approved = input.evm_address_at(1)?;
+				let token_id = input.u64_at(2)?;
+				let who = Runtime::AddressMapping::get_account_id(&caller);
+
+				log::debug!(target: "evm", "erc721: class_id: {:?}, approve who: {:?}, approved: {:?}, token_id: {:?}", class_id, who, approved, token_id);
+
+				let approved_account =
+					(!approved.is_zero()).then(|| Runtime::AddressMapping::get_account_id(&approved));
+				module_nft::Pallet::<Runtime>::approve(&who, approved_account, (class_id, token_id)).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("ERC721 Approve failed", e),
+					}
+				})?;
+
+				let owner = Self::owner_of(class_id, token_id)?;
+				handle.log(
+					handle.code_address(),
+					vec![
+						ERC721_APPROVAL_TOPIC,
+						Self::evm_address(&owner).into(),
+						approved.into(),
+						H256::from_low_u64_be(token_id),
+					],
+					vec![],
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::SetApprovalForAll => {
+				let operator = input.evm_address_at(1)?;
+				let approved = input.bool_at(2)?;
+				let who = Runtime::AddressMapping::get_account_id(&caller);
+
+				log::debug!(target: "evm", "erc721: class_id: {:?}, set_approval_for_all who: {:?}, operator: {:?}, approved: {:?}", class_id, who, operator, approved);
+
+				module_nft::Pallet::<Runtime>::set_approval_for_all(
+					&who,
+					class_id,
+					&Runtime::AddressMapping::get_account_id(&operator),
+					approved,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("ERC721 SetApprovalForAll failed", e),
+				})?;
+
+				handle.log(
+					handle.code_address(),
+					vec![ERC721_APPROVAL_FOR_ALL_TOPIC, caller.into(), operator.into()],
+					Output::encode_bool(approved),
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::TransferFrom | Action::SafeTransferFrom | Action::SafeTransferFromWithData => {
+				let from = input.evm_address_at(1)?;
+				let to = input.evm_address_at(2)?;
+				let token_id = input.u64_at(3)?;
+				let data = if action == Action::SafeTransferFromWithData {
+					input.bytes_at(4)?
+				} else {
+					vec![]
+				};
+
+				log::debug!(target: "evm", "erc721: class_id: {:?}, transfer_from caller: {:?}, from: {:?}, to: {:?}, token_id: {:?}", class_id, caller, from, to, token_id);
+
+				let spender = Runtime::AddressMapping::get_account_id(&caller);
+				let owner = Self::owner_of(class_id, token_id)?;
+				if Self::evm_address(&owner) != from {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "transfer from incorrect owner".into(),
+					});
+				}
+				if to.is_zero() {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "transfer to the zero address".into(),
+					});
+				}
+				if !module_nft::Pallet::<Runtime>::is_approved_or_owner(&spender, (class_id, token_id)) {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "caller is not token owner or approved".into(),
+					});
+				}
+
+				module_nft::Pallet::<Runtime>::do_transfer(
+					&owner,
+					&Runtime::AddressMapping::get_account_id(&to),
+					(class_id, token_id),
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("ERC721 Transfer failed", e),
+				})?;
+
+				handle.log(
+					handle.code_address(),
+					vec![
+						ERC20_TRANSFER_TOPIC,
+						from.into(),
+						to.into(),
+						H256::from_low_u64_be(token_id),
+					],
+					vec![],
+				)?;
+
+				if action != Action::TransferFrom && module_evm::Pallet::<Runtime>::is_contract(&to) {
+					Self::check_on_erc721_received(handle, caller, from, to, token_id, data)?;
+				}
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::SupportsInterface => {
+				let interface_id = u32::from_be_bytes(
+					input.bytes32_at(1)?[..4]
+						.try_into()
+						.expect("bytes32 has 4 bytes at least; qed"),
+				);
+				let supported = matches!(
+					interface_id,
+					INTERFACE_ID_ERC165 | INTERFACE_ID_ERC721 | INTERFACE_ID_ERC721_METADATA
+				);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bool(supported),
+				})
+			}
+		}
+	}
+}
+
+impl<Runtime> Erc721Precompile<Runtime>
+where
+	Runtime: module_evm::Config + module_nft::Config + orml_nft::Config<ClassId = u32, TokenId = u64>,
+{
+	fn owner_of(class_id: u32, token_id: u64) -> Result<Runtime::AccountId, PrecompileFailure> {
+		orml_nft::Pallet::<Runtime>::tokens(class_id, token_id)
+			.map(|token_info| token_info.owner)
+			.ok_or_else(|| PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "nft token not found".into(),
+			})
+	}
+
+	fn evm_address(who: &Runtime::AccountId) -> H160 {
+		Runtime::AddressMapping::get_evm_address(who)
+			.unwrap_or_else(|| Runtime::AddressMapping::get_default_evm_address(who))
+	}
+
+	// Ensure the receiver contract implements `IERC721Receiver.onERC721Received`.
+	fn check_on_erc721_received(
+		handle: &mut impl PrecompileHandle,
+		operator: H160,
+		from: H160,
+		to: H160,
+		token_id: u64,
+		data: Vec<u8>,
+	) -> Result<(), PrecompileFailure> {
+		let mut input = ON_ERC721_RECEIVED_SELECTOR.to_vec();
+		input.extend_from_slice(&ethabi::encode(&[
+			ethabi::Token::Address(operator),
+			ethabi::Token::Address(from),
+			ethabi::Token::Uint(U256::from(token_id)),
+			ethabi::Token::Bytes(data),
+		]));
+
+		let context = Context {
+			address: to,
+			caller: handle.code_address(),
+			apparent_value: U256::zero(),
+		};
+		let (reason, output) = handle.call(to, None, input, None, false, &context);
+
+		match reason {
+			ExitReason::Succeed(_) if output.get(..4) == Some(&ON_ERC721_RECEIVED_SELECTOR[..]) => Ok(()),
+			_ => Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "transfer to non ERC721Receiver implementer".into(),
+			}),
+		}
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config + module_nft::Config,
+{
+	const BASE_COST: u64 = 200;
+	// LOG4 without data: 375 + 4 * 375
+	const ERC721_LOG_COST: u64 = 1875;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+		let action = input.action()?;
+
+		// OrmlNFT::Classes (r: 1)
+		let read_class = WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+
+		let cost = match action {
+			Action::QueryTotalSupply | Action::SupportsInterface => 0,
+			Action::QueryBalance => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// NFT::ClassBalances (r: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
+			Action::QueryOwner | Action::QueryTokenURI => {
+				// OrmlNFT::Tokens (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::QueryApproved => {
+				// OrmlNFT::Tokens, NFT::TokenApprovals (r: 2)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+			}
+			Action::QueryApprovedForAll => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// NFT::OperatorApprovals (r: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
+			Action::Approve => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// OrmlNFT::Tokens, NFT::OperatorApprovals (r: 2)
+				// NFT::TokenApprovals (w: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 1),
+				))
+				.saturating_add(Self::ERC721_LOG_COST)
+			}
+			Action::SetApprovalForAll => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// NFT::OperatorApprovals (w: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().writes(1),
+				))
+				.saturating_add(Self::ERC721_LOG_COST)
+			}
+			Action::TransferFrom | Action::SafeTransferFrom | Action::SafeTransferFromWithData => {
+				let cost = InputPricer::<Runtime>::read_accounts(3);
+				// OrmlNFT::Tokens, NFT::TokenApprovals, NFT::OperatorApprovals (r: 3)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(3),
+				))
+				.saturating_add(WeightToGas::convert(
+					<Runtime as module_nft::Config>::WeightInfo::transfer(),
+				))
+				.saturating_add(Self::ERC721_LOG_COST)
+			}
+		};
+
+		Ok(Self::BASE_COST.saturating_add(read_class).saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, bob, bob_evm_addr, new_test_ext, Balances, NFTModule, RuntimeOrigin, Test,
+	};
+	use frame_support::{assert_noop, assert_ok};
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use primitives::{
+		evm::{nft_class_address, Log},
+		nft::{ClassProperty, Properties},
+	};
+
+	type Erc721Precompile = super::Erc721Precompile<Test>;
+
+	fn setup_class() {
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(alice()),
+			b"class".to_vec(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		let class_owner = orml_nft::Pallet::<Test>::classes(0).unwrap().owner;
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			class_owner.clone(),
+			1_000_000_000
+		));
+		assert_ok!(NFTModule::mint(
+			RuntimeOrigin::signed(class_owner),
+			alice(),
+			0,
+			b"ipfs://token".to_vec(),
+			Default::default(),
+			2
+		));
+	}
+
+	fn execute(input: &[u8], caller: H160, is_static: bool) -> (PrecompileResult, Vec<Log>) {
+		let context = Context {
+			address: nft_class_address(0),
+			caller,
+			apparent_value: Default::default(),
+		};
+		let mut handle = MockPrecompileHandle::new(input, None, &context, is_static);
+		handle.code_address = nft_class_address(0);
+		let result = Erc721Precompile::execute(&mut handle);
+		(result, handle.logs)
+	}
+
+	#[test]
+	fn query_works() {
+		new_test_ext().execute_with(|| {
+			// balanceOf(address) -> 0x70a08231
+			// owner
+			let input = hex! {"
+				70a08231
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			assert_noop!(
+				execute(&input, alice_evm_addr(), false).0,
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "nft class not found".into(),
+				}
+			);
+
+			setup_class();
+
+			let (result, _) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().output, Output::encode_uint(2u128));
+
+			// totalSupply() -> 0x18160ddd
+			let (result, _) = execute(&hex!("18160ddd"), alice_evm_addr(), false);
+			assert_eq!(result.unwrap().output, Output::encode_uint(2u64));
+
+			// ownerOf(uint256) -> 0x6352211e
+			// token_id
+			let input = hex! {"
+				6352211e
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let (result, _) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().output, Output::encode_address(alice_evm_addr()));
+
+			// tokenURI(uint256) -> 0xc87b56dd
+			// token_id
+			let input = hex! {"
+				c87b56dd
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let (result, _) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().output, Output::encode_bytes(b"ipfs://token"));
+
+			// ownerOf(uint256) -> 0x6352211e
+			// token_id
+			let input = hex! {"
+				6352211e
+				00000000000000000000000000000000 00000000000000000000000000000002
+			"};
+			assert_noop!(
+				execute(&input, alice_evm_addr(), false).0,
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "nft token not found".into(),
+				}
+			);
+
+			// supportsInterface(bytes4) -> 0x01ffc9a7
+			// interface_id
+			let input = hex! {"
+				01ffc9a7
+				80ac58cd00000000000000000000000000000000000000000000000000000000
+			"};
+			let (result, _) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().output, Output::encode_bool(true));
+
+			let input = hex! {"
+				01ffc9a7
+				ffffffff00000000000000000000000000000000000000000000000000000000
+			"};
+			let (result, _) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().output, Output::encode_bool(false));
+		});
+	}
+
+	#[test]
+	fn approve_and_transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			setup_class();
+
+			// transferFrom(address,address,uint256) -> 0x23b872dd
+			// from
+			// to
+			// token_id
+			let transfer_input = hex! {"
+				23b872dd
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_noop!(
+				execute(&transfer_input, bob_evm_addr(), false).0,
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "caller is not token owner or approved".into(),
+				}
+			);
+
+			// approve(address,uint256) -> 0x095ea7b3
+			// approved
+			// token_id
+			let input = hex! {"
+				095ea7b3
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_noop!(
+				execute(&input, alice_evm_addr(), true).0,
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "cannot be called with STATICCALL".into(),
+				}
+			);
+			let (result, logs) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				logs,
+				vec![Log {
+					address: nft_class_address(0),
+					topics: vec![
+						ERC721_APPROVAL_TOPIC,
+						alice_evm_addr().into(),
+						bob_evm_addr().into(),
+						H256::zero(),
+					],
+					data: vec![],
+				}]
+			);
+
+			// getApproved(uint256) -> 0x081812fc
+			// token_id
+			let input = hex! {"
+				081812fc
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let (result, _) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().output, Output::encode_address(bob_evm_addr()));
+
+			let (result, logs) = execute(&transfer_input, bob_evm_addr(), false);
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				logs,
+				vec![Log {
+					address: nft_class_address(0),
+					topics: vec![
+						ERC20_TRANSFER_TOPIC,
+						alice_evm_addr().into(),
+						bob_evm_addr().into(),
+						H256::zero(),
+					],
+					data: vec![],
+				}]
+			);
+			assert_eq!(orml_nft::Pallet::<Test>::tokens(0, 0).unwrap().owner, bob());
+			assert_eq!(NFTModule::token_approvals(0, 0), None);
+
+			// transfer from incorrect owner
+			assert_noop!(
+				execute(&transfer_input, bob_evm_addr(), false).0,
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "transfer from incorrect owner".into(),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn set_approval_for_all_works() {
+		new_test_ext().execute_with(|| {
+			setup_class();
+
+			// setApprovalForAll(address,bool) -> 0xa22cb465
+			// operator
+			// approved
+			let input = hex! {"
+				a22cb465
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let (result, logs) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				logs,
+				vec![Log {
+					address: nft_class_address(0),
+					topics: vec![
+						ERC721_APPROVAL_FOR_ALL_TOPIC,
+						alice_evm_addr().into(),
+						bob_evm_addr().into(),
+					],
+					data: Output::encode_bool(true),
+				}]
+			);
+
+			// isApprovedForAll(address,address) -> 0xe985e9c5
+			// owner
+			// operator
+			let input = hex! {"
+				e985e9c5
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+			let (result, _) = execute(&input, alice_evm_addr(), false);
+			assert_eq!(result.unwrap().output, Output::encode_bool(true));
+
+			// safeTransferFrom(address,address,uint256) -> 0x42842e0e
+			// from
+			// to
+			// token_id
+			let input = hex! {"
+				42842e0e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let (result, _) = execute(&input, bob_evm_addr(), false);
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_eq!(orml_nft::Pallet::<Test>::tokens(0, 1).unwrap().owner, bob());
+			assert_eq!(NFTModule::class_balance(&alice(), 0), 1);
+		});
+	}
+}
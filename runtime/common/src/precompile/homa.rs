@@ -18,6 +18,7 @@
 
 use super::input::{Input, InputPricer, InputT, Output};
 use crate::WeightToGas;
+use ethabi::Token;
 use frame_support::traits::Get;
 use module_evm::{
 	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
@@ -28,6 +29,7 @@ use module_support::HomaManager;
 use module_homa::WeightInfo;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::Balance;
+use sp_core::U256;
 use sp_runtime::{traits::Convert, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -42,6 +44,13 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Get estimated reward rate.
 /// - Get commission rate.
 /// - Get fast match fee.
+/// - Claim redemption. Rest `input` bytes: `redeemer`.
+/// - Fast match redeems. Rest `input` bytes: `redeemer_list`.
+/// - Get redeem request. Rest `input` bytes: `redeemer`.
+/// - Get unbonding. Rest `input` bytes: `redeemer`, `era_index`.
+/// - Get relay chain current era.
+/// - Get total staking bonded.
+/// - Get staking currency soft cap.
 pub struct HomaPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -54,6 +63,13 @@ pub enum Action {
 	GetEstimatedRewardRate = "getEstimatedRewardRate()",
 	GetCommissionRate = "getCommissionRate()",
 	GetFastMatchFee = "getFastMatchFee()",
+	ClaimRedemption = "claimRedemption(address)",
+	FastMatchRedeems = "fastMatchRedeems(address[])",
+	GetRedeemRequest = "getRedeemRequest(address)",
+	GetUnbonding = "getUnbonding(address,uint32)",
+	GetRelayChainCurrentEra = "getRelayChainCurrentEra()",
+	GetTotalStakingBonded = "getTotalStakingBonded()",
+	GetStakingCurrencySoftCap = "getStakingCurrencySoftCap()",
 }
 
 impl<Runtime> Precompile for HomaPrecompile<Runtime>
@@ -152,6 +168,99 @@ where
 					output: Output::encode_uint(rate.into_inner()),
 				})
 			}
+			Action::ClaimRedemption => {
+				let redeemer = input.account_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"homa: claim_redemption, redeemer: {:?}",
+					&redeemer
+				);
+
+				module_homa::Pallet::<Runtime>::do_claim_redemption(redeemer).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Homa ClaimRedemption failed", e),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::FastMatchRedeems => {
+				// solidity abi encode array will add an offset at input[1]
+				let redeemer_len = input.u32_at(2)?;
+				let mut redeemer_list = vec![];
+				for i in 0..redeemer_len {
+					redeemer_list.push(input.account_id_at((3 + i) as usize)?);
+				}
+
+				log::debug!(
+					target: "evm",
+					"homa: fast_match_redeems, redeemer_list: {:?}",
+					&redeemer_list
+				);
+
+				for redeemer in redeemer_list {
+					module_homa::Pallet::<Runtime>::do_fast_match_redeem(&redeemer, true).map_err(|e| {
+						PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("Homa FastMatchRedeems failed", e),
+						}
+					})?;
+				}
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::GetRedeemRequest => {
+				let redeemer = input.account_id_at(1)?;
+				let (amount, allow_fast_match) =
+					module_homa::Pallet::<Runtime>::redeem_requests(&redeemer).unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: ethabi::encode(&[Token::Uint(U256::from(amount)), Token::Bool(allow_fast_match)]),
+				})
+			}
+			Action::GetUnbonding => {
+				let redeemer = input.account_id_at(1)?;
+				let era_index = input.u32_at(2)?;
+				let unbonding = module_homa::Pallet::<Runtime>::unbondings(&redeemer, era_index);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(unbonding),
+				})
+			}
+			Action::GetRelayChainCurrentEra => {
+				let era_index = module_homa::Pallet::<Runtime>::relay_chain_current_era();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(era_index),
+				})
+			}
+			Action::GetTotalStakingBonded => {
+				let total_bonded = module_homa::Pallet::<Runtime>::get_total_bonded();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(total_bonded),
+				})
+			}
+			Action::GetStakingCurrencySoftCap => {
+				let soft_cap = module_homa::Pallet::<Runtime>::get_staking_currency_soft_cap();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(soft_cap),
+				})
+			}
 		}
 	}
 }
@@ -202,6 +311,45 @@ where
 				// Homa::FastMatchFeeRate (r: 1)
 				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
 			}
+			Action::ClaimRedemption => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_homa::Config>::WeightInfo::claim_redemption();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::FastMatchRedeems => {
+				let redeemer_len = input.u32_at(2)?;
+				let cost = InputPricer::<Runtime>::read_accounts(redeemer_len.into());
+				let weight = <Runtime as module_homa::Config>::WeightInfo::fast_match_redeems(redeemer_len);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetRedeemRequest => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Homa::RedeemRequests (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetUnbonding => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Homa::Unbondings (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetRelayChainCurrentEra => {
+				// Homa::RelayChainCurrentEra (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetTotalStakingBonded => {
+				// Homa::TotalStakingBonded (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetStakingCurrencySoftCap => {
+				// Homa::SoftBondedCapPerSubAccount (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
 		};
 		Ok(Self::BASE_COST.saturating_add(cost))
 	}
@@ -212,10 +360,12 @@ mod tests {
 	use super::*;
 	use crate::precompile::mock::{
 		alice, alice_evm_addr, new_test_ext, Currencies, Homa, HomaAdmin, RuntimeOrigin, StakingCurrencyId, Test, ACA,
+		LDOT,
 	};
 	use frame_support::assert_ok;
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use orml_traits::MultiCurrency;
 	use sp_runtime::{FixedPointNumber, FixedU128};
 
 	type HomaPrecompile = super::HomaPrecompile<Test>;
@@ -430,4 +580,156 @@ mod tests {
 			assert_eq!(res.output, expected_output);
 		});
 	}
+
+	#[test]
+	fn claim_redemption_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(Homa::reset_current_era(RuntimeOrigin::signed(HomaAdmin::get()), 2));
+			module_homa::Unbondings::<Test>::insert(alice(), 1, 1_000);
+			module_homa::UnclaimedRedemption::<Test>::put(1_000);
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				Homa::account_id(),
+				StakingCurrencyId::get(),
+				1_000
+			));
+
+			// getRelayChainCurrentEra() -> 0x5ca00723
+			let input = hex! {"5ca00723"};
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.output, Output::encode_uint(2u32));
+
+			// getUnbonding(address,uint32) -> 0x1f7b2465
+			// redeemer
+			// era_index
+			let input = hex! {"
+				1f7b2465
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.output, Output::encode_uint(1_000u128));
+
+			let staking_balance = Currencies::free_balance(StakingCurrencyId::get(), &alice());
+
+			// claimRedemption(address) -> 0xc0598b0d
+			// redeemer
+			let input = hex! {"
+				c0598b0d
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				Currencies::free_balance(StakingCurrencyId::get(), &alice()),
+				staking_balance + 1_000
+			);
+			assert_eq!(Homa::unbondings(alice(), 1), 0);
+		});
+	}
+
+	#[test]
+	fn fast_match_redeems_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000_000_000),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				None,
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				StakingCurrencyId::get(),
+				1_000_000_000_000
+			));
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(alice()), 1_000_000_000));
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(alice()), 1_000_000, true));
+
+			// getRedeemRequest(address) -> 0x3c05e18f
+			// redeemer
+			let input = hex! {"
+				3c05e18f
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(
+				res.output,
+				hex! {"
+					00000000000000000000000000000000 000000000000000000000000000f4240
+					00000000000000000000000000000000 00000000000000000000000000000001
+				"}
+				.to_vec()
+			);
+
+			let liquid_balance = Currencies::total_issuance(LDOT);
+
+			// fastMatchRedeems(address[]) -> 0xf3d71053
+			// offset
+			// redeemer_list len
+			// redeemer
+			let fast_match_input = hex! {"
+				f3d71053
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&fast_match_input, None, &context, false))
+				.unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Currencies::total_issuance(LDOT), liquid_balance - 1_000_000);
+
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(
+				res.output,
+				[Output::encode_uint(0u128), Output::encode_uint(0u128)].concat()
+			);
+		});
+	}
+
+	#[test]
+	fn get_staking_status_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000),
+				None,
+				None,
+				None,
+				None,
+			));
+			module_homa::TotalStakingBonded::<Test>::put(5_000_000);
+
+			// getTotalStakingBonded() -> 0x5f9d043f
+			let input = hex! {"5f9d043f"};
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.output, Output::encode_uint(5_000_000u128));
+
+			// getStakingCurrencySoftCap() -> 0x613a116c
+			// 3 active sub accounts
+			let input = hex! {"613a116c"};
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.output, Output::encode_uint(3_000_000u128));
+		});
+	}
 }
@@ -41,6 +41,18 @@ use sp_std::{marker::PhantomData, prelude::*};
 ///  - Get position. `input` bytes: `who`, `currency_id`.
 ///  - Get liquidation ratio. `input` bytes: `currency_id`.
 ///  - Get current collateral ratio. `input` bytes: `who`, `currency_id`.
+///  - Get debit exchange rate. `input` bytes: `currency_id`.
+///  - Authorize. `input` bytes: `who`, `currency_id`, `to`.
+///  - Unauthorize. `input` bytes: `who`, `currency_id`, `to`.
+///  - Unauthorize all. `input` bytes: `who`.
+///  - Transfer loan from. `input` bytes: `who`, `currency_id`, `from`.
+///  - Expand position collateral. `input` bytes: `who`, `currency_id`, `increase_debit_value`,
+///    `min_increase_collateral`.
+///  - Shrink position debit. `input` bytes: `who`, `currency_id`, `decrease_collateral`,
+///    `min_decrease_debit_value`.
+///  - Adjust loan by debit value. `input` bytes: `who`, `currency_id`, `collateral_adjustment`,
+///    `debit_value_adjustment`.
+///  - Transfer debit. `input` bytes: `who`, `from_currency`, `to_currency`, `debit_transfer`.
 pub struct HonzonPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -53,6 +65,14 @@ pub enum Action {
 	GetCollateralParameters = "getCollateralParameters(address)",
 	GetCurrentCollateralRatio = "getCurrentCollateralRatio(address,address)",
 	GetDebitExchangeRate = "getDebitExchangeRate(address)",
+	Authorize = "authorize(address,address,address)",
+	Unauthorize = "unauthorize(address,address,address)",
+	UnauthorizeAll = "unauthorizeAll(address)",
+	TransferLoanFrom = "transferLoanFrom(address,address,address)",
+	ExpandPositionCollateral = "expandPositionCollateral(address,address,uint256,uint256)",
+	ShrinkPositionDebit = "shrinkPositionDebit(address,address,uint256,uint256)",
+	AdjustLoanByDebitValue = "adjustLoanByDebitValue(address,address,int128,int128)",
+	TransferDebit = "transferDebit(address,address,address,uint256)",
 }
 
 impl<Runtime> Precompile for HonzonPrecompile<Runtime>
@@ -188,6 +208,227 @@ where
 					output: Output::encode_uint(exchange_rate.into_inner()),
 				})
 			}
+			Action::Authorize => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let to = input.account_id_at(3)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: authorize who: {:?}, currency_id: {:?}, to: {:?}",
+					who, currency_id, to
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::authorize(&who, currency_id, &to).map_err(|e|
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Honzon Authorize failed", e),
+					}
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::Unauthorize => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let to = input.account_id_at(3)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: unauthorize who: {:?}, currency_id: {:?}, to: {:?}",
+					who, currency_id, to
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::unauthorize(&who, currency_id, &to).map_err(|e|
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Honzon Unauthorize failed", e),
+					}
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::UnauthorizeAll => {
+				let who = input.account_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: unauthorize_all who: {:?}",
+					who
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::unauthorize_all(&who);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::TransferLoanFrom => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let from = input.account_id_at(3)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: transfer_loan_from who: {:?}, currency_id: {:?}, from: {:?}",
+					who, currency_id, from
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::transfer_loan_from(&from, &who, currency_id).map_err(|e|
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Honzon TransferLoanFrom failed", e),
+					}
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::ExpandPositionCollateral => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let increase_debit_value = input.balance_at(3)?;
+				let min_increase_collateral = input.balance_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: expand_position_collateral who: {:?}, currency_id: {:?}, increase_debit_value: {:?}, min_increase_collateral: {:?}",
+					who, currency_id, increase_debit_value, min_increase_collateral
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::expand_position_collateral(&who, currency_id, increase_debit_value, min_increase_collateral).map_err(|e|
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Honzon ExpandPositionCollateral failed", e),
+					}
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::ShrinkPositionDebit => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let decrease_collateral = input.balance_at(3)?;
+				let min_decrease_debit_value = input.balance_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: shrink_position_debit who: {:?}, currency_id: {:?}, decrease_collateral: {:?}, min_decrease_debit_value: {:?}",
+					who, currency_id, decrease_collateral, min_decrease_debit_value
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::shrink_position_debit(&who, currency_id, decrease_collateral, min_decrease_debit_value).map_err(|e|
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Honzon ShrinkPositionDebit failed", e),
+					}
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::AdjustLoanByDebitValue => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let collateral_adjustment = input.i128_at(3)?;
+				let debit_value_adjustment = input.i128_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: adjust_loan_by_debit_value who: {:?}, currency_id: {:?}, collateral_adjustment: {:?}, debit_value_adjustment: {:?}",
+					who, currency_id, collateral_adjustment, debit_value_adjustment
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::adjust_loan_by_debit_value(&who, currency_id, collateral_adjustment, debit_value_adjustment).map_err(|e|
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Honzon AdjustLoanByDebitValue failed", e),
+					}
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::TransferDebit => {
+				let who = input.account_id_at(1)?;
+				let from_currency = input.currency_id_at(2)?;
+				let to_currency = input.currency_id_at(3)?;
+				let debit_transfer = input.balance_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: transfer_debit who: {:?}, from_currency: {:?}, to_currency: {:?}, debit_transfer: {:?}",
+					who, from_currency, to_currency, debit_transfer
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::transfer_debit(&who, from_currency, to_currency, debit_transfer).map_err(|e|
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Honzon TransferDebit failed", e),
+					}
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
 		}
 	}
 }
@@ -271,6 +512,103 @@ where
 					.saturating_add(read_currency)
 					.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::Authorize => {
+				let read_account = InputPricer::<Runtime>::read_accounts(2);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::authorize();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Unauthorize => {
+				let read_account = InputPricer::<Runtime>::read_accounts(2);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::unauthorize();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::UnauthorizeAll => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::unauthorize_all(
+					<Runtime as module_honzon::Config>::CollateralCurrencyIds::get().len() as u32,
+				);
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::TransferLoanFrom => {
+				let read_account = InputPricer::<Runtime>::read_accounts(2);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::transfer_loan_from();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::ExpandPositionCollateral => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::expand_position_collateral();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::ShrinkPositionDebit => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::shrink_position_debit();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::AdjustLoanByDebitValue => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::adjust_loan();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::TransferDebit => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let from_currency = input.currency_id_at(2)?;
+				let to_currency = input.currency_id_at(3)?;
+				let read_currency =
+					InputPricer::<Runtime>::read_currency(from_currency)
+						.saturating_add(InputPricer::<Runtime>::read_currency(to_currency));
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::transfer_debit();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
 		};
 		Ok(cost)
 	}
@@ -281,10 +619,10 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		alice, alice_evm_addr, new_test_ext, CDPEngine, Currencies, DexModule, Honzon, Loans, One, RuntimeOrigin, Test,
-		AUSD, BOB, DOT,
+		alice, alice_evm_addr, bob, bob_evm_addr, new_test_ext, CDPEngine, Currencies, DexModule, Honzon, Loans, One,
+		RuntimeOrigin, Test, AUSD, BOB, DOT,
 	};
-	use frame_support::assert_ok;
+	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
 	use module_support::{Rate, Ratio};
@@ -598,4 +936,147 @@ mod tests {
 			assert_eq!(res.output, expected_output.to_vec());
 		})
 	}
+
+	#[test]
+	fn authorize_and_transfer_loan_from_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(CDPEngine::set_collateral_params(
+				RuntimeOrigin::signed(One::get()),
+				DOT,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(1_000_000_000)
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				DOT,
+				1_000_000_000_000
+			));
+			assert_ok!(Honzon::adjust_loan(
+				RuntimeOrigin::signed(alice()),
+				DOT,
+				100_000_000_000,
+				1_000_000
+			));
+
+			let alice_context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			let bob_context = Context {
+				address: Default::default(),
+				caller: bob_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// transferLoanFrom(address,address,address) => 0xca5f04da
+			// who
+			// currency_id
+			// from
+			let transfer_loan_input = hex! {"
+				ca5f04da
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			assert_noop!(
+				HonzonPrecompile::execute(&mut MockPrecompileHandle::new(
+					&transfer_loan_input,
+					None,
+					&bob_context,
+					false
+				)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Honzon TransferLoanFrom failed: NoPermission".into(),
+				}
+			);
+
+			// authorize(address,address,address) => 0xc4b2e400
+			// who
+			// currency_id
+			// to
+			let input = hex! {"
+				c4b2e400
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+			let res =
+				HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &alice_context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert!(Honzon::authorization(alice(), (DOT, bob())).is_some());
+
+			let res = HonzonPrecompile::execute(&mut MockPrecompileHandle::new(
+				&transfer_loan_input,
+				None,
+				&bob_context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Loans::positions(DOT, alice()).collateral, 0);
+			assert_eq!(Loans::positions(DOT, bob()).collateral, 100_000_000_000);
+			assert_eq!(Loans::positions(DOT, bob()).debit, 1_000_000);
+
+			// unauthorizeAll(address) => 0xda16d66d
+			// who
+			let input = hex! {"
+				da16d66d
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			let res =
+				HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &alice_context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert!(Honzon::authorization(alice(), (DOT, bob())).is_none());
+		});
+	}
+
+	#[test]
+	fn adjust_loan_by_debit_value_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(CDPEngine::set_collateral_params(
+				RuntimeOrigin::signed(One::get()),
+				DOT,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(10000)
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				DOT,
+				1_000_000_000_000
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			// adjustLoanByDebitValue(address,address,int128,int128) => 0x11b2c4c9
+			// who
+			// currency_id
+			// collateral_adjustment
+			// debit_value_adjustment
+			let input = hex! {"
+				11b2c4c9
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000000010000000
+				00000000000000000000000000000000 00000000000000000000000000001000
+			"};
+
+			let res = HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Loans::positions(DOT, alice()).collateral, 268435456);
+			assert_eq!(Loans::positions(DOT, alice()).debit, 4096);
+		});
+	}
 }
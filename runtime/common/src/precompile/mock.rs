@@ -22,7 +22,7 @@ use crate::{AllPrecompiles, Ratio, RuntimeBlockWeights, Weight};
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, EqualPrivilegeOnly, Everything, ExistenceRequirement, InstanceFilter,
+		ConstU128, ConstU32, ConstU64, Contains, EqualPrivilegeOnly, Everything, ExistenceRequirement, InstanceFilter,
 		LockIdentifier, Nothing, OnFinalize, OnInitialize, SortedMembers,
 	},
 	weights::{ConstantMultiplier, IdentityFee},
@@ -36,7 +36,7 @@ use module_support::{
 	mocks::{MockStableAsset, TestRandomness},
 	AddressMapping as AddressMappingT, AuctionManager, DEXIncentives, DispatchableTask, EmergencyShutdown,
 	ExchangeRate, ExchangeRateProvider, FractionalRate, HomaSubAccountXcm, PoolId, PriceProvider, Rate,
-	SpecificJointsSwap,
+	SpecificJointsSwap, XcmTransact,
 };
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key, MultiCurrency, MultiReservableCurrency};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -184,6 +184,7 @@ impl module_currencies::Config for Test {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = ();
+	type Erc20TransferLog = module_evm::Erc20TransferLogger<Test, EvmErc20InfoMapping>;
 }
 
 impl module_evm_bridge::Config for Test {
@@ -556,6 +557,15 @@ impl nutsfinance_stable_asset::Config for Test {
 	type EnsurePoolAssetId = EnsurePoolAssetId;
 }
 
+impl module_aggregated_dex::Config for Test {
+	type DEX = DexModule;
+	type StableAsset = StableAsset;
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type DexSwapJointList = AlternativeSwapPathJointList;
+	type SwapPathLimit = ConstU32<3>;
+	type WeightInfo = ();
+}
+
 impl module_transaction_pause::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
@@ -592,6 +602,8 @@ impl module_evm::Config for Test {
 	type NewContractExtraBytes = ConstU32<100>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<10>;
+	type MinBaseFeePerGas = ConstU128<10>;
+	type BaseFeeGasTarget = ConstU64<15_000_000>;
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType = AllPrecompiles<Self, module_transaction_pause::PausedPrecompileFilter<Self>, ()>;
 	type PrecompilesValue = PrecompilesValue;
@@ -611,6 +623,43 @@ impl module_evm::Config for Test {
 	type WeightInfo = ();
 }
 
+pub struct EvmDispatchCallFilter;
+impl Contains<RuntimeCall> for EvmDispatchCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
+				| RuntimeCall::Utility(pallet_utility::Call::batch_all { .. })
+				| RuntimeCall::Proxy(pallet_proxy::Call::add_proxy { .. })
+		)
+	}
+}
+
+impl crate::precompile::dispatch::DispatchPrecompileConfig for Test {
+	type CallFilter = EvmDispatchCallFilter;
+}
+
+parameter_types! {
+	/// The arguments of the `MockXcmTransact` calls.
+	pub storage XcmTransacts: Vec<(AccountId, Location, Vec<u8>, Asset, Weight)> = vec![];
+}
+
+/// mock XCM transact, records its arguments.
+pub struct MockXcmTransact;
+impl XcmTransact<AccountId> for MockXcmTransact {
+	fn transact(sender: &AccountId, dest: Location, call: Vec<u8>, fee: Asset, weight: Weight) -> DispatchResult {
+		let mut transacts = XcmTransacts::get();
+		transacts.push((sender.clone(), dest, call, fee, weight));
+		XcmTransacts::set(&transacts);
+		Ok(())
+	}
+}
+
+impl crate::precompile::xcm_transact::XcmTransactPrecompileConfig for Test {
+	type XcmTransact = MockXcmTransact;
+	type XcmWeightInfo = pallet_xcm::TestWeightInfo;
+}
+
 impl module_evm_accounts::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -659,6 +708,13 @@ ord_parameter_types! {
 	pub const One: AccountId = AccountId::new([1u8; 32]);
 }
 
+impl module_dex_oracle::Config for Test {
+	type DEX = DexModule;
+	type Time = Timestamp;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
 impl module_prices::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = Oracle;
@@ -985,6 +1041,27 @@ impl module_earning::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const NomineesElectionId: LockIdentifier = *b"aca/nome";
+}
+
+impl module_nominees_election::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = module_currencies::Currency<Test, GetLiquidCurrencyId>;
+	type NomineeId = AccountId;
+	type PalletId = NomineesElectionId;
+	type MinBond = ConstU128<1_000>;
+	type BondingDuration = BondingDuration;
+	type MaxNominateesCount = ConstU32<5>;
+	type MaxUnbondingChunks = ConstU32<3>;
+	type NomineeFilter = Everything;
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type OnBonded = module_incentives::OnNomineesElectionBonded<Test>;
+	type OnUnbonded = module_incentives::OnNomineesElectionUnbonded<Test>;
+	type CurrentEra = Homa;
+	type WeightInfo = ();
+}
+
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
 pub const BOB: AccountId = AccountId::new([2u8; 32]);
 pub const EVA: AccountId = AccountId::new([5u8; 32]);
@@ -1044,6 +1121,7 @@ frame_support::construct_runtime!(
 		EVMBridge: module_evm_bridge exclude_parts { Call },
 		AssetRegistry: module_asset_registry,
 		NFTModule: module_nft,
+		OrmlNFT: orml_nft,
 		TransactionPause: module_transaction_pause,
 		TransactionPayment: module_transaction_payment,
 		Prices: module_prices,
@@ -1051,6 +1129,7 @@ frame_support::construct_runtime!(
 		Utility: pallet_utility,
 		Scheduler: pallet_scheduler,
 		DexModule: module_dex,
+		DexOracle: module_dex_oracle,
 		EVMModule: module_evm,
 		EvmAccounts: module_evm_accounts,
 		IdleScheduler: module_idle_scheduler,
@@ -1059,8 +1138,10 @@ frame_support::construct_runtime!(
 		Rewards: orml_rewards,
 		XTokens: orml_xtokens,
 		StableAsset: nutsfinance_stable_asset,
+		AggregatedDex: module_aggregated_dex,
 		LiquidCrowdloan: module_liquid_crowdloan,
 		Earning: module_earning,
+		NomineesElection: module_nominees_election,
 	}
 );
 
@@ -18,6 +18,7 @@
 
 //! The precompiles for EVM, includes standard Ethereum precompiles, and more:
 //! - MultiCurrency at address `H160::from_low_u64_be(1024)`.
+//! - ERC721 of the NFT classes at address `primitives::evm::nft_class_address(class_id)`.
 
 #![allow(clippy::upper_case_acronyms)]
 
@@ -28,18 +29,25 @@ mod weights;
 use hex_literal::hex;
 use module_evm::{
 	precompiles::{
-		Blake2F, Bn128Add, Bn128Mul, Bn128Pairing, ECRecover, ECRecoverPublicKey, Identity, IstanbulModexp, Modexp,
-		Precompile, Ripemd160, Sha256, Sha3FIPS256, Sha3FIPS512,
+		Blake2F, Bls12381G1Add, Bls12381G1Msm, Bls12381G2Add, Bls12381G2Msm, Bls12381MapG1, Bls12381MapG2,
+		Bls12381Pairing, Bn128Add, Bn128Mul, Bn128Pairing, ECRecover, ECRecoverPublicKey, Identity, IstanbulModexp,
+		Modexp, P256Verify, Precompile, Ripemd160, Sha256, Sha3FIPS256, Sha3FIPS512,
 	},
 	ExitRevert, IsPrecompileResult, PrecompileFailure, PrecompileHandle, PrecompileResult, PrecompileSet,
 };
 use module_support::{PrecompileCallerFilter, PrecompilePauseFilter};
+use primitives::evm::nft_class_id;
 use sp_core::H160;
 use sp_runtime::traits::Zero;
 use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData};
 
+pub mod aggregated_dex;
+pub mod asset_registry;
+pub mod batch;
 pub mod dex;
+pub mod dispatch;
 pub mod earning;
+pub mod erc721;
 pub mod evm;
 pub mod evm_accounts;
 pub mod homa;
@@ -49,14 +57,21 @@ pub mod input;
 pub mod liquid_crowdloan;
 pub mod multicurrency;
 pub mod nft;
+pub mod nominees_election;
 pub mod oracle;
 pub mod schedule;
 pub mod stable_asset;
+pub mod xcm_transact;
 pub mod xtokens;
 
 use crate::SystemContractsFilter;
+pub use aggregated_dex::AggregatedDexPrecompile;
+pub use asset_registry::AssetRegistryPrecompile;
+pub use batch::BatchPrecompile;
 pub use dex::DEXPrecompile;
+pub use dispatch::DispatchPrecompile;
 pub use earning::EarningPrecompile;
+pub use erc721::Erc721Precompile;
 pub use evm::EVMPrecompile;
 pub use evm_accounts::EVMAccountsPrecompile;
 pub use homa::HomaPrecompile;
@@ -65,9 +80,11 @@ pub use incentives::IncentivesPrecompile;
 pub use liquid_crowdloan::LiquidCrowdloanPrecompile;
 pub use multicurrency::MultiCurrencyPrecompile;
 pub use nft::NFTPrecompile;
+pub use nominees_election::NomineesElectionPrecompile;
 pub use oracle::OraclePrecompile;
 pub use schedule::SchedulePrecompile;
 pub use stable_asset::StableAssetPrecompile;
+pub use xcm_transact::XcmTransactPrecompile;
 pub use xtokens::XtokensPrecompile;
 
 pub const ECRECOVER: H160 = H160(hex!("0000000000000000000000000000000000000001"));
@@ -79,13 +96,24 @@ pub const BN_ADD: H160 = H160(hex!("0000000000000000000000000000000000000006"));
 pub const BN_MUL: H160 = H160(hex!("0000000000000000000000000000000000000007"));
 pub const BN_PAIRING: H160 = H160(hex!("0000000000000000000000000000000000000008"));
 pub const BLAKE2F: H160 = H160(hex!("0000000000000000000000000000000000000009"));
+// EIP-2537
+pub const BLS12_G1ADD: H160 = H160(hex!("000000000000000000000000000000000000000b"));
+pub const BLS12_G1MSM: H160 = H160(hex!("000000000000000000000000000000000000000c"));
+pub const BLS12_G2ADD: H160 = H160(hex!("000000000000000000000000000000000000000d"));
+pub const BLS12_G2MSM: H160 = H160(hex!("000000000000000000000000000000000000000e"));
+pub const BLS12_PAIRING_CHECK: H160 = H160(hex!("000000000000000000000000000000000000000f"));
+pub const BLS12_MAP_FP_TO_G1: H160 = H160(hex!("0000000000000000000000000000000000000010"));
+pub const BLS12_MAP_FP2_TO_G2: H160 = H160(hex!("0000000000000000000000000000000000000011"));
 
-pub const ETH_PRECOMPILE_END: H160 = BLAKE2F;
+pub const ETH_PRECOMPILE_END: H160 = BLS12_MAP_FP2_TO_G2;
 
 pub const ECRECOVER_PUBLICKEY: H160 = H160(hex!("0000000000000000000000000000000000000080"));
 pub const SHA3_256: H160 = H160(hex!("0000000000000000000000000000000000000081"));
 pub const SHA3_512: H160 = H160(hex!("0000000000000000000000000000000000000082"));
 
+// RIP-7212
+pub const P256VERIFY: H160 = H160(hex!("0000000000000000000000000000000000000100"));
+
 pub const MULTI_CURRENCY: H160 = H160(hex!("0000000000000000000000000000000000000400"));
 pub const NFT: H160 = H160(hex!("0000000000000000000000000000000000000401"));
 pub const EVM: H160 = H160(hex!("0000000000000000000000000000000000000402"));
@@ -100,6 +128,12 @@ pub const INCENTIVES: H160 = H160(hex!("000000000000000000000000000000000000040a
 pub const XTOKENS: H160 = H160(hex!("000000000000000000000000000000000000040b"));
 pub const LIQUID_CROWDLOAN: H160 = H160(hex!("000000000000000000000000000000000000040c"));
 pub const EARNING: H160 = H160(hex!("000000000000000000000000000000000000040d"));
+pub const AGGREGATED_DEX: H160 = H160(hex!("000000000000000000000000000000000000040e"));
+pub const NOMINEES_ELECTION: H160 = H160(hex!("000000000000000000000000000000000000040f"));
+pub const ASSET_REGISTRY: H160 = H160(hex!("0000000000000000000000000000000000000410"));
+pub const BATCH: H160 = H160(hex!("0000000000000000000000000000000000000411"));
+pub const DISPATCH: H160 = H160(hex!("0000000000000000000000000000000000000412"));
+pub const XCM_TRANSACT: H160 = H160(hex!("0000000000000000000000000000000000000413"));
 
 pub struct AllPrecompiles<R, F, E> {
 	set: BTreeSet<H160>,
@@ -123,10 +157,19 @@ where
 				BN_MUL,
 				BN_PAIRING,
 				BLAKE2F,
+				BLS12_G1ADD,
+				BLS12_G1MSM,
+				BLS12_G2ADD,
+				BLS12_G2MSM,
+				BLS12_PAIRING_CHECK,
+				BLS12_MAP_FP_TO_G1,
+				BLS12_MAP_FP2_TO_G2,
 				// Non-standard precompile starts with 128
 				ECRECOVER_PUBLICKEY,
 				SHA3_256,
 				SHA3_512,
+				// RIP-7212
+				P256VERIFY,
 				// Acala precompile
 				MULTI_CURRENCY,
 				// NFT,
@@ -142,6 +185,12 @@ where
 				XTOKENS,
 				LIQUID_CROWDLOAN,
 				EARNING,
+				AGGREGATED_DEX,
+				NOMINEES_ELECTION,
+				ASSET_REGISTRY,
+				BATCH,
+				DISPATCH,
+				XCM_TRANSACT,
 			]),
 			_marker: Default::default(),
 		}
@@ -159,10 +208,19 @@ where
 				BN_MUL,
 				BN_PAIRING,
 				BLAKE2F,
+				BLS12_G1ADD,
+				BLS12_G1MSM,
+				BLS12_G2ADD,
+				BLS12_G2MSM,
+				BLS12_PAIRING_CHECK,
+				BLS12_MAP_FP_TO_G1,
+				BLS12_MAP_FP2_TO_G2,
 				// Non-standard precompile starts with 128
 				ECRECOVER_PUBLICKEY,
 				SHA3_256,
 				SHA3_512,
+				// RIP-7212
+				P256VERIFY,
 				// Acala precompile
 				MULTI_CURRENCY,
 				// NFT,
@@ -178,6 +236,12 @@ where
 				XTOKENS,
 				// LIQUID_CROWDLOAN,
 				EARNING,
+				AGGREGATED_DEX,
+				NOMINEES_ELECTION,
+				ASSET_REGISTRY,
+				BATCH,
+				DISPATCH,
+				XCM_TRANSACT,
 			]),
 			_marker: Default::default(),
 		}
@@ -195,10 +259,19 @@ where
 				BN_MUL,
 				BN_PAIRING,
 				BLAKE2F,
+				BLS12_G1ADD,
+				BLS12_G1MSM,
+				BLS12_G2ADD,
+				BLS12_G2MSM,
+				BLS12_PAIRING_CHECK,
+				BLS12_MAP_FP_TO_G1,
+				BLS12_MAP_FP2_TO_G2,
 				// Non-standard precompile starts with 128
 				ECRECOVER_PUBLICKEY,
 				SHA3_256,
 				SHA3_512,
+				// RIP-7212
+				P256VERIFY,
 				// Acala precompile
 				MULTI_CURRENCY,
 				NFT,
@@ -214,6 +287,12 @@ where
 				XTOKENS,
 				// LIQUID_CROWDLOAN,
 				EARNING,
+				AGGREGATED_DEX,
+				NOMINEES_ELECTION,
+				ASSET_REGISTRY,
+				BATCH,
+				DISPATCH,
+				XCM_TRANSACT,
 			]),
 			_marker: Default::default(),
 		}
@@ -227,6 +306,7 @@ where
 	PausedPrecompile: PrecompilePauseFilter,
 	MultiCurrencyPrecompile<R>: Precompile,
 	NFTPrecompile<R>: Precompile,
+	Erc721Precompile<R>: Precompile,
 	EVMPrecompile<R>: Precompile,
 	EVMAccountsPrecompile<R>: Precompile,
 	OraclePrecompile<R>: Precompile,
@@ -238,6 +318,12 @@ where
 	IncentivesPrecompile<R>: Precompile,
 	XtokensPrecompile<R>: Precompile,
 	EarningPrecompile<R>: Precompile,
+	AggregatedDexPrecompile<R>: Precompile,
+	NomineesElectionPrecompile<R>: Precompile,
+	AssetRegistryPrecompile<R>: Precompile,
+	BatchPrecompile<R>: Precompile,
+	DispatchPrecompile<R>: Precompile,
+	XcmTransactPrecompile<R>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
 		let context = handle.context();
@@ -292,6 +378,20 @@ where
 			Some(Bn128Pairing::execute(handle))
 		} else if address == BLAKE2F {
 			Some(Blake2F::execute(handle))
+		} else if address == BLS12_G1ADD {
+			Some(Bls12381G1Add::execute(handle))
+		} else if address == BLS12_G1MSM {
+			Some(Bls12381G1Msm::execute(handle))
+		} else if address == BLS12_G2ADD {
+			Some(Bls12381G2Add::execute(handle))
+		} else if address == BLS12_G2MSM {
+			Some(Bls12381G2Msm::execute(handle))
+		} else if address == BLS12_PAIRING_CHECK {
+			Some(Bls12381Pairing::execute(handle))
+		} else if address == BLS12_MAP_FP_TO_G1 {
+			Some(Bls12381MapG1::execute(handle))
+		} else if address == BLS12_MAP_FP2_TO_G2 {
+			Some(Bls12381MapG2::execute(handle))
 		}
 		// Non-standard precompile starts with 128
 		else if address == ECRECOVER_PUBLICKEY {
@@ -301,6 +401,22 @@ where
 		} else if address == SHA3_512 {
 			Some(Sha3FIPS512::execute(handle))
 		}
+		// RIP-7212
+		else if address == P256VERIFY {
+			Some(P256Verify::execute(handle))
+		}
+		// Mirrored ERC721 of the NFT classes, can be called by any account
+		else if nft_class_id(&address).is_some() {
+			Some(Erc721Precompile::<R>::execute(handle))
+		}
+		// The calls of Batch are made on behalf of the caller, can be called by any account
+		else if address == BATCH {
+			Some(BatchPrecompile::<R>::execute(handle))
+		}
+		// The call of Dispatch is dispatched with the origin of the caller, can be called by any account
+		else if address == DISPATCH {
+			Some(DispatchPrecompile::<R>::execute(handle))
+		}
 		// Acala precompile
 		else {
 			if !SystemContractsFilter::is_allowed(context.caller) {
@@ -345,6 +461,14 @@ where
 				Some(XtokensPrecompile::<R>::execute(handle))
 			} else if address == EARNING {
 				Some(EarningPrecompile::<R>::execute(handle))
+			} else if address == AGGREGATED_DEX {
+				Some(AggregatedDexPrecompile::<R>::execute(handle))
+			} else if address == NOMINEES_ELECTION {
+				Some(NomineesElectionPrecompile::<R>::execute(handle))
+			} else if address == ASSET_REGISTRY {
+				Some(AssetRegistryPrecompile::<R>::execute(handle))
+			} else if address == XCM_TRANSACT {
+				Some(XcmTransactPrecompile::<R>::execute(handle))
 			} else {
 				E::execute(&Default::default(), handle)
 			}
@@ -360,6 +484,7 @@ where
 	fn is_precompile(&self, address: H160, _remaining_gas: u64) -> IsPrecompileResult {
 		let is_precompile = {
 			self.set.contains(&address)
+				|| (self.set.contains(&NFT) && nft_class_id(&address).is_some())
 				|| match E::is_precompile(&Default::default(), address, u64::zero()) {
 					IsPrecompileResult::Answer { is_precompile, .. } => is_precompile,
 					_ => false,
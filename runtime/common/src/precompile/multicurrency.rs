@@ -18,6 +18,7 @@
 
 use super::input::{Input, InputPricer, InputT, Output};
 use crate::WeightToGas;
+use ethabi::Token;
 use frame_support::{
 	pallet_prelude::IsType,
 	traits::{Currency, ExistenceRequirement, Get},
@@ -27,13 +28,29 @@ use module_evm::{
 	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
 	PrecompileResult,
 };
-use module_support::Erc20InfoMapping as Erc20InfoMappingT;
+use module_evm_utility_macro::keccak256;
+use module_support::{AddressMapping, Erc20InfoMapping as Erc20InfoMappingT};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::MultiCurrency as MultiCurrencyT;
-use primitives::{currency::DexShare, Balance, CurrencyId};
-use sp_runtime::{traits::Convert, AccountId32, RuntimeDebug};
+use primitives::{currency::DexShare, evm::ERC20_TRANSFER_TOPIC, Balance, CurrencyId};
+use sp_core::{H160, H256, U256};
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_runtime::{
+	traits::{Convert, UniqueSaturatedInto},
+	AccountId32, RuntimeDebug,
+};
 use sp_std::{marker::PhantomData, prelude::*};
 
+const ERC20_APPROVAL_TOPIC: H256 = H256(*keccak256!("Approval(address,address,uint256)"));
+const DOMAIN_TYPEHASH: &[u8; 32] =
+	keccak256!("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)");
+const PERMIT_TYPEHASH: &[u8; 32] =
+	keccak256!("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)");
+/// The upper bound of the `s` values of the signatures, secp256k1n / 2.
+const SECP256K1N_HALF: H256 = H256(hex_literal::hex!(
+	"7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0"
+));
+
 /// The `MultiCurrency` impl precompile.
 ///
 ///
@@ -43,6 +60,14 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Query total issuance.
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Transfer. Rest `input` bytes: `from`, `to`, `amount`.
+/// - Query allowance. Rest `input` bytes: `owner`, `spender`.
+/// - Approve. Rest `input` bytes: `owner`, `spender`, `amount`.
+/// - Transfer from. Rest `input` bytes: `spender`, `from`, `to`, `amount`.
+/// - EIP-2612 permit. Rest `input` bytes: `owner`, `spender`, `amount`, `deadline`, `v`, `r`, `s`.
+/// - Query permit nonces. Rest `input` bytes: `owner`.
+/// - Query EIP-712 domain separator.
+///
+/// Transfers and approvals emit the ERC20 `Transfer` and `Approval` logs from the token address.
 pub struct MultiCurrencyPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -56,6 +81,12 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	Transfer = "transfer(address,address,uint256)",
 	TransferToAccountId = "transferToAccountId(address,bytes32,uint256)",
+	QueryAllowance = "allowance(address,address)",
+	Approve = "approve(address,address,uint256)",
+	TransferFrom = "transferFrom(address,address,address,uint256)",
+	Permit = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+	QueryNonces = "nonces(address)",
+	QueryDomainSeparator = "DOMAIN_SEPARATOR()",
 }
 
 impl<Runtime> Precompile for MultiCurrencyPrecompile<Runtime>
@@ -64,6 +95,8 @@ where
 		module_currencies::Config + module_evm::Config + module_prices::Config + module_transaction_payment::Config,
 	Runtime::AccountId: IsType<AccountId32>,
 	module_currencies::Pallet<Runtime>: MultiCurrencyT<Runtime::AccountId, CurrencyId = CurrencyId, Balance = Balance>,
+	<Runtime as module_currencies::Config>::MultiCurrency:
+		MultiCurrencyT<Runtime::AccountId, CurrencyId = CurrencyId, Balance = Balance>,
 {
 	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		let context = handle.context();
@@ -157,18 +190,23 @@ where
 				let amount = input.balance_at(3)?;
 				log::debug!(target: "evm", "multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}", from, to, amount);
 
-				<module_currencies::Pallet<Runtime> as MultiCurrencyT<Runtime::AccountId>>::transfer(
-					currency_id,
-					&from,
-					&to,
-					amount,
-					ExistenceRequirement::AllowDeath,
-				)
+				// the transfer is logged below
+				module_evm::without_erc20_transfer_logs(|| {
+					<module_currencies::Pallet<Runtime> as MultiCurrencyT<Runtime::AccountId>>::transfer(
+						currency_id,
+						&from,
+						&to,
+						amount,
+						ExistenceRequirement::AllowDeath,
+					)
+				})
 				.map_err(|e| PrecompileFailure::Revert {
 					exit_status: ExitRevert::Reverted,
 					output: Output::encode_error_msg("Multicurrency Transfer failed", e),
 				})?;
 
+				Self::log_transfer(handle, input.evm_address_at(1)?, input.evm_address_at(2)?, amount)?;
+
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
 					output: vec![],
@@ -184,27 +222,217 @@ where
 				let amount = input.balance_at(3)?;
 				log::debug!(target: "evm", "multicurrency: transferToAccountId from: {:?}, to: {:?}, amount: {:?}", from, to, amount);
 
-				<module_currencies::Pallet<Runtime> as MultiCurrencyT<Runtime::AccountId>>::transfer(
-					currency_id,
-					&from,
-					&to,
-					amount,
-					ExistenceRequirement::AllowDeath,
-				)
+				// the transfer is logged below
+				module_evm::without_erc20_transfer_logs(|| {
+					<module_currencies::Pallet<Runtime> as MultiCurrencyT<Runtime::AccountId>>::transfer(
+						currency_id,
+						&from,
+						&to,
+						amount,
+						ExistenceRequirement::AllowDeath,
+					)
+				})
 				.map_err(|e| PrecompileFailure::Revert {
 					exit_status: ExitRevert::Reverted,
 					output: Output::encode_error_msg("Multicurrency TransferToAccountId failed", e),
 				})?;
 
+				let to_address = <Runtime as module_evm::Config>::AddressMapping::get_evm_address(&to)
+					.unwrap_or_else(|| <Runtime as module_evm::Config>::AddressMapping::get_default_evm_address(&to));
+				Self::log_transfer(handle, input.evm_address_at(1)?, to_address, amount)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::QueryAllowance => {
+				let owner = input.account_id_at(1)?;
+				let spender = input.account_id_at(2)?;
+				let allowance = module_currencies::Pallet::<Runtime>::allowances((currency_id, owner), spender);
+				log::debug!(target: "evm", "multicurrency: allowance: {:?}", allowance);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(allowance),
+				})
+			}
+			Action::Approve => {
+				let owner = input.account_id_at(1)?;
+				let spender = input.account_id_at(2)?;
+				let amount = input.u256_at(3)?;
+				log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+				// allowances above `Balance::MAX`, such as `type(uint256).max`, are unlimited
+				let allowance: Balance = amount.unique_saturated_into();
+				module_currencies::Pallet::<Runtime>::approve(currency_id, &owner, &spender, allowance);
+				Self::log_approval(handle, input.evm_address_at(1)?, input.evm_address_at(2)?, amount)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::TransferFrom => {
+				let spender = input.account_id_at(1)?;
+				let from = input.account_id_at(2)?;
+				let to = input.account_id_at(3)?;
+				let amount = input.balance_at(4)?;
+				log::debug!(target: "evm", "multicurrency: transferFrom spender: {:?}, from: {:?}, to: {:?}, amount: {:?}", spender, from, to, amount);
+
+				// the transfer is logged below
+				module_evm::without_erc20_transfer_logs(|| {
+					module_currencies::Pallet::<Runtime>::transfer_from(currency_id, &spender, &from, &to, amount)
+				})
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Multicurrency TransferFrom failed", e),
+				})?;
+
+				Self::log_transfer(handle, input.evm_address_at(2)?, input.evm_address_at(3)?, amount)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::Permit => {
+				let owner = input.evm_address_at(1)?;
+				let spender = input.evm_address_at(2)?;
+				let amount = input.u256_at(3)?;
+				let deadline = input.u256_at(4)?;
+				let v = input.u32_at(5)?;
+				let r = input.bytes32_at(6)?;
+				let s = input.bytes32_at(7)?;
+				log::debug!(target: "evm", "multicurrency: permit owner: {:?}, spender: {:?}, amount: {:?}, deadline: {:?}", owner, spender, amount, deadline);
+
+				// timestamp in seconds
+				let now: u128 = pallet_timestamp::Pallet::<Runtime>::get().unique_saturated_into();
+				if deadline < U256::from(now / 1000) {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "permit expired".into(),
+					});
+				}
+
+				let owner_account = <Runtime as module_evm::Config>::AddressMapping::get_account_id(&owner);
+				let spender_account = <Runtime as module_evm::Config>::AddressMapping::get_account_id(&spender);
+				let nonce = module_currencies::Pallet::<Runtime>::permit_nonces(currency_id, &owner_account);
+
+				let struct_hash = keccak_256(&ethabi::encode(&[
+					Token::FixedBytes(PERMIT_TYPEHASH.to_vec()),
+					Token::Address(owner),
+					Token::Address(spender),
+					Token::Uint(amount),
+					Token::Uint(nonce.into()),
+					Token::Uint(deadline),
+				]));
+				let domain_separator = Self::domain_separator(currency_id, context.caller)?;
+				let digest = keccak_256(&[&b"\x19\x01"[..], &domain_separator[..], &struct_hash[..]].concat());
+
+				// only accept the non-malleable signatures, as OpenZeppelin's `ECDSA`
+				if !(v == 27 || v == 28) || H256::from_slice(&s) > SECP256K1N_HALF {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "invalid permit signature".into(),
+					});
+				}
+
+				let mut sig = [0u8; 65];
+				sig[0..32].copy_from_slice(&r);
+				sig[32..64].copy_from_slice(&s);
+				sig[64] = (v - 27) as u8;
+				let signer = secp256k1_ecdsa_recover(&sig, &digest)
+					.map(|pubkey| H160::from(H256::from_slice(&keccak_256(&pubkey))))
+					.ok();
+				if signer != Some(owner) {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "invalid permit signature".into(),
+					});
+				}
+
+				module_currencies::Pallet::<Runtime>::use_permit_nonce(currency_id, &owner_account);
+				let allowance: Balance = amount.unique_saturated_into();
+				module_currencies::Pallet::<Runtime>::approve(currency_id, &owner_account, &spender_account, allowance);
+				Self::log_approval(handle, owner, spender, amount)?;
+
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
 					output: vec![],
 				})
 			}
+			Action::QueryNonces => {
+				let owner = input.account_id_at(1)?;
+				let nonce = module_currencies::Pallet::<Runtime>::permit_nonces(currency_id, owner);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(nonce),
+				})
+			}
+			Action::QueryDomainSeparator => {
+				let domain_separator = Self::domain_separator(currency_id, context.caller)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_fixed_bytes(&domain_separator),
+				})
+			}
 		}
 	}
 }
 
+impl<Runtime> MultiCurrencyPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config,
+{
+	fn domain_separator(currency_id: CurrencyId, token: H160) -> Result<[u8; 32], PrecompileFailure> {
+		let name = Runtime::Erc20InfoMapping::name(currency_id).ok_or_else(|| PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: "Get name failed".into(),
+		})?;
+
+		Ok(keccak_256(&ethabi::encode(&[
+			Token::FixedBytes(DOMAIN_TYPEHASH.to_vec()),
+			Token::FixedBytes(keccak_256(&name).to_vec()),
+			Token::FixedBytes(keccak_256(b"1").to_vec()),
+			Token::Uint(module_evm::Pallet::<Runtime>::chain_id().into()),
+			Token::Address(token),
+		])))
+	}
+
+	fn log_approval(
+		handle: &mut impl PrecompileHandle,
+		owner: H160,
+		spender: H160,
+		amount: U256,
+	) -> Result<(), PrecompileFailure> {
+		let token = handle.context().caller;
+		handle.log(
+			token,
+			vec![ERC20_APPROVAL_TOPIC, owner.into(), spender.into()],
+			Output::encode_uint(amount),
+		)?;
+		Ok(())
+	}
+
+	fn log_transfer(
+		handle: &mut impl PrecompileHandle,
+		from: H160,
+		to: H160,
+		amount: Balance,
+	) -> Result<(), PrecompileFailure> {
+		let token = handle.context().caller;
+		handle.log(
+			token,
+			vec![ERC20_TRANSFER_TOPIC, from.into(), to.into()],
+			Output::encode_uint(amount),
+		)?;
+		Ok(())
+	}
+}
+
 struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
@@ -213,6 +441,10 @@ where
 		module_currencies::Config + module_evm::Config + module_prices::Config + module_transaction_payment::Config,
 {
 	const BASE_COST: u64 = 200;
+	// LOG3 with a 32 bytes data: 375 + 3 * 375 + 8 * 32
+	const ERC20_LOG_COST: u64 = 1756;
+	// Same as the ECRecover precompile
+	const ECRECOVER_COST: u64 = 3000;
 
 	fn cost(handle: &mut impl PrecompileHandle, currency_id: CurrencyId) -> Result<u64, PrecompileFailure> {
 		let input = Input::<
@@ -243,21 +475,71 @@ where
 			Action::Transfer | Action::TransferToAccountId => {
 				let cost = InputPricer::<Runtime>::read_accounts(2);
 
-				// transfer weight
-				let weight = if currency_id == <Runtime as module_transaction_payment::Config>::NativeCurrencyId::get()
-				{
-					<Runtime as module_currencies::Config>::WeightInfo::transfer_native_currency()
-				} else {
-					<Runtime as module_currencies::Config>::WeightInfo::transfer_non_native_currency()
-				};
-
-				cost.saturating_add(WeightToGas::convert(weight))
+				cost.saturating_add(Self::transfer_cost(currency_id))
+					.saturating_add(Self::ERC20_LOG_COST)
+			}
+			Action::QueryAllowance => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// Currencies::Allowances (r: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
+			Action::Approve => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// Currencies::Allowances (w: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().writes(1),
+				))
+				.saturating_add(Self::ERC20_LOG_COST)
+			}
+			Action::TransferFrom => {
+				let cost = InputPricer::<Runtime>::read_accounts(3);
+				// Currencies::Allowances (r: 1, w: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1),
+				))
+				.saturating_add(Self::transfer_cost(currency_id))
+				.saturating_add(Self::ERC20_LOG_COST)
+			}
+			Action::Permit => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// Timestamp::Now, EVM::ChainId (r: 2)
+				// Currencies::PermitNonces, Currencies::Allowances (w: 2)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 2),
+				))
+				.saturating_add(Self::erc20_info(currency_id))
+				.saturating_add(Self::ECRECOVER_COST)
+				.saturating_add(Self::ERC20_LOG_COST)
+			}
+			Action::QueryNonces => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Currencies::PermitNonces (r: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
+			Action::QueryDomainSeparator => {
+				// EVM::ChainId (r: 1)
+				Self::erc20_info(currency_id).saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
 			}
 		};
 
 		Ok(Self::BASE_COST.saturating_add(read_currency).saturating_add(cost))
 	}
 
+	fn transfer_cost(currency_id: CurrencyId) -> u64 {
+		let weight = if currency_id == <Runtime as module_transaction_payment::Config>::NativeCurrencyId::get() {
+			<Runtime as module_currencies::Config>::WeightInfo::transfer_native_currency()
+		} else {
+			<Runtime as module_currencies::Config>::WeightInfo::transfer_non_native_currency()
+		};
+		WeightToGas::convert(weight)
+	}
+
 	fn dex_share_read_cost(share: DexShare) -> u64 {
 		match share {
 			DexShare::Erc20(_) | DexShare::ForeignAsset(_) => WeightToGas::convert(Runtime::DbWeight::get().reads(1)),
@@ -283,12 +565,13 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		aca_evm_address, alice, ausd_evm_address, bob, erc20_address_not_exists, lp_aca_ausd_evm_address, new_test_ext,
-		Balances, Test,
+		aca_evm_address, alice, ausd_evm_address, bob, bob_evm_addr, erc20_address_not_exists, lp_aca_ausd_evm_address,
+		new_test_ext, Balances, Test, Timestamp, ACA,
 	};
 	use frame_support::assert_noop;
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use primitives::evm::Log;
 
 	type MultiCurrencyPrecompile = crate::MultiCurrencyPrecompile<Test>;
 
@@ -551,13 +834,25 @@ mod tests {
 			// Token
 			context.caller = aca_evm_address();
 
-			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
-				.unwrap();
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = MultiCurrencyPrecompile::execute(&mut handle).unwrap();
 			assert_eq!(resp.exit_status, ExitSucceed::Returned);
 			assert_eq!(resp.output, [0u8; 0].to_vec());
 
 			assert_eq!(Balances::free_balance(alice()), from_balance - 1);
 			assert_eq!(Balances::free_balance(bob()), to_balance + 1);
+			assert_eq!(
+				handle.logs,
+				vec![Log {
+					address: aca_evm_address(),
+					topics: vec![
+						ERC20_TRANSFER_TOPIC,
+						H256::from(H160::from(hex!("1000000000000000000000000000000000000001"))),
+						H256::from(H160::from(hex!("1000000000000000000000000000000000000002"))),
+					],
+					data: Output::encode_uint(1u128),
+				}]
+			);
 
 			// DexShare
 			context.caller = lp_aca_ausd_evm_address();
@@ -602,13 +897,25 @@ mod tests {
 			// Token
 			context.caller = aca_evm_address();
 
-			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
-				.unwrap();
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = MultiCurrencyPrecompile::execute(&mut handle).unwrap();
 			assert_eq!(resp.exit_status, ExitSucceed::Returned);
 			assert_eq!(resp.output, [0u8; 0].to_vec());
 
 			assert_eq!(Balances::free_balance(alice()), from_balance - 1);
 			assert_eq!(Balances::free_balance(bob()), to_balance + 1);
+			assert_eq!(
+				handle.logs,
+				vec![Log {
+					address: aca_evm_address(),
+					topics: vec![
+						ERC20_TRANSFER_TOPIC,
+						H256::from(H160::from(hex!("1000000000000000000000000000000000000001"))),
+						H256::from(H160::from(hex!("1000000000000000000000000000000000000002"))),
+					],
+					data: Output::encode_uint(1u128),
+				}]
+			);
 
 			// DexShare
 			context.caller = lp_aca_ausd_evm_address();
@@ -626,4 +933,257 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn approve_and_transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: aca_evm_address(),
+				apparent_value: Default::default(),
+			};
+
+			// approve(address,address,uint256) -> 0xe1f21c67
+			// owner
+			// spender
+			// amount
+			let input = hex! {"
+				e1f21c67
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = MultiCurrencyPrecompile::execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				handle.logs,
+				vec![Log {
+					address: aca_evm_address(),
+					topics: vec![
+						ERC20_APPROVAL_TOPIC,
+						H256::from(H160::from(hex!("1000000000000000000000000000000000000001"))),
+						H256::from(H160::from(hex!("1000000000000000000000000000000000000002"))),
+					],
+					data: Output::encode_uint(10u128),
+				}]
+			);
+
+			// allowance(address,address) -> 0xdd62ed3e
+			// owner
+			// spender
+			let input = hex! {"
+				dd62ed3e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.output, Output::encode_uint(10u128));
+
+			// transferFrom(address,address,address,uint256) -> 0x15dacbea
+			// spender
+			// from
+			// to
+			// amount
+			let input = hex! {"
+				15dacbea
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000004
+			"};
+
+			let from_balance = Balances::free_balance(alice());
+			let to_balance = Balances::free_balance(bob());
+
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = MultiCurrencyPrecompile::execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(Balances::free_balance(alice()), from_balance - 4);
+			assert_eq!(Balances::free_balance(bob()), to_balance + 4);
+			assert_eq!(module_currencies::Pallet::<Test>::allowances((ACA, alice()), bob()), 6);
+			assert_eq!(handle.logs[0].topics[0], ERC20_TRANSFER_TOPIC);
+
+			// more than the allowance
+			let input = hex! {"
+				15dacbea
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000007
+			"};
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Multicurrency TransferFrom failed: AllowanceTooLow".into(),
+				}
+			);
+
+			// approve type(uint256).max
+			let input = hex! {"
+				e1f21c67
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				ffffffffffffffffffffffffffffffff ffffffffffffffffffffffffffffffff
+			"};
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = MultiCurrencyPrecompile::execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(handle.logs[0].data, Output::encode_uint(U256::MAX));
+			assert_eq!(
+				module_currencies::Pallet::<Test>::allowances((ACA, alice()), bob()),
+				Balance::MAX
+			);
+
+			// the unlimited allowance is not spent
+			let input = hex! {"
+				15dacbea
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000007
+			"};
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				module_currencies::Pallet::<Test>::allowances((ACA, alice()), bob()),
+				Balance::MAX
+			);
+		});
+	}
+
+	#[test]
+	fn permit_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: aca_evm_address(),
+				apparent_value: Default::default(),
+			};
+
+			let secret = libsecp256k1::SecretKey::parse(&keccak_256(b"Owner")).unwrap();
+			let public = libsecp256k1::PublicKey::from_secret_key(&secret);
+			let owner = H160::from(H256::from_slice(&keccak_256(&public.serialize()[1..])));
+			let spender = bob_evm_addr();
+
+			// DOMAIN_SEPARATOR() -> 0x3644e515
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(
+				&hex!("3644e515"),
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			let domain_separator = keccak_256(&ethabi::encode(&[
+				Token::FixedBytes(DOMAIN_TYPEHASH.to_vec()),
+				Token::FixedBytes(keccak_256(b"Acala").to_vec()),
+				Token::FixedBytes(keccak_256(b"1").to_vec()),
+				Token::Uint(module_evm::Pallet::<Test>::chain_id().into()),
+				Token::Address(aca_evm_address()),
+			]));
+			assert_eq!(resp.output, domain_separator.to_vec());
+
+			let permit = |amount: U256, nonce: u64, deadline: u64| {
+				let struct_hash = keccak_256(&ethabi::encode(&[
+					Token::FixedBytes(PERMIT_TYPEHASH.to_vec()),
+					Token::Address(owner),
+					Token::Address(spender),
+					Token::Uint(amount),
+					Token::Uint(nonce.into()),
+					Token::Uint(deadline.into()),
+				]));
+				let digest = keccak_256(&[&b"\x19\x01"[..], &domain_separator[..], &struct_hash[..]].concat());
+				let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&digest), &secret);
+
+				// permit(address,address,uint256,uint256,uint8,bytes32,bytes32) -> 0xd505accf
+				let mut input = hex!("d505accf").to_vec();
+				input.extend_from_slice(H256::from(owner).as_bytes());
+				input.extend_from_slice(H256::from(spender).as_bytes());
+				input.extend_from_slice(&Output::encode_uint(amount));
+				input.extend_from_slice(&Output::encode_uint(deadline));
+				input.extend_from_slice(&Output::encode_uint(recovery_id.serialize() + 27));
+				input.extend_from_slice(&sig.serialize());
+				input
+			};
+
+			let input = permit(100.into(), 0, u64::MAX);
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = MultiCurrencyPrecompile::execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(handle.logs[0].topics[0], ERC20_APPROVAL_TOPIC);
+
+			let owner_account = <Test as module_evm::Config>::AddressMapping::get_account_id(&owner);
+			assert_eq!(
+				module_currencies::Pallet::<Test>::allowances((ACA, owner_account.clone()), bob()),
+				100
+			);
+			assert_eq!(
+				module_currencies::Pallet::<Test>::permit_nonces(ACA, owner_account.clone()),
+				1
+			);
+
+			// replay
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid permit signature".into(),
+				}
+			);
+
+			// the signed uint256 amount is hashed, the allowance saturates
+			let mut handle = MockPrecompileHandle::new(&permit(U256::MAX, 1, u64::MAX), None, &context, false);
+			let resp = MultiCurrencyPrecompile::execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(handle.logs[0].data, Output::encode_uint(U256::MAX));
+			assert_eq!(
+				module_currencies::Pallet::<Test>::allowances((ACA, owner_account), bob()),
+				Balance::MAX
+			);
+
+			let invalid_signature = PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "invalid permit signature".into(),
+			};
+
+			// `v` is not 27 or 28
+			let mut input = permit(100.into(), 2, u64::MAX);
+			input[163] -= 27;
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				invalid_signature.clone()
+			);
+
+			// the malleable signature with `s` above secp256k1n / 2
+			let secp256k1n = U256::from_big_endian(&hex!(
+				"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+			));
+			let mut input = permit(100.into(), 2, u64::MAX);
+			let s = secp256k1n - U256::from_big_endian(&input[196..228]);
+			input[196..228].copy_from_slice(&Output::encode_uint(s));
+			input[163] = 55 - input[163];
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				invalid_signature
+			);
+
+			// expired
+			Timestamp::set_timestamp(10_000);
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(
+					&permit(100.into(), 2, 0),
+					None,
+					&context,
+					false
+				)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "permit expired".into(),
+				}
+			);
+		});
+	}
 }
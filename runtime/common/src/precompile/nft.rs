@@ -17,17 +17,23 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::input::{Input, InputT, Output};
-use frame_support::traits::tokens::nonfungibles::{Inspect, Transfer};
+use crate::WeightToGas;
+use frame_support::traits::{
+	tokens::nonfungibles::{Inspect, Transfer},
+	Get,
+};
 use module_evm::{
 	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
 	PrecompileResult,
 };
+use module_nft::WeightInfo;
 use module_support::AddressMapping;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::InspectExtended;
-use primitives::nft::NFTBalance;
+use parity_scale_codec::Decode;
+use primitives::nft::{Attributes, NFTBalance, Properties};
 use sp_core::H160;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{traits::Convert, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
 /// The `NFT` impl precompile.
@@ -38,6 +44,21 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Query owner. Rest `input` bytes: `class_id`, `token_id`.
 /// - Transfer. Rest `input`bytes: `from`, `to`, `class_id`, `token_id`.
+/// - Create class. Rest `input` bytes: `who`, `metadata`, `properties`, `attributes`.
+/// - Mint. Rest `input` bytes: `who`, `to`, `class_id`, `metadata`, `attributes`, `quantity`.
+/// - Burn. Rest `input` bytes: `who`, `class_id`, `token_id`.
+/// - Burn with remark. Rest `input` bytes: `who`, `class_id`, `token_id`, `remark`.
+/// - Destroy class. Rest `input` bytes: `who`, `class_id`, `dest`.
+/// - Update class properties. Rest `input` bytes: `who`, `class_id`, `properties`.
+/// - Get class metadata. Rest `input` bytes: `class_id`.
+/// - Get class properties. Rest `input` bytes: `class_id`.
+/// - Get class attribute. Rest `input` bytes: `class_id`, `key`.
+/// - Get token metadata. Rest `input` bytes: `class_id`, `token_id`.
+/// - Get token attribute. Rest `input` bytes: `class_id`, `token_id`, `key`.
+///
+/// `attributes` are SCALE encoded `BTreeMap<Vec<u8>, Vec<u8>>`, empty bytes means no attributes.
+/// Mint, destroy class and update class properties can be called by the class owner or the
+/// account that created the class, the deposits of mint are paid by `who`.
 pub struct NFTPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -47,11 +68,25 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	QueryOwner = "ownerOf(uint256,uint256)",
 	Transfer = "transfer(address,address,uint256,uint256)",
+	CreateClass = "createClass(address,bytes,uint8,bytes)",
+	Mint = "mint(address,address,uint256,bytes,bytes,uint32)",
+	Burn = "burn(address,uint256,uint256)",
+	BurnWithRemark = "burnWithRemark(address,uint256,uint256,bytes)",
+	DestroyClass = "destroyClass(address,uint256,address)",
+	UpdateClassProperties = "updateClassProperties(address,uint256,uint8)",
+	GetClassMetadata = "getClassMetadata(uint256)",
+	GetClassProperties = "getClassProperties(uint256)",
+	GetClassAttribute = "getClassAttribute(uint256,bytes)",
+	GetTokenMetadata = "getTokenMetadata(uint256,uint256)",
+	GetTokenAttribute = "getTokenAttribute(uint256,uint256,bytes)",
 }
 
 impl<Runtime> Precompile for NFTPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config + module_nft::Config,
+	Runtime: module_evm::Config
+		+ module_prices::Config
+		+ module_nft::Config
+		+ orml_nft::Config<ClassId = u32, TokenId = u64>,
 	module_nft::Pallet<Runtime>: InspectExtended<Runtime::AccountId, Balance = NFTBalance>
 		+ Inspect<Runtime::AccountId, ItemId = u64, CollectionId = u32>
 		+ Transfer<Runtime::AccountId>,
@@ -117,10 +152,227 @@ where
 					output: vec![],
 				})
 			}
+			Action::CreateClass => {
+				let who = input.account_id_at(1)?;
+				let metadata = input.bytes_at(2)?;
+				let properties = decode_properties(input.u32_at(3)?)?;
+				let attributes = decode_attributes(&input.bytes_at(4)?)?;
+
+				log::debug!(target: "evm", "nft: create_class who: {:?}, metadata: {:?}, properties: {:?}, attributes: {:?}", who, metadata, properties, attributes);
+
+				let class_id = module_nft::Pallet::<Runtime>::do_create_class(&who, metadata, properties, attributes)
+					.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("NFT CreateClass failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(class_id),
+				})
+			}
+			Action::Mint => {
+				let who = input.account_id_at(1)?;
+				let to = input.account_id_at(2)?;
+				let class_id = input.u32_at(3)?;
+				let metadata = input.bytes_at(4)?;
+				let attributes = decode_attributes(&input.bytes_at(5)?)?;
+				let quantity = input.u32_at(6)?;
+
+				log::debug!(target: "evm", "nft: mint who: {:?}, to: {:?}, class_id: {:?}, metadata: {:?}, attributes: {:?}, quantity: {:?}", who, to, class_id, metadata, attributes, quantity);
+
+				let token_ids = module_nft::Pallet::<Runtime>::ensure_class_owner_or_delegate(&who, class_id)
+					.and_then(|class_owner| {
+						module_nft::Pallet::<Runtime>::do_mint(
+							&class_owner,
+							&who,
+							&to,
+							class_id,
+							metadata,
+							attributes,
+							quantity,
+						)
+					})
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT Mint failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_array(token_ids),
+				})
+			}
+			Action::Burn => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+
+				log::debug!(target: "evm", "nft: burn who: {:?}, class_id: {:?}, token_id: {:?}", who, class_id, token_id);
+
+				module_nft::Pallet::<Runtime>::do_burn(who, (class_id, token_id), None).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT Burn failed", e),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::BurnWithRemark => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+				let remark = input.bytes_at(4)?;
+
+				log::debug!(target: "evm", "nft: burn_with_remark who: {:?}, class_id: {:?}, token_id: {:?}, remark: {:?}", who, class_id, token_id, remark);
+
+				module_nft::Pallet::<Runtime>::do_burn(who, (class_id, token_id), Some(remark)).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT BurnWithRemark failed", e),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::DestroyClass => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let dest = input.account_id_at(3)?;
+
+				log::debug!(target: "evm", "nft: destroy_class who: {:?}, class_id: {:?}, dest: {:?}", who, class_id, dest);
+
+				module_nft::Pallet::<Runtime>::ensure_class_owner_or_delegate(&who, class_id)
+					.and_then(|class_owner| {
+						module_nft::Pallet::<Runtime>::do_destroy_class(&class_owner, class_id, &dest)
+					})
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT DestroyClass failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::UpdateClassProperties => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let properties = decode_properties(input.u32_at(3)?)?;
+
+				log::debug!(target: "evm", "nft: update_class_properties who: {:?}, class_id: {:?}, properties: {:?}", who, class_id, properties);
+
+				module_nft::Pallet::<Runtime>::ensure_class_owner_or_delegate(&who, class_id)
+					.and_then(|class_owner| {
+						module_nft::Pallet::<Runtime>::do_update_class_properties(&class_owner, class_id, properties)
+					})
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT UpdateClassProperties failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::GetClassMetadata => {
+				let class_id = input.u32_at(1)?;
+
+				let metadata = orml_nft::Pallet::<Runtime>::classes(class_id)
+					.map(|class_info| class_info.metadata.into_inner())
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&metadata),
+				})
+			}
+			Action::GetClassProperties => {
+				let class_id = input.u32_at(1)?;
+
+				let properties = orml_nft::Pallet::<Runtime>::classes(class_id)
+					.map(|class_info| class_info.data.properties.0.bits())
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(properties),
+				})
+			}
+			Action::GetClassAttribute => {
+				let class_id = input.u32_at(1)?;
+				let key = input.bytes_at(2)?;
+
+				let value = orml_nft::Pallet::<Runtime>::classes(class_id)
+					.and_then(|class_info| class_info.data.attributes.get(&key).cloned())
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&value),
+				})
+			}
+			Action::GetTokenMetadata => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+
+				let metadata = orml_nft::Pallet::<Runtime>::tokens(class_id, token_id)
+					.map(|token_info| token_info.metadata.into_inner())
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&metadata),
+				})
+			}
+			Action::GetTokenAttribute => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+				let key = input.bytes_at(3)?;
+
+				let value = orml_nft::Pallet::<Runtime>::tokens(class_id, token_id)
+					.and_then(|token_info| token_info.data.attributes.get(&key).cloned())
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&value),
+				})
+			}
 		}
 	}
 }
 
+fn decode_properties(properties: u32) -> Result<Properties, PrecompileFailure> {
+	u8::try_from(properties)
+		.ok()
+		.and_then(|properties| Properties::decode(&mut &[properties][..]).ok())
+		.ok_or_else(|| PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: "invalid properties".into(),
+		})
+}
+
+fn decode_attributes(attributes: &[u8]) -> Result<Attributes, PrecompileFailure> {
+	if attributes.is_empty() {
+		return Ok(Default::default());
+	}
+
+	Attributes::decode(&mut &attributes[..]).map_err(|_| PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: "invalid attributes".into(),
+	})
+}
+
 pub struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
@@ -133,8 +385,348 @@ where
 		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
 			handle.input(),
 		);
-		let _action = input.action()?;
-		// TODO: gas cost
-		Ok(Self::BASE_COST)
+		let action = input.action()?;
+
+		let cost = match action {
+			// TODO: gas cost
+			Action::QueryBalance | Action::QueryOwner | Action::Transfer => 0,
+			Action::CreateClass => {
+				// Proxy::Proxies (r: 1)
+				let weight = <Runtime as module_nft::Config>::WeightInfo::create_class()
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+				WeightToGas::convert(weight)
+			}
+			Action::Mint => {
+				let quantity = input.u32_at(6)?;
+				// Proxy::Proxies (r: 1)
+				let weight = <Runtime as module_nft::Config>::WeightInfo::mint(quantity)
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+				WeightToGas::convert(weight)
+			}
+			Action::Burn => WeightToGas::convert(<Runtime as module_nft::Config>::WeightInfo::burn()),
+			Action::BurnWithRemark => {
+				let remark = input.bytes_at(4)?;
+				WeightToGas::convert(<Runtime as module_nft::Config>::WeightInfo::burn_with_remark(
+					remark.len() as u32,
+				))
+			}
+			Action::DestroyClass => {
+				// Proxy::Proxies (r: 1)
+				let weight = <Runtime as module_nft::Config>::WeightInfo::destroy_class()
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+				WeightToGas::convert(weight)
+			}
+			Action::UpdateClassProperties => {
+				// Proxy::Proxies (r: 1)
+				let weight = <Runtime as module_nft::Config>::WeightInfo::update_class_properties()
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+				WeightToGas::convert(weight)
+			}
+			Action::GetClassMetadata | Action::GetClassProperties | Action::GetClassAttribute => {
+				// OrmlNFT::Classes (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetTokenMetadata | Action::GetTokenAttribute => {
+				// OrmlNFT::Tokens (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{alice, alice_evm_addr, bob, new_test_ext, Balances, Test};
+	use frame_support::{assert_noop, assert_ok, storage::with_transaction};
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use primitives::nft::ClassProperty;
+	use sp_runtime::{DispatchError, TransactionOutcome};
+
+	type NFTPrecompile = super::NFTPrecompile<Test>;
+
+	fn execute(input: &[u8]) -> PrecompileResult {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+		// evm executes precompiles inside a storage layer
+		with_transaction(|| {
+			let result = NFTPrecompile::execute(&mut MockPrecompileHandle::new(input, None, &context, false));
+			TransactionOutcome::Commit(Ok::<_, DispatchError>(result))
+		})
+		.unwrap()
+	}
+
+	#[test]
+	fn create_class_and_mint_works() {
+		new_test_ext().execute_with(|| {
+			let alice_free = Balances::free_balance(alice());
+
+			// createClass(address,bytes,uint8,bytes) -> 0xb6b29c83
+			// who
+			// metadata offset
+			// properties
+			// attributes offset
+			// metadata
+			// attributes
+			let input = hex! {"
+				b6b29c83
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 0000000000000000000000000000000f
+				00000000000000000000000000000000 000000000000000000000000000000c0
+				00000000000000000000000000000000 00000000000000000000000000000005
+				636c617373000000000000000000000000000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_uint(0u32));
+			assert_eq!(
+				orml_nft::Pallet::<Test>::classes(0).unwrap().metadata.into_inner(),
+				b"class".to_vec()
+			);
+			assert!(Balances::free_balance(alice()) < alice_free);
+
+			// mint(address,address,uint256,bytes,bytes,uint32) -> 0x475bb6b1
+			// who
+			// to
+			// class_id
+			// metadata offset
+			// attributes offset
+			// quantity
+			// metadata
+			// attributes
+			let input = hex! {"
+				475bb6b1
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 000000000000000000000000000000c0
+				00000000000000000000000000000000 00000000000000000000000000000100
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000005
+				746f6b656e000000000000000000000000000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000005
+				04046b0476000000000000000000000000000000000000000000000000000000
+			"};
+			let alice_free = Balances::free_balance(alice());
+			assert_eq!(
+				execute(&input).unwrap().output,
+				Output::encode_uint_array(vec![0u64, 1u64])
+			);
+			assert_eq!(orml_nft::Pallet::<Test>::tokens(0, 1).unwrap().owner, bob());
+			// the deposits are paid by the caller and reserved on the receiver
+			assert!(Balances::free_balance(alice()) < alice_free);
+			assert_eq!(
+				Balances::reserved_balance(bob()),
+				orml_nft::Pallet::<Test>::tokens(0, 0).unwrap().data.deposit * 2
+			);
+
+			// getClassMetadata(uint256) -> 0x603b395d
+			// class_id
+			let input = hex! {"
+				603b395d
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_bytes(b"class"));
+
+			// getClassProperties(uint256) -> 0xe6470059
+			// class_id
+			let input = hex! {"
+				e6470059
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_uint(15u8));
+
+			// getTokenMetadata(uint256,uint256) -> 0x9537be7e
+			// class_id
+			// token_id
+			let input = hex! {"
+				9537be7e
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_bytes(b"token"));
+
+			// getTokenAttribute(uint256,uint256,bytes) -> 0xe68e29bc
+			// class_id
+			// token_id
+			// key offset
+			// key
+			let input = hex! {"
+				e68e29bc
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000060
+				00000000000000000000000000000000 00000000000000000000000000000001
+				6b00000000000000000000000000000000000000000000000000000000000000
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_bytes(b"v"));
+
+			// getClassAttribute(uint256,bytes) -> 0x5d7975e7
+			// class_id
+			// key offset
+			// key
+			let input = hex! {"
+				5d7975e7
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 00000000000000000000000000000001
+				6b00000000000000000000000000000000000000000000000000000000000000
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_bytes(b""));
+		});
+	}
+
+	#[test]
+	fn update_class_properties_and_burn_works() {
+		new_test_ext().execute_with(|| {
+			// createClass(address,bytes,uint8,bytes) -> 0xb6b29c83
+			// who
+			// metadata offset
+			// properties
+			// attributes offset
+			// metadata
+			// attributes
+			let input = hex! {"
+				b6b29c83
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 0000000000000000000000000000000f
+				00000000000000000000000000000000 000000000000000000000000000000a0
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_ok!(execute(&input));
+
+			// updateClassProperties(address,uint256,uint8) -> 0x8f50e2a7
+			// who
+			// class_id
+			// properties
+			let input = hex! {"
+				8f50e2a7
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000003
+			"};
+			assert_noop!(
+				execute(&input),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "NFT UpdateClassProperties failed: NoPermission".into(),
+				}
+			);
+
+			// updateClassProperties(address,uint256,uint8) -> 0x8f50e2a7
+			// who
+			// class_id
+			// properties
+			let input = hex! {"
+				8f50e2a7
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000007
+			"};
+			assert_ok!(execute(&input));
+			assert_eq!(
+				orml_nft::Pallet::<Test>::classes(0).unwrap().data.properties,
+				Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable)
+			);
+
+			// mint(address,address,uint256,bytes,bytes,uint32) -> 0x475bb6b1
+			// who
+			// to
+			// class_id
+			// metadata offset
+			// attributes offset
+			// quantity
+			// metadata
+			// attributes
+			let input = hex! {"
+				475bb6b1
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 000000000000000000000000000000c0
+				00000000000000000000000000000000 000000000000000000000000000000e0
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_eq!(
+				execute(&input).unwrap().output,
+				Output::encode_uint_array(vec![0u64, 1u64])
+			);
+
+			// burn(address,uint256,uint256) -> 0xf5298aca
+			// who
+			// class_id
+			// token_id
+			let input = hex! {"
+				f5298aca
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_noop!(
+				execute(&input),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "NFT Burn failed: NoPermission".into(),
+				}
+			);
+
+			// burn(address,uint256,uint256) -> 0xf5298aca
+			// who
+			// class_id
+			// token_id
+			let input = hex! {"
+				f5298aca
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_ok!(execute(&input));
+			assert!(orml_nft::Pallet::<Test>::tokens(0, 0).is_none());
+
+			// burnWithRemark(address,uint256,uint256,bytes) -> 0x126b523c
+			// who
+			// class_id
+			// token_id
+			// remark offset
+			// remark
+			let input = hex! {"
+				126b523c
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 00000000000000000000000000000006
+				72656d61726b0000000000000000000000000000000000000000000000000000
+			"};
+			assert_ok!(execute(&input));
+			assert!(orml_nft::Pallet::<Test>::tokens(0, 1).is_none());
+
+			// updateClassProperties(address,uint256,uint8) -> 0x8f50e2a7
+			// who
+			// class_id
+			// properties
+			let input = hex! {"
+				8f50e2a7
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			assert_noop!(
+				execute(&input),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "NFT UpdateClassProperties failed: Immutable".into(),
+				}
+			);
+		});
 	}
 }
@@ -0,0 +1,553 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputPricer, InputT, Output};
+use crate::WeightToGas;
+use frame_support::traits::Get;
+use module_evm::{
+	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+
+use ethabi::Token;
+use module_nominees_election::WeightInfo;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::AccountId;
+use sp_core::U256;
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The NomineesElection precompile
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Bond. `input` bytes: `who`, `amount`.
+/// - Unbond. `input` bytes: `who`, `amount`.
+/// - Rebond. `input` bytes: `who`, `amount`.
+/// - Withdraw unbonded. `input` bytes: `who`.
+/// - Nominate. `input` bytes: `who`, `targets`.
+/// - Chill. `input` bytes: `who`.
+/// - Get ledger. `input` bytes: `who`.
+/// - Get nominations. `input` bytes: `who`.
+/// - Get votes. `input` bytes: `nominee`.
+/// - Get reserved nominees. `input` bytes: `group_index`.
+///
+/// Nominees are relay chain accounts, they are encoded as `bytes32`.
+pub struct NomineesElectionPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Bond = "bond(address,uint256)",
+	Unbond = "unbond(address,uint256)",
+	Rebond = "rebond(address,uint256)",
+	WithdrawUnbonded = "withdrawUnbonded(address)",
+	Nominate = "nominate(address,bytes32[])",
+	Chill = "chill(address)",
+	GetLedger = "getLedger(address)",
+	GetNominations = "getNominations(address)",
+	GetVotes = "getVotes(bytes32)",
+	GetReservedNominees = "getReservedNominees(uint16)",
+}
+
+impl<Runtime> Precompile for NomineesElectionPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_nominees_election::Config<NomineeId = AccountId> + module_prices::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			Runtime::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		match action {
+			Action::Bond => {
+				let who = input.account_id_at(1)?;
+				let amount = input.balance_at(2)?;
+
+				log::debug!(
+					target: "evm",
+					"nominees_election: bond, who: {:?}, amount: {:?}",
+					&who, amount
+				);
+
+				let bonded_amount =
+					module_nominees_election::Pallet::<Runtime>::do_bond(&who, amount).map_err(|e| {
+						PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("NomineesElection bond failed", e),
+						}
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(bonded_amount),
+				})
+			}
+			Action::Unbond => {
+				let who = input.account_id_at(1)?;
+				let amount = input.balance_at(2)?;
+
+				log::debug!(
+					target: "evm",
+					"nominees_election: unbond, who: {:?}, amount: {:?}",
+					&who, amount
+				);
+
+				let unbonded_amount =
+					module_nominees_election::Pallet::<Runtime>::do_unbond(&who, amount).map_err(|e| {
+						PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("NomineesElection unbond failed", e),
+						}
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(unbonded_amount),
+				})
+			}
+			Action::Rebond => {
+				let who = input.account_id_at(1)?;
+				let amount = input.balance_at(2)?;
+
+				log::debug!(
+					target: "evm",
+					"nominees_election: rebond, who: {:?}, amount: {:?}",
+					&who, amount
+				);
+
+				let rebonded_amount =
+					module_nominees_election::Pallet::<Runtime>::do_rebond(&who, amount).map_err(|e| {
+						PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("NomineesElection rebond failed", e),
+						}
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(rebonded_amount),
+				})
+			}
+			Action::WithdrawUnbonded => {
+				let who = input.account_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"nominees_election: withdraw_unbonded, who: {:?}",
+					&who
+				);
+
+				let withdrawn_amount = module_nominees_election::Pallet::<Runtime>::do_withdraw_unbonded(&who)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NomineesElection withdraw unbonded failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(withdrawn_amount),
+				})
+			}
+			Action::Nominate => {
+				let who = input.account_id_at(1)?;
+				// solidity abi encode array will add an offset at input[2]
+				let targets_len = input.u32_at(3)?;
+				let mut targets = vec![];
+				for i in 0..targets_len {
+					let mut nominee = [0u8; 32];
+					nominee.copy_from_slice(&input.bytes32_at((4 + i) as usize)?);
+					targets.push(AccountId::new(nominee));
+				}
+
+				log::debug!(
+					target: "evm",
+					"nominees_election: nominate, who: {:?}, targets: {:?}",
+					&who, targets
+				);
+
+				module_nominees_election::Pallet::<Runtime>::do_nominate(&who, targets).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NomineesElection nominate failed", e),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::Chill => {
+				let who = input.account_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"nominees_election: chill, who: {:?}",
+					&who
+				);
+
+				module_nominees_election::Pallet::<Runtime>::do_chill(&who).map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("NomineesElection chill failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::GetLedger => {
+				let who = input.account_id_at(1)?;
+				let ledger = module_nominees_election::Pallet::<Runtime>::ledger(&who).unwrap_or_default();
+				let unlocking_token: Vec<Token> = ledger
+					.unlocking()
+					.iter()
+					.cloned()
+					.map(|(value, unlock_at)| {
+						Token::Tuple(vec![
+							Token::Uint(Into::<U256>::into(value)),
+							Token::Uint(Into::<U256>::into(unlock_at)),
+						])
+					})
+					.collect();
+				let ledger_token: Token = Token::Tuple(vec![
+					Token::Uint(Into::<U256>::into(ledger.total())),
+					Token::Uint(Into::<U256>::into(ledger.active())),
+					Token::Array(unlocking_token),
+				]);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: ethabi::encode(&[ledger_token]),
+				})
+			}
+			Action::GetNominations => {
+				let who = input.account_id_at(1)?;
+				let nominations = module_nominees_election::Pallet::<Runtime>::nominations(&who);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: encode_nominees(nominations.into_inner()),
+				})
+			}
+			Action::GetVotes => {
+				let mut nominee = [0u8; 32];
+				nominee.copy_from_slice(&input.bytes32_at(1)?);
+				let votes = module_nominees_election::Pallet::<Runtime>::votes(AccountId::new(nominee));
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(votes),
+				})
+			}
+			Action::GetReservedNominees => {
+				let group_index = u16::try_from(input.u32_at(1)?).map_err(|_| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid group index".into(),
+				})?;
+				let reserved_nominees = module_nominees_election::Pallet::<Runtime>::reserved_nominees(group_index);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: encode_nominees(reserved_nominees.into_inner()),
+				})
+			}
+		}
+	}
+}
+
+fn encode_nominees(nominees: Vec<AccountId>) -> Vec<u8> {
+	ethabi::encode(&[Token::Array(
+		nominees
+			.into_iter()
+			.map(|nominee| Token::FixedBytes(Into::<[u8; 32]>::into(nominee).to_vec()))
+			.collect(),
+	)])
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_nominees_election::Config + module_prices::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::Bond => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nominees_election::Config>::WeightInfo::bond();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Unbond => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nominees_election::Config>::WeightInfo::unbond();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Rebond => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nominees_election::Config>::WeightInfo::rebond(
+					<Runtime as module_nominees_election::Config>::MaxUnbondingChunks::get(),
+				);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::WithdrawUnbonded => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nominees_election::Config>::WeightInfo::withdraw_unbonded(
+					<Runtime as module_nominees_election::Config>::MaxUnbondingChunks::get(),
+				);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Nominate => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let targets_len = input.u32_at(3)?;
+				let weight = <Runtime as module_nominees_election::Config>::WeightInfo::nominate(targets_len);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Chill => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nominees_election::Config>::WeightInfo::chill(
+					<Runtime as module_nominees_election::Config>::MaxNominateesCount::get(),
+				);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetLedger => {
+				// NomineesElection::Ledger (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetNominations => {
+				// NomineesElection::Nominations (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetVotes => {
+				// NomineesElection::Votes (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetReservedNominees => {
+				// NomineesElection::ReservedNominees (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, new_test_ext, Currencies, Homa, HomaAdmin, NomineesElection, RuntimeOrigin, Test, LDOT,
+	};
+	use frame_support::assert_ok;
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+
+	type NomineesElectionPrecompile = super::NomineesElectionPrecompile<Test>;
+
+	fn execute(input: &[u8]) -> PrecompileResult {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+		NomineesElectionPrecompile::execute(&mut MockPrecompileHandle::new(input, None, &context, false))
+	}
+
+	#[test]
+	fn bond_and_nominate_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				LDOT,
+				1_000_000
+			));
+
+			// bond(address,uint256) -> 0xa515366a
+			// who
+			// amount 100_000
+			let input = hex! {"
+				a515366a
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000186a0
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_uint(100_000u128));
+			assert_eq!(NomineesElection::ledger(alice()).unwrap().active(), 100_000);
+
+			// nominate(address,bytes32[]) -> 0x1412ff08
+			// who
+			// offset
+			// targets len
+			// target
+			let input = hex! {"
+				1412ff08
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 00000000000000000000000000000001
+				0303030303030303030303030303030303030303030303030303030303030303
+			"};
+			assert_eq!(execute(&input).unwrap().exit_status, ExitSucceed::Returned);
+
+			// getNominations(address) -> 0x3e7dfcaa
+			// who
+			let input = hex! {"
+				3e7dfcaa
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			assert_eq!(
+				execute(&input).unwrap().output,
+				encode_nominees(vec![AccountId::new([3u8; 32])])
+			);
+
+			// getVotes(bytes32) -> 0x4c051100
+			// nominee
+			let votes_input = hex! {"
+				4c051100
+				0303030303030303030303030303030303030303030303030303030303030303
+			"};
+			assert_eq!(execute(&votes_input).unwrap().output, Output::encode_uint(100_000u128));
+
+			// chill(address) -> 0x516f7f2a
+			// who
+			let input = hex! {"
+				516f7f2a
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			assert_eq!(execute(&input).unwrap().exit_status, ExitSucceed::Returned);
+			assert!(NomineesElection::nominations(alice()).is_empty());
+			assert_eq!(execute(&votes_input).unwrap().output, Output::encode_uint(0u128));
+		});
+	}
+
+	#[test]
+	fn unbond_rebond_and_withdraw_unbonded_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				LDOT,
+				1_000_000
+			));
+			assert_ok!(NomineesElection::bond(RuntimeOrigin::signed(alice()), 100_000));
+
+			// unbond(address,uint256) -> 0xa5d059ca
+			// who
+			// amount 40_000
+			let input = hex! {"
+				a5d059ca
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000009c40
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_uint(40_000u128));
+
+			// rebond(address,uint256) -> 0x92d1b784
+			// who
+			// amount 10_000
+			let input = hex! {"
+				92d1b784
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000002710
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_uint(10_000u128));
+
+			// getLedger(address) -> 0xf7cd6af9
+			// who
+			let input = hex! {"
+				f7cd6af9
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			// offset
+			// total 100_000
+			// active 70_000
+			// offset of unlocking
+			// unlocking len
+			// value 30_000
+			// unlock_at 28
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 000000000000000000000000000186a0
+				00000000000000000000000000000000 00000000000000000000000000011170
+				00000000000000000000000000000000 00000000000000000000000000000060
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000007530
+				00000000000000000000000000000000 0000000000000000000000000000001c
+			"};
+			assert_eq!(execute(&input).unwrap().output, expected_output.to_vec());
+
+			assert_ok!(Homa::reset_current_era(RuntimeOrigin::signed(HomaAdmin::get()), 28));
+
+			// withdrawUnbonded(address) -> 0xaeffaa47
+			// who
+			let input = hex! {"
+				aeffaa47
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			assert_eq!(execute(&input).unwrap().output, Output::encode_uint(30_000u128));
+			assert_eq!(NomineesElection::ledger(alice()).unwrap().total(), 70_000);
+		});
+	}
+
+	#[test]
+	fn get_reserved_nominees_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(NomineesElection::reset_reserved_nominees(
+				RuntimeOrigin::root(),
+				vec![(
+					1,
+					vec![AccountId::new([4u8; 32]), AccountId::new([3u8; 32])]
+						.try_into()
+						.unwrap()
+				)]
+			));
+
+			// getReservedNominees(uint16) -> 0x44d7647e
+			// group_index
+			let input = hex! {"
+				44d7647e
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			assert_eq!(
+				execute(&input).unwrap().output,
+				encode_nominees(vec![AccountId::new([3u8; 32]), AccountId::new([4u8; 32])])
+			);
+		});
+	}
+}
@@ -20,13 +20,17 @@ use super::{
 	input::{Input, InputPricer, InputT, Output},
 	weights::PrecompileWeights,
 };
-use crate::{Weight, WeightToGas};
+use crate::{TimeStampedPrice, Weight, WeightToGas};
+use frame_support::traits::Get;
 use module_evm::{
 	precompiles::Precompile, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileResult,
 };
-use module_support::{Erc20InfoMapping as Erc20InfoMappingT, PriceProvider as PriceProviderT};
+use module_support::{Erc20InfoMapping as Erc20InfoMappingT, Price, PriceProvider as PriceProviderT};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use sp_runtime::{traits::Convert, RuntimeDebug};
+use orml_traits::DataProviderExtended;
+use primitives::{CurrencyId, TradingPair};
+use sp_core::U256;
+use sp_runtime::{traits::Convert, RuntimeDebug, SaturatedConversion};
 use sp_std::{marker::PhantomData, prelude::*};
 
 /// The `Oracle` impl precompile.
@@ -36,6 +40,10 @@ use sp_std::{marker::PhantomData, prelude::*};
 ///
 /// Actions:
 /// - Get price. Rest `input` bytes: `currency_id`.
+/// - Get price with timestamp. Rest `input` bytes: `currency_id`.
+/// - Get locked price. Rest `input` bytes: `currency_id`.
+/// - Get dex average price. Rest `input` bytes: `base_currency_id`, `quote_currency_id`.
+/// - Get dex cumulative price. Rest `input` bytes: `base_currency_id`, `quote_currency_id`.
 pub struct OraclePrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -43,11 +51,16 @@ pub struct OraclePrecompile<R>(PhantomData<R>);
 #[repr(u32)]
 pub enum Action {
 	GetPrice = "getPrice(address)",
+	GetPriceWithTimestamp = "getPriceWithTimestamp(address)",
+	GetLockedPrice = "getLockedPrice(address)",
+	GetDexAveragePrice = "getDexAveragePrice(address,address)",
+	GetDexCumulativePrice = "getDexCumulativePrice(address,address)",
 }
 
 impl<Runtime> Precompile for OraclePrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config,
+	Runtime: module_evm::Config + module_prices::Config + module_dex_oracle::Config,
+	<Runtime as module_prices::Config>::Source: DataProviderExtended<CurrencyId, TimeStampedPrice>,
 {
 	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		let gas_cost = Pricer::<Runtime>::cost(handle)?;
@@ -62,43 +75,133 @@ where
 		match action {
 			Action::GetPrice => {
 				let currency_id = input.currency_id_at(1)?;
-				let mut price =
-					<module_prices::RealTimePriceProvider<Runtime>>::get_price(currency_id).unwrap_or_default();
-
-				let maybe_decimals = Runtime::Erc20InfoMapping::decimals(currency_id);
-				let decimals = match maybe_decimals {
-					Some(decimals) => decimals,
-					None => {
-						// If the option is none, let price = 0 to return 0.
-						// Solidity should handle the situation of price 0.
-						price = Default::default();
-						Default::default()
-					}
-				};
-
-				let maybe_adjustment_multiplier = 10u128.checked_pow((18u8.saturating_sub(decimals)).into());
-				let adjustment_multiplier = match maybe_adjustment_multiplier {
-					Some(adjustment_multiplier) => adjustment_multiplier,
-					None => {
-						// If the option is none, let price = 0 to return 0.
-						// Solidity should handle the situation of price 0.
-						price = Default::default();
-						Default::default()
-					}
-				};
-
-				let output = price.into_inner().wrapping_div(adjustment_multiplier);
-
-				log::debug!(target: "evm", "oracle: getPrice currency_id: {:?}, price: {:?}, adjustment_multiplier: {:?}, output: {:?}", currency_id, price, adjustment_multiplier, output);
+				let price = <module_prices::RealTimePriceProvider<Runtime>>::get_price(currency_id).unwrap_or_default();
+				let output = Self::adjusted_price(currency_id, price);
+
+				log::debug!(target: "evm", "oracle: getPrice currency_id: {:?}, price: {:?}, output: {:?}", currency_id, price, output);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(output),
+				})
+			}
+			Action::GetPriceWithTimestamp => {
+				let currency_id = input.currency_id_at(1)?;
+				// The oracle source price is already the price of one whole unit.
+				let (price, timestamp) = <Runtime as module_prices::Config>::Source::get_no_op(&currency_id)
+					.map(|v| (v.value.into_inner(), v.timestamp))
+					.unwrap_or_default();
+
+				log::debug!(target: "evm", "oracle: getPriceWithTimestamp currency_id: {:?}, price: {:?}, timestamp: {:?}", currency_id, price, timestamp);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_tuple(vec![U256::from(price), U256::from(timestamp)]),
+				})
+			}
+			Action::GetLockedPrice => {
+				let currency_id = input.currency_id_at(1)?;
+				let price = module_prices::Pallet::<Runtime>::locked_price(currency_id).unwrap_or_default();
+				let output = Self::adjusted_price(currency_id, price);
+
+				log::debug!(target: "evm", "oracle: getLockedPrice currency_id: {:?}, price: {:?}, output: {:?}", currency_id, price, output);
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
 					output: Output::encode_uint(output),
 				})
 			}
+			Action::GetDexAveragePrice => {
+				let base_currency_id = input.currency_id_at(1)?;
+				let quote_currency_id = input.currency_id_at(2)?;
+
+				// If average price is not enabled for the trading pair, return 0.
+				let (price, last_update_timestamp, interval) =
+					TradingPair::from_currency_ids(base_currency_id, quote_currency_id)
+						.and_then(|trading_pair| {
+							module_dex_oracle::Pallet::<Runtime>::average_prices(trading_pair).map(
+								|(price_0, price_1, _, _, last_update_timestamp, interval)| {
+									let price = if base_currency_id == trading_pair.first() {
+										price_0
+									} else {
+										price_1
+									};
+									(
+										price.into_inner(),
+										last_update_timestamp.saturated_into::<u128>(),
+										interval.saturated_into::<u128>(),
+									)
+								},
+							)
+						})
+						.unwrap_or_default();
+
+				log::debug!(target: "evm", "oracle: getDexAveragePrice base_currency_id: {:?}, quote_currency_id: {:?}, price: {:?}, last_update_timestamp: {:?}, interval: {:?}", base_currency_id, quote_currency_id, price, last_update_timestamp, interval);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_tuple(vec![price, last_update_timestamp, interval]),
+				})
+			}
+			Action::GetDexCumulativePrice => {
+				let base_currency_id = input.currency_id_at(1)?;
+				let quote_currency_id = input.currency_id_at(2)?;
+
+				// If average price is not enabled for the trading pair, return 0.
+				let (cumulative, last_cumulative_timestamp) =
+					TradingPair::from_currency_ids(base_currency_id, quote_currency_id)
+						.map(|trading_pair| {
+							let (cumulative_0, cumulative_1, last_cumulative_timestamp) =
+								module_dex_oracle::Pallet::<Runtime>::cumulatives(trading_pair);
+							let cumulative = if base_currency_id == trading_pair.first() {
+								cumulative_0
+							} else {
+								cumulative_1
+							};
+							(cumulative, last_cumulative_timestamp.saturated_into::<u128>())
+						})
+						.unwrap_or_default();
+
+				log::debug!(target: "evm", "oracle: getDexCumulativePrice base_currency_id: {:?}, quote_currency_id: {:?}, cumulative: {:?}, last_cumulative_timestamp: {:?}", base_currency_id, quote_currency_id, cumulative, last_cumulative_timestamp);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_tuple(vec![cumulative, U256::from(last_cumulative_timestamp)]),
+				})
+			}
 		}
 	}
 }
 
+impl<Runtime> OraclePrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config,
+{
+	/// Convert the price of one basic unit to the price of one whole unit with 18 decimals.
+	fn adjusted_price(currency_id: CurrencyId, price: Price) -> u128 {
+		let mut price = price;
+
+		let maybe_decimals = Runtime::Erc20InfoMapping::decimals(currency_id);
+		let decimals = match maybe_decimals {
+			Some(decimals) => decimals,
+			None => {
+				// If the option is none, let price = 0 to return 0.
+				// Solidity should handle the situation of price 0.
+				price = Default::default();
+				Default::default()
+			}
+		};
+
+		let maybe_adjustment_multiplier = 10u128.checked_pow((18u8.saturating_sub(decimals)).into());
+		let adjustment_multiplier = match maybe_adjustment_multiplier {
+			Some(adjustment_multiplier) => adjustment_multiplier,
+			None => {
+				// If the option is none, let price = 0 to return 0.
+				// Solidity should handle the situation of price 0.
+				price = Default::default();
+				Default::default()
+			}
+		};
+
+		price.into_inner().wrapping_div(adjustment_multiplier)
+	}
+}
+
 struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
@@ -121,6 +224,38 @@ where
 				let get_price = WeightToGas::convert(PrecompileWeights::<Runtime>::oracle_get_price());
 				WeightToGas::convert(Weight::from_parts(read_currency, 0)).saturating_add(get_price)
 			}
+			Action::GetPriceWithTimestamp => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				// Oracle::Values (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_currency.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetLockedPrice => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				// AssetRegistry::AssetMetadatas (r: 1)
+				// Prices::LockedPrice (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+
+				read_currency.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetDexAveragePrice | Action::GetDexCumulativePrice => {
+				let base_currency_id = input.currency_id_at(1)?;
+				let quote_currency_id = input.currency_id_at(2)?;
+				let read_base_currency = InputPricer::<Runtime>::read_currency(base_currency_id);
+				let read_quote_currency = InputPricer::<Runtime>::read_currency(quote_currency_id);
+
+				// DexOracle::AveragePrices or DexOracle::Cumulatives (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_base_currency
+					.saturating_add(read_quote_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
 		};
 		Ok(Self::BASE_COST.saturating_add(cost))
 	}
@@ -130,7 +265,10 @@ where
 mod tests {
 	use super::*;
 
-	use crate::precompile::mock::{alice_evm_addr, new_test_ext, Oracle, Price, Test, ALICE, DOT};
+	use crate::precompile::mock::{
+		alice_evm_addr, new_test_ext, DexModule, DexOracle, One, Oracle, Price, Prices, RuntimeOrigin, Test, Timestamp,
+		ALICE, AUSD, DOT,
+	};
 	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context, ExitRevert};
@@ -187,6 +325,195 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn get_price_with_timestamp_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getPriceWithTimestamp(address) -> 0xeeb9ef62
+			// DOT
+			let input = hex! {"
+				eeb9ef62
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// no price yet
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp =
+				OraclePrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			assert_ok!(Oracle::feed_value(Some(ALICE), DOT, Price::from(30_000)));
+
+			// returned price
+			// timestamp
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000065a4da25d3016c00000
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let resp =
+				OraclePrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn get_locked_price_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getLockedPrice(address) -> 0x0b52fc29
+			// DOT
+			let input = hex! {"
+				0b52fc29
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			assert_ok!(Oracle::feed_value(Some(ALICE), DOT, Price::from(30_000)));
+
+			// no locked price yet
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp =
+				OraclePrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			assert_ok!(Prices::lock_price(RuntimeOrigin::signed(One::get()), DOT));
+			assert_ok!(Oracle::feed_value(Some(ALICE), DOT, Price::from(20_000)));
+
+			// locked price
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000065a4da25d3016c00000
+			"};
+
+			let resp =
+				OraclePrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn get_dex_average_and_cumulative_price_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getDexAveragePrice(address,address) -> 0xbcf986ea
+			// DOT
+			// AUSD
+			let average_price_input = hex! {"
+				bcf986ea
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// getDexCumulativePrice(address,address) -> 0x49b354a1
+			// DOT
+			// AUSD
+			let cumulative_price_input = hex! {"
+				49b354a1
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// average price is not enabled
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp = OraclePrecompile::execute(&mut MockPrecompileHandle::new(
+				&average_price_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// enable DOT/AUSD
+			assert_ok!(DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), DOT, AUSD,));
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AUSD,
+				1_000,
+				1_000_000,
+				0,
+				true
+			));
+			assert_ok!(DexOracle::enable_average_price(
+				RuntimeOrigin::root(),
+				DOT,
+				AUSD,
+				12_000
+			));
+
+			// average price: 1_000
+			// last update timestamp: 1
+			// interval: 12_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000003635c9adc5dea00000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000002ee0
+			"};
+
+			let resp = OraclePrecompile::execute(&mut MockPrecompileHandle::new(
+				&average_price_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			Timestamp::set_timestamp(1_001);
+			DexOracle::try_update_cumulative(&TradingPair::from_currency_ids(DOT, AUSD).unwrap(), 1_000_000, 1_000);
+
+			// cumulative: 1_000 * 1_000
+			// last cumulative timestamp: 1_001
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000d3c21bcecceda1000000
+				00000000000000000000000000000000 000000000000000000000000000003e9
+			"};
+
+			let resp = OraclePrecompile::execute(&mut MockPrecompileHandle::new(
+				&cumulative_price_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
 	#[test]
 	fn oracle_precompile_should_handle_invalid_input() {
 		new_test_ext().execute_with(|| {
@@ -44,6 +44,7 @@ fn precompile_filter_works_on_acala_precompiles() {
 			gas_used: 0,
 			context: &non_system_caller_context,
 			is_static: false,
+			logs: vec![],
 		};
 		assert_eq!(
 			PrecompilesValue::get().execute(&mut handle),
@@ -75,6 +76,7 @@ fn precompile_filter_does_not_work_on_system_contracts() {
 			gas_used: 0,
 			context: &non_system_caller_context,
 			is_static: false,
+			logs: vec![],
 		};
 		assert!(PrecompilesValue::get().execute(&mut handle).is_none());
 	});
@@ -100,6 +102,7 @@ fn precompile_filter_does_not_work_on_non_system_contracts() {
 			gas_used: 0,
 			context: &non_system_caller_context,
 			is_static: false,
+			logs: vec![],
 		};
 		assert!(PrecompilesValue::get().execute(&mut handle).is_none());
 	});
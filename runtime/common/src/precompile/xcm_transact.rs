@@ -0,0 +1,240 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputPricer, InputT, Output};
+use crate::WeightToGas;
+use frame_support::{pallet_prelude::Decode, weights::Weight};
+use module_evm::{
+	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use module_support::XcmTransact;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_xcm::WeightInfo;
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::{
+	prelude::*,
+	v4::{Asset, Location},
+};
+
+/// The configuration of the XcmTransact precompile, implemented by the runtime.
+pub trait XcmTransactPrecompileConfig: frame_system::Config {
+	/// The interface to send the XCM `Transact` message.
+	type XcmTransact: XcmTransact<Self::AccountId>;
+
+	/// The weight info of `pallet_xcm`, used to price the sending of XCM message.
+	type XcmWeightInfo: WeightInfo;
+}
+
+/// The XcmTransact precompile.
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Transact. Rest `input` bytes: `who`, `dest`, `call`, `fee`, `ref_time`, `proof_size`.
+///
+/// The SCALE-encoded `call` is executed on `dest`, the relay chain or a sibling parachain, by the
+/// account derived from `who`. The `fee` asset is withdrawn from it to buy the execution of the
+/// call with `ref_time` and `proof_size` as its weight limit.
+pub struct XcmTransactPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Transact = "transact(address,bytes,bytes,bytes,uint64,uint64)",
+}
+
+impl<Runtime> Precompile for XcmTransactPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config + XcmTransactPrecompileConfig,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			Runtime::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		match action {
+			Action::Transact => {
+				let who = input.account_id_at(1)?;
+
+				let dest_bytes: &[u8] = &input.bytes_at(2)?[..];
+				let dest: Location = decode_location(dest_bytes).ok_or(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid dest".into(),
+				})?;
+
+				let call = input.bytes_at(3)?;
+
+				let fee_bytes: &[u8] = &input.bytes_at(4)?[..];
+				let fee: Asset = decode_asset(fee_bytes).ok_or(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid fee".into(),
+				})?;
+
+				let weight = Weight::from_parts(input.u64_at(5)?, input.u64_at(6)?);
+
+				log::debug!(
+					target: "evm",
+					"xcm_transact: Transact who: {:?}, dest: {:?}, call: {:?}, fee: {:?}, weight: {:?}",
+					who, dest, call, fee, weight
+				);
+
+				<Runtime as XcmTransactPrecompileConfig>::XcmTransact::transact(&who, dest, call, fee, weight)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("XcmTransact Transact failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+		}
+	}
+}
+
+fn decode_asset(mut bytes: &[u8]) -> Option<Asset> {
+	VersionedAsset::decode(&mut bytes).ok()?.try_into().ok()
+}
+
+fn decode_location(mut bytes: &[u8]) -> Option<Location> {
+	VersionedLocation::decode(&mut bytes).ok()?.try_into().ok()
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config + XcmTransactPrecompileConfig,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::Transact => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as XcmTransactPrecompileConfig>::XcmWeightInfo::send();
+
+				read_account.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{alice, alice_evm_addr, new_test_ext, Test, XcmTransacts};
+	use ethabi::Token;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use parity_scale_codec::Encode;
+
+	type XcmTransactPrecompile = crate::precompile::XcmTransactPrecompile<Test>;
+
+	fn transact_input(dest: Vec<u8>, fee: Vec<u8>) -> Vec<u8> {
+		let mut input = Into::<u32>::into(Action::Transact).to_be_bytes().to_vec();
+		input.extend_from_slice(&ethabi::encode(&[
+			Token::Address(alice_evm_addr()),
+			Token::Bytes(dest),
+			Token::Bytes(vec![0x00, 0x00, 0x04, 0x00]),
+			Token::Bytes(fee),
+			Token::Uint(1_000_000_000u64.into()),
+			Token::Uint(64_000u64.into()),
+		]));
+		input
+	}
+
+	#[test]
+	fn transact_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			let fee: Asset = (Here, 1_000_000u128).into();
+
+			let input = transact_input(
+				VersionedLocation::V4(Location::parent()).encode(),
+				VersionedAsset::V4(fee.clone()).encode(),
+			);
+			let resp =
+				XcmTransactPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Vec::<u8>::new());
+			assert_eq!(
+				XcmTransacts::get(),
+				vec![(
+					alice(),
+					Location::parent(),
+					vec![0x00, 0x00, 0x04, 0x00],
+					fee,
+					Weight::from_parts(1_000_000_000, 64_000)
+				)]
+			);
+		});
+	}
+
+	#[test]
+	fn transact_fails() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			let input = transact_input(vec![0xff], VersionedAsset::V4((Here, 1_000_000u128).into()).encode());
+			assert_eq!(
+				XcmTransactPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid dest".into(),
+				})
+			);
+
+			let input = transact_input(VersionedLocation::V4(Location::parent()).encode(), vec![0xff]);
+			assert_eq!(
+				XcmTransactPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid fee".into(),
+				})
+			);
+
+			assert!(XcmTransacts::get().is_empty());
+		});
+	}
+}
@@ -66,13 +66,13 @@ use orml_traits::{
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use primitives::{
 	define_combined_task,
-	evm::{decode_gas_limit, decode_gas_price, AccessListItem, EthereumTransactionMessage},
+	evm::{decode_gas_limit, decode_gas_price, AccessListItem, EthereumTransactionMessage, StateOverride},
 	task::TaskResult,
 	unchecked_extrinsic::AcalaUncheckedExtrinsic,
 };
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160};
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160, H256, U256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
@@ -968,6 +968,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, TreasuryAccount>;
+	type Erc20TransferLog = module_evm::Erc20TransferLogger<Runtime, EvmErc20InfoMapping<Runtime>>;
 }
 
 pub struct EnsureRootOrTreasury;
@@ -1106,6 +1107,7 @@ where
 			frame_system::CheckWeight::<Runtime>::new(),
 			module_evm::SetEvmOrigin::<Runtime>::new(),
 			module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			module_currencies::LogNativeTransfer::<Runtime>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1650,6 +1652,8 @@ impl pallet_proxy::Config for Runtime {
 parameter_types! {
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
 	pub PrecompilesValue: AllPrecompiles<Runtime, module_transaction_pause::PausedPrecompileFilter<Runtime>, AcalaPrecompiles<Runtime>> = AllPrecompiles::<_, _, _>::mandala();
+	// NOTE: half of the max gas of an extrinsic, the block is allowed to use twice the target.
+	pub BaseFeeGasTarget: u64 = runtime_common::EvmLimits::<Runtime>::max_gas_limit() / 2;
 }
 
 #[cfg(feature = "with-ethereum-compatibility")]
@@ -1699,8 +1703,16 @@ impl<I: From<Balance>> frame_support::traits::Get<I> for TxFeePerGasV2 {
 	}
 }
 
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BaseFeePerGas;
+impl frame_support::traits::Get<U256> for BaseFeePerGas {
+	fn get() -> U256 {
+		EVM::base_fee_per_gas()
+	}
+}
+
 #[cfg(feature = "with-ethereum-compatibility")]
-static SHANGHAI_CONFIG: module_evm_utility::evm::Config = module_evm_utility::evm::Config::shanghai();
+static CANCUN_CONFIG: module_evm_utility::evm::Config = module_evm_utility::evm::Config::cancun();
 
 impl module_evm::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
@@ -1709,6 +1721,8 @@ impl module_evm::Config for Runtime {
 	type NewContractExtraBytes = NewContractExtraBytes;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = TxFeePerGas;
+	type MinBaseFeePerGas = TxFeePerGasV2;
+	type BaseFeeGasTarget = BaseFeeGasTarget;
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType =
 		AllPrecompiles<Self, module_transaction_pause::PausedPrecompileFilter<Self>, AcalaPrecompiles<Runtime>>;
@@ -1730,10 +1744,49 @@ impl module_evm::Config for Runtime {
 
 	#[cfg(feature = "with-ethereum-compatibility")]
 	fn config() -> &'static module_evm_utility::evm::Config {
-		&SHANGHAI_CONFIG
+		&CANCUN_CONFIG
 	}
 }
 
+/// The calls that EVM accounts are allowed to dispatch with the Dispatch precompile.
+pub struct EvmDispatchCallFilter;
+impl Contains<RuntimeCall> for EvmDispatchCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Utility(
+				pallet_utility::Call::batch { .. }
+					| pallet_utility::Call::batch_all { .. }
+					| pallet_utility::Call::force_batch { .. }
+			) | RuntimeCall::Proxy(
+				pallet_proxy::Call::add_proxy { .. }
+					| pallet_proxy::Call::remove_proxy { .. }
+					| pallet_proxy::Call::remove_proxies { .. }
+			) | RuntimeCall::Democracy(
+				pallet_democracy::Call::vote { .. }
+					| pallet_democracy::Call::remove_vote { .. }
+					| pallet_democracy::Call::delegate { .. }
+					| pallet_democracy::Call::undelegate { .. }
+					| pallet_democracy::Call::unlock { .. }
+			) | RuntimeCall::HomaValidatorList(
+				module_homa_validator_list::Call::bond { .. }
+					| module_homa_validator_list::Call::unbond { .. }
+					| module_homa_validator_list::Call::rebond { .. }
+					| module_homa_validator_list::Call::withdraw_unbonded { .. }
+			)
+		)
+	}
+}
+
+impl runtime_common::precompile::dispatch::DispatchPrecompileConfig for Runtime {
+	type CallFilter = EvmDispatchCallFilter;
+}
+
+impl runtime_common::precompile::xcm_transact::XcmTransactPrecompileConfig for Runtime {
+	type XcmTransact = XcmInterface;
+	type XcmWeightInfo = weights::pallet_xcm::WeightInfo<Runtime>;
+}
+
 impl module_evm_bridge::Config for Runtime {
 	type EVM = EVM;
 }
@@ -2032,10 +2085,18 @@ pub type SignedExtra = (
 	// as fee token.
 	module_evm::SetEvmOrigin<Runtime>,
 	module_transaction_payment::ChargeTransactionPayment<Runtime>,
+	// `LogNativeTransfer` needs behind `ChargeTransactionPayment`, so the logged `transfer_all` amount excludes the fee.
+	module_currencies::LogNativeTransfer<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
-pub type UncheckedExtrinsic =
-	AcalaUncheckedExtrinsic<RuntimeCall, SignedExtra, ConvertEthereumTx, StorageDepositPerByte, TxFeePerGas>;
+pub type UncheckedExtrinsic = AcalaUncheckedExtrinsic<
+	RuntimeCall,
+	SignedExtra,
+	ConvertEthereumTx,
+	StorageDepositPerByte,
+	TxFeePerGas,
+	BaseFeePerGas,
+>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
@@ -2051,7 +2112,7 @@ pub type Executive = frame_executive::Executive<
 >;
 
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (module_nft::InitializeClassBalances<Runtime>);
 
 construct_runtime!(
 	pub enum Runtime {
@@ -2397,18 +2458,21 @@ impl_runtime_apis! {
 			storage_limit: u32,
 			access_list: Option<Vec<AccessListItem>>,
 			_estimate: bool,
+			state_overrides: Option<Vec<(H160, StateOverride<Balance>)>>,
 		) -> Result<CallInfo, sp_runtime::DispatchError> {
-			<Runtime as module_evm::Config>::Runner::rpc_call(
-				from,
-				from,
-				to,
-				data,
-				value,
-				gas_limit,
-				storage_limit,
-				access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
-				<Runtime as module_evm::Config>::config(),
-			)
+			module_evm::Pallet::<Runtime>::with_state_overrides(state_overrides.unwrap_or_default(), || {
+				<Runtime as module_evm::Config>::Runner::rpc_call(
+					from,
+					from,
+					to,
+					data,
+					value,
+					gas_limit,
+					storage_limit,
+					access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+					<Runtime as module_evm::Config>::config(),
+				)
+			})
 		}
 
 		fn create(
@@ -2419,16 +2483,19 @@ impl_runtime_apis! {
 			storage_limit: u32,
 			access_list: Option<Vec<AccessListItem>>,
 			_estimate: bool,
+			state_overrides: Option<Vec<(H160, StateOverride<Balance>)>>,
 		) -> Result<CreateInfo, sp_runtime::DispatchError> {
-			<Runtime as module_evm::Config>::Runner::rpc_create(
-				from,
-				data,
-				value,
-				gas_limit,
-				storage_limit,
-				access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
-				<Runtime as module_evm::Config>::config(),
-			)
+			module_evm::Pallet::<Runtime>::with_state_overrides(state_overrides.unwrap_or_default(), || {
+				<Runtime as module_evm::Config>::Runner::rpc_create(
+					from,
+					data,
+					value,
+					gas_limit,
+					storage_limit,
+					access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+					<Runtime as module_evm::Config>::config(),
+				)
+			})
 		}
 
 		fn get_estimate_resources_request(extrinsic: Vec<u8>) -> Result<EstimateResourcesRequest, sp_runtime::DispatchError> {
@@ -2478,7 +2545,7 @@ impl_runtime_apis! {
 		) -> Result<CallInfo, sp_runtime::DispatchError> {
 			let from = EvmAddressMapping::<Runtime>::get_or_create_evm_address(&from);
 
-			Self::call(from, to, data, value, gas_limit, storage_limit, access_list, estimate)
+			Self::call(from, to, data, value, gas_limit, storage_limit, access_list, estimate, None)
 		}
 
 		fn account_create(
@@ -2492,21 +2559,109 @@ impl_runtime_apis! {
 		) -> Result<CreateInfo, sp_runtime::DispatchError> {
 			let from = EvmAddressMapping::<Runtime>::get_or_create_evm_address(&from);
 
-			Self::create(from, data, value, gas_limit, storage_limit, access_list, estimate)
+			Self::create(from, data, value, gas_limit, storage_limit, access_list, estimate, None)
+		}
+
+		fn create_access_list(
+			from: H160,
+			to: Option<H160>,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+		) -> Result<module_evm::runner::AccessListInfo, sp_runtime::DispatchError> {
+			<Runtime as module_evm::Config>::Runner::rpc_create_access_list(
+				from,
+				to,
+				data,
+				value,
+				gas_limit,
+				storage_limit,
+				access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+				<Runtime as module_evm::Config>::config(),
+			)
+		}
+
+		fn fee_history(block_count: u32) -> module_evm::FeeHistory {
+			module_evm::Pallet::<Runtime>::fee_history(block_count)
+		}
+
+		fn account_entries(address: H160, storage_keys: Vec<H256>) -> module_evm::AccountEntries {
+			module_evm::Pallet::<Runtime>::account_entries(address, storage_keys)
 		}
 	}
 
 	#[cfg(feature = "tracing")]
-	impl module_evm_rpc_runtime_api::EVMTraceApi<Block> for Runtime {
+	impl module_evm_rpc_runtime_api::EVMTraceApi<Block, Balance> for Runtime {
 		fn trace_extrinsic(
 			extrinsic: <Block as BlockT>::Extrinsic,
 			tracer_config: primitives::evm::tracing::TracerConfig,
 		) -> Result<module_evm::runner::tracing::TraceOutcome, sp_runtime::transaction_validity::TransactionValidityError> {
 			let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config);
-			module_evm::runner::tracing::using(&mut tracer, || {
+			module_evm::runner::tracing::trace::<Runtime, _, _>(&mut tracer, || {
 				Executive::apply_extrinsic(extrinsic)
 			}).map(|_| tracer.finalize())
 		}
+
+		fn trace_call(
+			from: H160,
+			to: Option<H160>,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<module_evm::runner::tracing::TraceOutcome, sp_runtime::DispatchError> {
+			let access_list = access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect();
+			let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config);
+			module_evm::runner::tracing::trace::<Runtime, _, _>(&mut tracer, || {
+				match to {
+					Some(to) => <Runtime as module_evm::Config>::Runner::rpc_call(
+						from,
+						from,
+						to,
+						data,
+						value,
+						gas_limit,
+						storage_limit,
+						access_list,
+						<Runtime as module_evm::Config>::config(),
+					).map(|_| ()),
+					None => <Runtime as module_evm::Config>::Runner::rpc_create(
+						from,
+						data,
+						value,
+						gas_limit,
+						storage_limit,
+						access_list,
+						<Runtime as module_evm::Config>::config(),
+					).map(|_| ()),
+				}
+			}).map(|_| tracer.finalize())
+		}
+
+		fn trace_block(
+			block: Block,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<Vec<(u32, module_evm::runner::tracing::TraceOutcome)>, sp_runtime::transaction_validity::TransactionValidityError> {
+			let (header, extrinsics) = block.deconstruct();
+			Executive::initialize_block(&header);
+
+			let mut traces = Vec::new();
+			for (index, extrinsic) in extrinsics.into_iter().enumerate() {
+				let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config.clone());
+				let result = module_evm::runner::tracing::trace::<Runtime, _, _>(&mut tracer, || {
+					Executive::apply_extrinsic(extrinsic)
+				});
+				// an invalid extrinsic makes no changes, skip it and keep tracing the block
+				if result.is_ok() && tracer.executed() {
+					traces.push((index as u32, tracer.finalize()));
+				}
+			}
+			Ok(traces)
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -2801,6 +2956,7 @@ mod tests {
 				frame_system::CheckWeight::<Runtime>::new(),
 				module_evm::SetEvmOrigin::<Runtime>::new(),
 				module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+				module_currencies::LogNativeTransfer::<Runtime>::new(),
 			);
 
 			let mut expected_extra = extra.clone();